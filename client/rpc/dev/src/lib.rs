@@ -16,19 +16,33 @@
 
 use cumulus_primitives_core::ParaId;
 use cumulus_primitives_core::XcmpMessageFormat;
+use futures::{channel::mpsc::Sender, SinkExt, StreamExt};
 use jsonrpsee::{
-	core::RpcResult,
+	core::{RpcResult, SubscriptionResult},
 	proc_macros::rpc,
 	types::{
 		error::{INTERNAL_ERROR_CODE, INTERNAL_ERROR_MSG},
 		ErrorObjectOwned,
 	},
+	PendingSubscriptionSink, SubscriptionMessage,
 };
 use parity_scale_codec::Encode;
+use sc_consensus_manual_seal::EngineCommand;
+use sp_core::{Bytes, H256, U256};
+use std::collections::BTreeMap;
+use std::sync::{
+	atomic::{AtomicU32, AtomicU64, Ordering},
+	Arc, Mutex,
+};
 use xcm::opaque::lts::Weight;
 use xcm::v5::prelude::*;
 use xcm_primitives::DEFAULT_PROOF_SIZE;
 
+mod outbound_xcm;
+mod xcm_message;
+pub use outbound_xcm::*;
+pub use xcm_message::*;
+
 /// This RPC interface is used to provide methods in dev mode only
 #[rpc(server)]
 #[jsonrpsee::core::async_trait]
@@ -53,15 +67,115 @@ pub trait DevApi {
 	#[method(name = "xcm_injectHrmpMessage")]
 	async fn inject_hrmp_message(&self, sender: ParaId, message: Vec<u8>) -> RpcResult<()>;
 
+	/// Inject a downward xcm message, either SCALE encoded or described in JSON, seal a block
+	/// and return how the message queue processed it: processed and failed messages, weight
+	/// used and deposits made by each processed message.
+	#[method(name = "xcm_sendDownwardXcm")]
+	async fn send_downward_xcm(&self, message: XcmMessage) -> RpcResult<XcmProcessingResult>;
+
+	/// Inject an HRMP message from the `sender` sibling parachain, either SCALE encoded (as a
+	/// `VersionedXcm`, without the XCMP format prefix) or described in JSON, seal a block and
+	/// return how the message queue processed it.
+	#[method(name = "xcm_sendHrmpXcm")]
+	async fn send_hrmp_xcm(
+		&self,
+		sender: ParaId,
+		message: XcmMessage,
+	) -> RpcResult<XcmProcessingResult>;
+
+	/// Messages sent upward to the relay chain and to sibling parachains by the given block,
+	/// the best block if not provided, as left in the outboxes of the parachain system pallet.
+	/// HRMP messages are only sent through the egress channels of the mocked relay state.
+	#[method(name = "xcm_getOutboundMessages")]
+	async fn get_outbound_messages(&self, block_hash: Option<H256>) -> RpcResult<OutboundXcm>;
+
+	/// Notify the messages sent upward and to sibling parachains by each imported block that
+	/// sent any.
+	#[subscription(
+		name = "xcm_subscribeOutbound" => "xcm_outbound",
+		unsubscribe = "xcm_unsubscribeOutbound",
+		item = OutboundXcm
+	)]
+	async fn subscribe_outbound(&self) -> SubscriptionResult;
+
 	/// Skip N relay blocks, for testing purposes
 	#[method(name = "test_skipRelayBlocks")]
 	async fn skip_relay_blocks(&self, n: u32) -> RpcResult<()>;
+
+	/// Skip N relay slots, for testing purposes. The mocked relay slot is otherwise derived
+	/// from the block timestamp.
+	#[method(name = "test_skipRelaySlots")]
+	async fn skip_relay_slots(&self, n: u64) -> RpcResult<()>;
+
+	/// Set entries of the mocked relay chain state, included in the relay state proof of the
+	/// following blocks. An entry with a `null` value drops a previously set entry.
+	#[method(name = "test_setRelayStorage")]
+	async fn set_relay_storage(&self, entries: Vec<(Bytes, Option<Bytes>)>) -> RpcResult<()>;
+
+	/// Set the timestamp (in milliseconds) of the next block. Following blocks keep advancing
+	/// from there by one relay slot duration.
+	///
+	/// The timestamp must be at least one relay slot after the one of the last sealed block,
+	/// otherwise an error is returned.
+	#[method(name = "test_setNextBlockTimestamp")]
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> RpcResult<()>;
+
+	/// Seal N blocks, empty unless transactions are pending, and return their hashes.
+	/// Works regardless of the sealing mode of the node.
+	#[method(name = "test_mineBlocks")]
+	async fn mine_blocks(&self, n: u32) -> RpcResult<Vec<H256>>;
+
+	/// Take a snapshot of the current best block and its state. Returns the snapshot
+	/// identifier to be used with `evm_revert`. Sealed blocks are not finalized while
+	/// snapshots are held.
+	#[method(name = "evm_snapshot")]
+	async fn snapshot(&self) -> RpcResult<U256>;
+
+	/// Rewind the chain head and state to a previously taken snapshot.
+	///
+	/// The snapshot is consumed, as well as every snapshot taken after it. Returns false if
+	/// the snapshot identifier is unknown, and an error if a block imported after the snapshot
+	/// was finalized.
+	#[method(name = "evm_revert")]
+	async fn revert(&self, snapshot_id: U256) -> RpcResult<bool>;
 }
 
+/// Node-side support for `evm_snapshot`/`evm_revert`.
+///
+/// Implemented by the node for each backend type able to rewind its head and state.
+pub trait ChainSnapshots: Send + Sync {
+	/// Record the current best block and state, returning the snapshot identifier.
+	fn snapshot(&self) -> Result<u64, String>;
+
+	/// Rewind head and state to the given snapshot, discarding it and every later snapshot.
+	/// Returns false if the snapshot is unknown, and an error if blocks imported after the
+	/// snapshot are finalized.
+	fn revert(&self, snapshot_id: u64) -> Result<bool, String>;
+
+	/// Whether any snapshot is held. Blocks are not finalized meanwhile, so that they can be
+	/// reverted.
+	fn has_snapshots(&self) -> bool;
+}
+
+#[derive(Clone)]
 pub struct DevRpc {
 	pub downward_message_channel: flume::Sender<Vec<u8>>,
 	pub hrmp_message_channel: flume::Sender<(ParaId, Vec<u8>)>,
-	pub additional_relay_offset: std::sync::Arc<std::sync::atomic::AtomicU32>,
+	pub additional_relay_offset: Arc<AtomicU32>,
+	pub additional_relay_slot_offset: Arc<AtomicU64>,
+	/// Entries added to the mocked relay chain state, overriding the default ones.
+	pub relay_storage: Arc<Mutex<BTreeMap<Vec<u8>, Vec<u8>>>>,
+	/// Timestamp override for the next block, zero when unset.
+	pub next_timestamp: Arc<AtomicU64>,
+	/// Timestamp of the last block sealed by the node, zero before the first one.
+	pub current_timestamp: &'static AtomicU64,
+	/// Minimum increment between the timestamps of two blocks, in milliseconds.
+	pub slot_duration: u64,
+	/// Sealing commands, merged into the commands stream of the authorship task.
+	pub command_sink: Sender<EngineCommand<H256>>,
+	pub snapshots: Option<Arc<dyn ChainSnapshots>>,
+	pub xcm_reports: Option<Arc<dyn XcmProcessingReports>>,
+	pub outbound_messages: Option<Arc<dyn OutboundXcmMessages>>,
 }
 
 #[jsonrpsee::core::async_trait]
@@ -155,11 +269,199 @@ impl DevApiServer for DevRpc {
 		Ok(())
 	}
 
+	async fn send_downward_xcm(&self, message: XcmMessage) -> RpcResult<XcmProcessingResult> {
+		let reports = self.xcm_reports_provider()?;
+		let message = message.encode_versioned_xcm().map_err(internal_err)?;
+
+		self.downward_message_channel
+			.try_send(message)
+			.map_err(|err| internal_err(err.to_string()))?;
+
+		let block_hash = self.seal_block().await?;
+		let report = reports.report(block_hash).map_err(internal_err)?;
+
+		Ok(XcmProcessingResult::new(block_hash, report))
+	}
+
+	async fn send_hrmp_xcm(
+		&self,
+		sender: ParaId,
+		message: XcmMessage,
+	) -> RpcResult<XcmProcessingResult> {
+		let reports = self.xcm_reports_provider()?;
+		let mut encoded = XcmpMessageFormat::ConcatenatedVersionedXcm.encode();
+		encoded.append(&mut message.encode_versioned_xcm().map_err(internal_err)?);
+
+		self.hrmp_message_channel
+			.try_send((sender, encoded))
+			.map_err(|err| internal_err(err.to_string()))?;
+
+		let block_hash = self.seal_block().await?;
+		let report = reports.report(block_hash).map_err(internal_err)?;
+
+		Ok(XcmProcessingResult::new(block_hash, report))
+	}
+
+	async fn get_outbound_messages(&self, block_hash: Option<H256>) -> RpcResult<OutboundXcm> {
+		let (block_hash, messages) = self
+			.outbound_messages_provider()?
+			.outbound_messages(block_hash)
+			.map_err(internal_err)?;
+
+		Ok(OutboundXcm::new(block_hash, messages))
+	}
+
+	async fn subscribe_outbound(&self, pending: PendingSubscriptionSink) -> SubscriptionResult {
+		let outbound_messages = self.outbound_messages_provider()?.clone();
+		let sink = pending.accept().await?;
+
+		let mut imported_blocks = outbound_messages.imported_blocks();
+		while let Some(block_hash) = imported_blocks.next().await {
+			let (block_hash, messages) = match outbound_messages.outbound_messages(Some(block_hash))
+			{
+				Ok(messages) => messages,
+				Err(e) => {
+					log::warn!(
+						"Failed to read the outbound messages of {}: {}",
+						block_hash,
+						e
+					);
+					continue;
+				}
+			};
+			let outbound = OutboundXcm::new(block_hash, messages);
+			if outbound.is_empty() {
+				continue;
+			}
+
+			if sink
+				.send(SubscriptionMessage::from_json(&outbound)?)
+				.await
+				.is_err()
+			{
+				// The subscriber is gone
+				break;
+			}
+		}
+
+		Ok(())
+	}
+
 	async fn skip_relay_blocks(&self, n: u32) -> RpcResult<()> {
-		self.additional_relay_offset
-			.fetch_add(n, std::sync::atomic::Ordering::SeqCst);
+		self.additional_relay_offset.fetch_add(n, Ordering::SeqCst);
+		Ok(())
+	}
+
+	async fn skip_relay_slots(&self, n: u64) -> RpcResult<()> {
+		self.additional_relay_slot_offset
+			.fetch_add(n, Ordering::SeqCst);
+		Ok(())
+	}
+
+	async fn set_relay_storage(&self, entries: Vec<(Bytes, Option<Bytes>)>) -> RpcResult<()> {
+		let mut relay_storage = self
+			.relay_storage
+			.lock()
+			.map_err(|err| internal_err(err.to_string()))?;
+		for (key, value) in entries {
+			match value {
+				Some(value) => relay_storage.insert(key.0, value.0),
+				None => relay_storage.remove(&key.0),
+			};
+		}
+
 		Ok(())
 	}
+
+	async fn set_next_block_timestamp(&self, timestamp: u64) -> RpcResult<()> {
+		// Zero is used as the "unset" marker.
+		if timestamp == 0 {
+			return Err(internal_err("Timestamp must be greater than zero"));
+		}
+		let current = self.current_timestamp.load(Ordering::SeqCst);
+		let minimum = current.saturating_add(self.slot_duration);
+		if current != 0 && timestamp < minimum {
+			return Err(internal_err(format!(
+				"Timestamp {} is too early, it must be at least {}",
+				timestamp, minimum
+			)));
+		}
+
+		self.next_timestamp.store(timestamp, Ordering::SeqCst);
+		Ok(())
+	}
+
+	async fn mine_blocks(&self, n: u32) -> RpcResult<Vec<H256>> {
+		let mut hashes = Vec::with_capacity(n as usize);
+
+		for _ in 0..n {
+			// Wait for each block to be imported before requesting the next one.
+			hashes.push(self.seal_block().await?);
+		}
+
+		Ok(hashes)
+	}
+
+	async fn snapshot(&self) -> RpcResult<U256> {
+		let snapshots = self.snapshot_provider()?;
+		let snapshot_id = snapshots.snapshot().map_err(internal_err)?;
+
+		Ok(U256::from(snapshot_id))
+	}
+
+	async fn revert(&self, snapshot_id: U256) -> RpcResult<bool> {
+		let snapshots = self.snapshot_provider()?;
+		// Identifiers are handed out as u64, anything larger can't be a known snapshot.
+		if snapshot_id > U256::from(u64::MAX) {
+			return Ok(false);
+		}
+
+		snapshots
+			.revert(snapshot_id.low_u64())
+			.map_err(internal_err)
+	}
+}
+
+impl DevRpc {
+	/// Seal a block, empty unless transactions are pending, and wait for its import.
+	async fn seal_block(&self) -> RpcResult<H256> {
+		let (sender, receiver) = futures::channel::oneshot::channel();
+		self.command_sink
+			.clone()
+			.send(EngineCommand::SealNewBlock {
+				create_empty: true,
+				finalize: false,
+				parent_hash: None,
+				sender: Some(sender),
+			})
+			.await
+			.map_err(|err| internal_err(err.to_string()))?;
+
+		let created_block = receiver
+			.await
+			.map_err(|err| internal_err(err.to_string()))?
+			.map_err(|err| internal_err(err.to_string()))?;
+
+		Ok(created_block.hash)
+	}
+
+	fn outbound_messages_provider(&self) -> RpcResult<&Arc<dyn OutboundXcmMessages>> {
+		self.outbound_messages
+			.as_ref()
+			.ok_or_else(|| internal_err("Outbound messages are not supported by this node"))
+	}
+
+	fn xcm_reports_provider(&self) -> RpcResult<&Arc<dyn XcmProcessingReports>> {
+		self.xcm_reports
+			.as_ref()
+			.ok_or_else(|| internal_err("XCM processing reports are not supported by this node"))
+	}
+
+	fn snapshot_provider(&self) -> RpcResult<&Arc<dyn ChainSnapshots>> {
+		self.snapshots
+			.as_ref()
+			.ok_or_else(|| internal_err("Snapshots are not supported by this node"))
+	}
 }
 
 // This bit cribbed from frontier.
@@ -0,0 +1,154 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of the messages sent upward and to siblings by a dev node.
+
+use cumulus_primitives_core::{OutboundHrmpMessage, XcmpMessageFormat};
+use futures::stream::BoxStream;
+use parity_scale_codec::{Decode, DecodeLimit};
+use serde::Serialize;
+use sp_core::{Bytes, H256};
+use xcm::IdentifyVersion;
+
+/// Messages left by `cumulus_pallet_parachain_system` in its outboxes at the end of a block.
+#[derive(Debug, Clone, Default)]
+pub struct RawOutboundMessages {
+	pub upward: Vec<Vec<u8>>,
+	pub horizontal: Vec<OutboundHrmpMessage>,
+}
+
+/// Node-side support for `xcm_getOutboundMessages`/`xcm_subscribeOutbound`.
+pub trait OutboundXcmMessages: Send + Sync {
+	/// Outbound messages of the given block, the best block if not provided. Returns the hash
+	/// of the block the messages were read at.
+	fn outbound_messages(&self, at: Option<H256>) -> Result<(H256, RawOutboundMessages), String>;
+
+	/// Hashes of the blocks imported from now on.
+	fn imported_blocks(&self) -> BoxStream<'static, H256>;
+}
+
+/// A single XCM message, raw and decoded.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundXcmMessage {
+	/// SCALE encoded `VersionedXcm`.
+	pub data: Bytes,
+	/// XCM version of the message, `null` if it could not be decoded.
+	pub version: Option<u32>,
+	/// Instructions of the message, converted to the latest XCM version.
+	pub instructions: Vec<String>,
+}
+
+impl OutboundXcmMessage {
+	fn decode(data: Vec<u8>) -> Self {
+		let (version, instructions) = match xcm::VersionedXcm::<()>::decode_with_depth_limit(
+			xcm::MAX_XCM_DECODE_DEPTH,
+			&mut data.as_slice(),
+		) {
+			Ok(message) => (Some(message.identify_version()), instructions_of(message)),
+			Err(_) => (None, Vec::new()),
+		};
+
+		Self {
+			data: data.into(),
+			version,
+			instructions,
+		}
+	}
+}
+
+fn instructions_of(message: xcm::VersionedXcm<()>) -> Vec<String> {
+	match xcm::latest::Xcm::<()>::try_from(message) {
+		Ok(message) => message
+			.0
+			.iter()
+			.map(|instruction| format!("{:?}", instruction))
+			.collect(),
+		Err(_) => Vec::new(),
+	}
+}
+
+/// Messages sent to a sibling parachain in a block.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundHrmpXcm {
+	pub recipient: u32,
+	/// Raw HRMP message, with its XCMP format prefix.
+	pub data: Bytes,
+	/// The XCM messages concatenated in `data`, empty for other formats.
+	pub messages: Vec<OutboundXcmMessage>,
+}
+
+impl OutboundHrmpXcm {
+	fn decode(message: OutboundHrmpMessage) -> Self {
+		let mut messages = Vec::new();
+		let mut input = message.data.as_slice();
+		if let Ok(XcmpMessageFormat::ConcatenatedVersionedXcm) =
+			XcmpMessageFormat::decode(&mut input)
+		{
+			while !input.is_empty() {
+				let start = input;
+				if xcm::VersionedXcm::<()>::decode_with_depth_limit(
+					xcm::MAX_XCM_DECODE_DEPTH,
+					&mut input,
+				)
+				.is_err()
+				{
+					break;
+				}
+				let encoded = start[..start.len() - input.len()].to_vec();
+				messages.push(OutboundXcmMessage::decode(encoded));
+			}
+		}
+
+		Self {
+			recipient: message.recipient.into(),
+			data: message.data.into(),
+			messages,
+		}
+	}
+}
+
+/// Messages sent upward and to siblings in a block.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundXcm {
+	pub block_hash: H256,
+	pub upward: Vec<OutboundXcmMessage>,
+	pub horizontal: Vec<OutboundHrmpXcm>,
+}
+
+impl OutboundXcm {
+	pub fn new(block_hash: H256, messages: RawOutboundMessages) -> Self {
+		Self {
+			block_hash,
+			upward: messages
+				.upward
+				.into_iter()
+				.map(OutboundXcmMessage::decode)
+				.collect(),
+			horizontal: messages
+				.horizontal
+				.into_iter()
+				.map(OutboundHrmpXcm::decode)
+				.collect(),
+		}
+	}
+
+	pub fn is_empty(&self) -> bool {
+		self.upward.is_empty() && self.horizontal.is_empty()
+	}
+}
@@ -0,0 +1,425 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! JSON description of injected XCM messages, and report of their processing.
+
+use moonbeam_rpc_primitives_xcm::{MessageOrigin, XcmProcessingReport};
+use parity_scale_codec::Encode;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize};
+use sp_core::{Bytes, H160, H256, U256};
+use xcm::opaque::lts::Weight;
+use xcm::v5::prelude::*;
+use xcm_primitives::DEFAULT_PROOF_SIZE;
+
+/// Node-side support for reporting the processing of injected XCM messages.
+pub trait XcmProcessingReports: Send + Sync {
+	/// Report of the XCM messages processed in the given block.
+	fn report(&self, at: H256) -> Result<XcmProcessingReport, String>;
+}
+
+/// XCM message to inject, either pre-encoded or described in JSON.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum XcmMessage {
+	/// SCALE encoded `VersionedXcm`, as a hex string.
+	Encoded(Bytes),
+	/// Transfer of assets to a beneficiary on this chain.
+	Transfer(XcmTransfer),
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum XcmTransferType {
+	/// The assets are reserve-backed by the sender (`ReserveAssetDeposited`).
+	#[default]
+	Reserve,
+	/// The assets are teleported (`ReceiveTeleportedAsset`).
+	Teleport,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct XcmTransfer {
+	/// Assets received, with locations relative to this chain.
+	pub assets: Vec<XcmAsset>,
+	/// Index in `assets` of the asset paying for the execution.
+	#[serde(default)]
+	pub fee_asset_index: usize,
+	/// Account receiving the assets.
+	pub beneficiary: H160,
+	#[serde(default)]
+	pub transfer_type: XcmTransferType,
+	/// Execution weight limit (ref time), unlimited if not provided.
+	#[serde(default)]
+	pub weight_limit: Option<u64>,
+	/// Topic set at the end of the message, used as the message id by the message queue.
+	#[serde(default)]
+	pub topic: Option<H256>,
+	/// XCM version the message is encoded with, the latest one if not provided.
+	#[serde(default)]
+	pub xcm_version: Option<u32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct XcmAsset {
+	pub location: XcmLocation,
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub amount: u128,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct XcmLocation {
+	pub parents: u8,
+	#[serde(default)]
+	pub interior: Vec<XcmJunction>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum XcmJunction {
+	Parachain(u32),
+	PalletInstance(u8),
+	GeneralIndex(#[serde(deserialize_with = "deserialize_u128")] u128),
+	AccountKey20(H160),
+}
+
+/// Accept integers either as JSON numbers or as (decimal or hex) strings, since JSON numbers
+/// can't hold most balances.
+fn deserialize_u128<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum NumberOrString {
+		Number(u128),
+		String(String),
+	}
+
+	match NumberOrString::deserialize(deserializer)? {
+		NumberOrString::Number(number) => Ok(number),
+		NumberOrString::String(string) => match string.strip_prefix("0x") {
+			Some(hex) => u128::from_str_radix(hex, 16),
+			None => string.parse(),
+		}
+		.map_err(D::Error::custom),
+	}
+}
+
+impl XcmLocation {
+	fn to_location(&self) -> Result<Location, String> {
+		let mut interior = Junctions::Here;
+		for junction in &self.interior {
+			let junction = match junction {
+				XcmJunction::Parachain(id) => Parachain(*id),
+				XcmJunction::PalletInstance(index) => PalletInstance(*index),
+				XcmJunction::GeneralIndex(index) => GeneralIndex(*index),
+				XcmJunction::AccountKey20(key) => AccountKey20 {
+					network: None,
+					key: key.0,
+				},
+			};
+			interior
+				.push(junction)
+				.map_err(|_| "Too many junctions in location".to_string())?;
+		}
+
+		Ok(Location::new(self.parents, interior))
+	}
+}
+
+impl XcmTransfer {
+	/// Build the `VersionedXcm` for this transfer.
+	pub fn to_versioned_xcm(&self) -> Result<xcm::VersionedXcm<()>, String> {
+		let assets = self
+			.assets
+			.iter()
+			.map(|asset| Ok((asset.location.to_location()?, asset.amount).into()))
+			.collect::<Result<Vec<Asset>, String>>()?;
+		let fees = assets
+			.get(self.fee_asset_index)
+			.cloned()
+			.ok_or_else(|| "Fee asset index out of bounds".to_string())?;
+		let asset_count = assets.len() as u32;
+
+		let mut instructions = vec![
+			match self.transfer_type {
+				XcmTransferType::Reserve => ReserveAssetDeposited(assets.into()),
+				XcmTransferType::Teleport => ReceiveTeleportedAsset(assets.into()),
+			},
+			ClearOrigin,
+			BuyExecution {
+				fees,
+				weight_limit: self.weight_limit.map_or(Unlimited, |ref_time| {
+					Limited(Weight::from_parts(ref_time, DEFAULT_PROOF_SIZE))
+				}),
+			},
+			DepositAsset {
+				assets: AllCounted(asset_count).into(),
+				beneficiary: Location::new(
+					0,
+					[AccountKey20 {
+						network: None,
+						key: self.beneficiary.0,
+					}],
+				),
+			},
+		];
+		if let Some(topic) = self.topic {
+			instructions.push(SetTopic(topic.0));
+		}
+
+		let message = xcm::VersionedXcm::<()>::V5(Xcm(instructions));
+		match self.xcm_version {
+			Some(version) => message
+				.into_version(version)
+				.map_err(|_| format!("Message can't be converted to XCM v{}", version)),
+			None => Ok(message),
+		}
+	}
+}
+
+impl XcmMessage {
+	/// SCALE encoded `VersionedXcm` of the message.
+	pub fn encode_versioned_xcm(&self) -> Result<Vec<u8>, String> {
+		match self {
+			Self::Encoded(bytes) => Ok(bytes.0.clone()),
+			Self::Transfer(transfer) => Ok(transfer.to_versioned_xcm()?.encode()),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum XcmOrigin {
+	Here,
+	Parent,
+	Sibling(u32),
+}
+
+impl From<MessageOrigin> for XcmOrigin {
+	fn from(origin: MessageOrigin) -> Self {
+		match origin {
+			MessageOrigin::Here => Self::Here,
+			MessageOrigin::Parent => Self::Parent,
+			MessageOrigin::Sibling(para_id) => Self::Sibling(para_id),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmWeight {
+	pub ref_time: u64,
+	pub proof_size: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessedXcm {
+	pub id: H256,
+	pub origin: XcmOrigin,
+	pub weight_used: XcmWeight,
+	pub success: bool,
+	/// Deposits made while processing the message.
+	pub deposits: Vec<XcmDeposit>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FailedXcm {
+	pub id: H256,
+	pub origin: XcmOrigin,
+	pub error: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmDeposit {
+	/// Contract of the minted ERC-20, `null` for the native currency.
+	pub asset: Option<H160>,
+	pub who: H160,
+	pub amount: U256,
+}
+
+/// Outcome of the block processing the injected message.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct XcmProcessingResult {
+	pub block_hash: H256,
+	pub processed: Vec<ProcessedXcm>,
+	pub failed: Vec<FailedXcm>,
+}
+
+impl XcmProcessingResult {
+	pub fn new(block_hash: H256, report: XcmProcessingReport) -> Self {
+		Self {
+			block_hash,
+			processed: report
+				.processed
+				.into_iter()
+				.map(|message| ProcessedXcm {
+					id: message.id,
+					origin: message.origin.into(),
+					weight_used: XcmWeight {
+						ref_time: message.weight_used.ref_time(),
+						proof_size: message.weight_used.proof_size(),
+					},
+					success: message.success,
+					deposits: message
+						.deposits
+						.into_iter()
+						.map(|deposit| XcmDeposit {
+							asset: deposit.asset,
+							who: deposit.who,
+							amount: deposit.amount,
+						})
+						.collect(),
+				})
+				.collect(),
+			failed: report
+				.failed
+				.into_iter()
+				.map(|message| FailedXcm {
+					id: message.id,
+					origin: message.origin.into(),
+					error: format!("{:?}", message.error),
+				})
+				.collect(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use moonbeam_rpc_primitives_xcm::{
+		Deposit, FailedMessage, ProcessMessageError, ProcessedMessage, ReportEvent,
+	};
+	use serde_json::json;
+
+	fn native_deposit(who: u8, amount: u64) -> ReportEvent {
+		ReportEvent::Deposit(Deposit {
+			asset: None,
+			who: H160::repeat_byte(who),
+			amount: amount.into(),
+		})
+	}
+
+	fn processed(id: u8, origin: MessageOrigin) -> ReportEvent {
+		ReportEvent::Processed {
+			id: H256::repeat_byte(id),
+			origin,
+			weight_used: Weight::from_parts(1_000, 10),
+			success: true,
+		}
+	}
+
+	#[test]
+	fn result_reports_the_deposits_of_each_message() {
+		let erc20 = H160::repeat_byte(0xff);
+		let report = XcmProcessingReport::from_events([
+			(0, native_deposit(1, 10)),
+			(0, processed(1, MessageOrigin::Parent)),
+			(
+				0,
+				ReportEvent::Deposit(Deposit {
+					asset: Some(erc20),
+					who: H160::repeat_byte(2),
+					amount: 20.into(),
+				}),
+			),
+			(0, processed(2, MessageOrigin::Sibling(2000))),
+		]);
+
+		let result = XcmProcessingResult::new(H256::repeat_byte(9), report);
+
+		assert_eq!(
+			serde_json::to_value(result).expect("result can be serialized"),
+			json!({
+				"blockHash": H256::repeat_byte(9),
+				"processed": [
+					{
+						"id": H256::repeat_byte(1),
+						"origin": "parent",
+						"weightUsed": { "refTime": 1_000, "proofSize": 10 },
+						"success": true,
+						"deposits": [
+							{ "asset": null, "who": H160::repeat_byte(1), "amount": "0xa" }
+						]
+					},
+					{
+						"id": H256::repeat_byte(2),
+						"origin": { "sibling": 2000 },
+						"weightUsed": { "refTime": 1_000, "proofSize": 10 },
+						"success": true,
+						"deposits": [
+							{ "asset": erc20, "who": H160::repeat_byte(2), "amount": "0x14" }
+						]
+					}
+				],
+				"failed": []
+			})
+		);
+	}
+
+	#[test]
+	fn result_reports_failed_messages() {
+		let report = XcmProcessingReport {
+			processed: vec![ProcessedMessage {
+				id: H256::repeat_byte(1),
+				origin: MessageOrigin::Here,
+				weight_used: Default::default(),
+				success: false,
+				deposits: vec![],
+			}],
+			failed: vec![FailedMessage {
+				id: H256::repeat_byte(2),
+				origin: MessageOrigin::Parent,
+				error: ProcessMessageError::Unsupported,
+			}],
+		};
+
+		let result = XcmProcessingResult::new(H256::zero(), report);
+
+		assert!(!result.processed[0].success);
+		assert!(result.processed[0].deposits.is_empty());
+		assert_eq!(result.failed.len(), 1);
+		assert_eq!(result.failed[0].id, H256::repeat_byte(2));
+		assert_eq!(result.failed[0].error, "Unsupported");
+	}
+
+	#[test]
+	fn transfer_is_encoded_as_a_reserve_deposit() {
+		let transfer: XcmMessage = serde_json::from_value(json!({
+			"assets": [{ "location": { "parents": 1 }, "amount": "1000" }],
+			"beneficiary": "0x0101010101010101010101010101010101010101",
+			"topic": H256::repeat_byte(7)
+		}))
+		.expect("valid transfer");
+
+		let XcmMessage::Transfer(transfer) = transfer else {
+			panic!("transfer is not decoded as encoded bytes");
+		};
+		let xcm::VersionedXcm::V5(Xcm(instructions)) =
+			transfer.to_versioned_xcm().expect("valid transfer")
+		else {
+			panic!("transfer is encoded with the latest XCM version");
+		};
+		assert_eq!(instructions.len(), 5);
+		assert!(matches!(instructions[0], ReserveAssetDeposited(_)));
+		assert_eq!(instructions[4], SetTopic([7; 32]));
+	}
+}
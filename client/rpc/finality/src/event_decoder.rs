@@ -0,0 +1,504 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Decoding of the `System::Events` storage into JSON, driven by the type registry of the
+//! runtime metadata.
+//!
+//! Composites with named fields become objects, composites with a single unnamed field become
+//! their inner value and other composites become arrays. Variants without fields become their
+//! name, and `{ "<name>": <fields> }` otherwise. Byte sequences and arrays are hex encoded, and
+//! integers wider than 32 bits are decimal strings.
+
+use frame_metadata::{RuntimeMetadata, RuntimeMetadataPrefixed};
+use parity_scale_codec::{Compact, Decode};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{Map, Value};
+use sp_core::U256;
+
+/// Decoder of the events of a given runtime version.
+pub struct EventDecoder {
+	registry: PortableRegistry,
+	events_type: u32,
+}
+
+impl EventDecoder {
+	/// Build the decoder from SCALE encoded runtime metadata.
+	pub fn from_metadata(encoded: &[u8]) -> Result<Self, String> {
+		let metadata = RuntimeMetadataPrefixed::decode(&mut &encoded[..])
+			.map_err(|e| format!("Invalid runtime metadata: {}", e))?;
+
+		macro_rules! events_decoder {
+			($metadata:expr, $version:ident) => {{
+				let events_type = $metadata
+					.pallets
+					.iter()
+					.find(|pallet| pallet.name == "System")
+					.and_then(|pallet| pallet.storage.as_ref())
+					.and_then(|storage| storage.entries.iter().find(|entry| entry.name == "Events"))
+					.and_then(|entry| match &entry.ty {
+						frame_metadata::$version::StorageEntryType::Plain(ty) => Some(ty.id),
+						_ => None,
+					})
+					.ok_or("No System::Events storage in the runtime metadata")?;
+
+				Ok(Self {
+					registry: $metadata.types,
+					events_type,
+				})
+			}};
+		}
+
+		match metadata.1 {
+			RuntimeMetadata::V14(metadata) => events_decoder!(metadata, v14),
+			RuntimeMetadata::V15(metadata) => events_decoder!(metadata, v15),
+			_ => Err("Unsupported runtime metadata version".to_string()),
+		}
+	}
+
+	/// Decode the SCALE encoded value of the `System::Events` storage.
+	pub fn decode_events(&self, mut input: &[u8]) -> Result<Value, String> {
+		self.decode(self.events_type, &mut input)
+	}
+
+	fn is_u8(&self, type_id: u32) -> bool {
+		matches!(
+			self.registry.resolve(type_id).map(|ty| &ty.type_def),
+			Some(TypeDef::Primitive(TypeDefPrimitive::U8))
+		)
+	}
+
+	fn decode_fields(
+		&self,
+		fields: &[Field<PortableForm>],
+		input: &mut &[u8],
+	) -> Result<Value, String> {
+		if !fields.is_empty() && fields.iter().all(|field| field.name.is_some()) {
+			let mut object = Map::new();
+			for field in fields {
+				let name = field.name.clone().unwrap_or_default();
+				object.insert(name, self.decode(field.ty.id, input)?);
+			}
+			return Ok(Value::Object(object));
+		}
+
+		let mut values = fields
+			.iter()
+			.map(|field| self.decode(field.ty.id, input))
+			.collect::<Result<Vec<_>, _>>()?;
+		if values.len() == 1 {
+			Ok(values.remove(0))
+		} else {
+			Ok(Value::Array(values))
+		}
+	}
+
+	fn decode_items(&self, type_id: u32, len: usize, input: &mut &[u8]) -> Result<Value, String> {
+		if self.is_u8(type_id) {
+			let bytes = input
+				.get(..len)
+				.ok_or("Not enough data to decode bytes")?
+				.to_vec();
+			*input = &input[len..];
+			return Ok(Value::String(format!("0x{}", hex::encode(bytes))));
+		}
+
+		(0..len)
+			.map(|_| self.decode(type_id, input))
+			.collect::<Result<Vec<_>, _>>()
+			.map(Value::Array)
+	}
+
+	fn decode(&self, type_id: u32, input: &mut &[u8]) -> Result<Value, String> {
+		let ty = self
+			.registry
+			.resolve(type_id)
+			.ok_or_else(|| format!("Unknown type {}", type_id))?;
+
+		match &ty.type_def {
+			// `U256` is a composite of 64-bit limbs, decoded as a single integer instead
+			TypeDef::Composite(_) if ty.path.ident().as_deref() == Some("U256") => {
+				Self::decode_primitive(&TypeDefPrimitive::U256, input)
+			}
+			TypeDef::Composite(composite) => self.decode_fields(&composite.fields, input),
+			TypeDef::Variant(variants) => {
+				let index = u8::decode(input).map_err(|e| e.to_string())?;
+				let variant = variants
+					.variants
+					.iter()
+					.find(|variant| variant.index == index)
+					.ok_or_else(|| format!("Unknown variant {} of type {}", index, type_id))?;
+				if variant.fields.is_empty() {
+					Ok(Value::String(variant.name.clone()))
+				} else {
+					let mut object = Map::new();
+					object.insert(
+						variant.name.clone(),
+						self.decode_fields(&variant.fields, input)?,
+					);
+					Ok(Value::Object(object))
+				}
+			}
+			TypeDef::Sequence(sequence) => {
+				let len = Compact::<u32>::decode(input).map_err(|e| e.to_string())?.0;
+				self.decode_items(sequence.type_param.id, len as usize, input)
+			}
+			TypeDef::Array(array) => {
+				self.decode_items(array.type_param.id, array.len as usize, input)
+			}
+			TypeDef::Tuple(tuple) => tuple
+				.fields
+				.iter()
+				.map(|field| self.decode(field.id, input))
+				.collect::<Result<Vec<_>, _>>()
+				.map(Value::Array),
+			TypeDef::Compact(_) => {
+				let value = Compact::<u128>::decode(input).map_err(|e| e.to_string())?.0;
+				Ok(Self::integer(value))
+			}
+			TypeDef::Primitive(primitive) => Self::decode_primitive(primitive, input),
+			TypeDef::BitSequence(_) => Err("Bit sequences are not supported".to_string()),
+		}
+	}
+
+	fn integer<T: Into<u128>>(value: T) -> Value {
+		let value = value.into();
+		if value <= u32::MAX as u128 {
+			Value::from(value as u32)
+		} else {
+			Value::String(value.to_string())
+		}
+	}
+
+	fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, String> {
+		fn decode<T: Decode>(input: &mut &[u8]) -> Result<T, String> {
+			T::decode(input).map_err(|e| e.to_string())
+		}
+
+		Ok(match primitive {
+			TypeDefPrimitive::Bool => Value::Bool(decode(input)?),
+			TypeDefPrimitive::Char => Value::String(
+				char::from_u32(decode(input)?)
+					.ok_or("Invalid char")?
+					.to_string(),
+			),
+			TypeDefPrimitive::Str => Value::String(decode(input)?),
+			TypeDefPrimitive::U8 => Self::integer(decode::<u8>(input)?),
+			TypeDefPrimitive::U16 => Self::integer(decode::<u16>(input)?),
+			TypeDefPrimitive::U32 => Self::integer(decode::<u32>(input)?),
+			TypeDefPrimitive::U64 => Self::integer(decode::<u64>(input)?),
+			TypeDefPrimitive::U128 => Self::integer(decode::<u128>(input)?),
+			TypeDefPrimitive::U256 => {
+				Value::String(U256::from_little_endian(&decode::<[u8; 32]>(input)?).to_string())
+			}
+			TypeDefPrimitive::I8 => Value::from(decode::<i8>(input)?),
+			TypeDefPrimitive::I16 => Value::from(decode::<i16>(input)?),
+			TypeDefPrimitive::I32 => Value::from(decode::<i32>(input)?),
+			TypeDefPrimitive::I64 => Value::String(decode::<i64>(input)?.to_string()),
+			TypeDefPrimitive::I128 => Value::String(decode::<i128>(input)?.to_string()),
+			TypeDefPrimitive::I256 => {
+				Value::String(format!("0x{}", hex::encode(decode::<[u8; 32]>(input)?)))
+			}
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_metadata::v14::{
+		ExtrinsicMetadata, PalletMetadata, PalletStorageMetadata, RuntimeMetadataV14,
+		StorageEntryMetadata, StorageEntryModifier, StorageEntryType,
+	};
+	use frame_system::{EventRecord, Phase};
+	use parity_scale_codec::{DecodeWithMemTracking, Encode};
+	use scale_info::{meta_type, TypeInfo};
+	use serde_json::json;
+	use sp_core::{H160, H256};
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum RuntimeEvent {
+		#[codec(index = 0)]
+		System(SystemEvent),
+		#[codec(index = 1)]
+		ParachainSystem(ParachainSystemEvent),
+		#[codec(index = 52)]
+		Ethereum(EthereumEvent),
+		#[codec(index = 80)]
+		Rewards(RewardsEvent),
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum SystemEvent {
+		ExtrinsicSuccess { dispatch_info: DispatchInfo },
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	struct DispatchInfo {
+		weight: Weight,
+		class: DispatchClass,
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	struct Weight {
+		#[codec(compact)]
+		ref_time: u64,
+		#[codec(compact)]
+		proof_size: u64,
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum DispatchClass {
+		Normal,
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum ParachainSystemEvent {
+		#[codec(index = 5)]
+		UpwardMessageSent { message_hash: Option<[u8; 32]> },
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum EthereumEvent {
+		Executed {
+			from: H160,
+			to: H160,
+			transaction_hash: H256,
+			exit_reason: ExitReason,
+			extra_data: Vec<u8>,
+		},
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum ExitReason {
+		Succeed(ExitSucceed),
+		Revert(ExitRevert),
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum ExitSucceed {
+		Stopped,
+		Returned,
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum ExitRevert {
+		Reverted,
+	}
+
+	#[derive(Encode, Decode, DecodeWithMemTracking, Clone, PartialEq, Eq, Debug, TypeInfo)]
+	enum RewardsEvent {
+		Rewarded { account: H160, amount: U256 },
+	}
+
+	type Events = Vec<EventRecord<RuntimeEvent, H256>>;
+
+	fn decoder() -> EventDecoder {
+		let metadata = RuntimeMetadataV14::new(
+			vec![PalletMetadata {
+				name: "System",
+				storage: Some(PalletStorageMetadata {
+					prefix: "System",
+					entries: vec![StorageEntryMetadata {
+						name: "Events",
+						modifier: StorageEntryModifier::Default,
+						ty: StorageEntryType::Plain(meta_type::<Events>()),
+						default: vec![0],
+						docs: vec![],
+					}],
+				}),
+				calls: None,
+				event: None,
+				constants: vec![],
+				error: None,
+				index: 0,
+			}],
+			ExtrinsicMetadata {
+				ty: meta_type::<()>(),
+				version: 4,
+				signed_extensions: vec![],
+			},
+			meta_type::<RuntimeEvent>(),
+		);
+
+		EventDecoder::from_metadata(&RuntimeMetadataPrefixed::from(metadata).encode())
+			.expect("valid metadata")
+	}
+
+	fn record(phase: Phase, event: RuntimeEvent) -> EventRecord<RuntimeEvent, H256> {
+		EventRecord {
+			phase,
+			event,
+			topics: vec![],
+		}
+	}
+
+	#[test]
+	fn decodes_ethereum_executed() {
+		let transaction_hash = H256::repeat_byte(0xab);
+		let events: Events = vec![record(
+			Phase::ApplyExtrinsic(3),
+			RuntimeEvent::Ethereum(EthereumEvent::Executed {
+				from: H160::repeat_byte(1),
+				to: H160::repeat_byte(2),
+				transaction_hash,
+				exit_reason: ExitReason::Succeed(ExitSucceed::Returned),
+				extra_data: vec![],
+			}),
+		)];
+
+		let decoded = decoder().decode_events(&events.encode()).unwrap();
+
+		assert_eq!(
+			decoded,
+			json!([{
+				"phase": { "ApplyExtrinsic": 3 },
+				"event": {
+					"Ethereum": {
+						"Executed": {
+							"from": format!("{:?}", H160::repeat_byte(1)),
+							"to": format!("{:?}", H160::repeat_byte(2)),
+							"transaction_hash": format!("{:?}", transaction_hash),
+							"exit_reason": { "Succeed": "Returned" },
+							"extra_data": "0x",
+						}
+					}
+				},
+				"topics": [],
+			}])
+		);
+		// The transaction effects RPC matches the hash formatted this way
+		assert_eq!(
+			decoded[0]["event"]["Ethereum"]["Executed"]["transaction_hash"],
+			Value::String(format!("{:?}", transaction_hash))
+		);
+	}
+
+	#[test]
+	fn decodes_compact_fields() {
+		let events: Events = vec![record(
+			Phase::Finalization,
+			RuntimeEvent::System(SystemEvent::ExtrinsicSuccess {
+				dispatch_info: DispatchInfo {
+					weight: Weight {
+						ref_time: 5_000_000_000,
+						proof_size: 1_000,
+					},
+					class: DispatchClass::Normal,
+				},
+			}),
+		)];
+
+		assert_eq!(
+			decoder().decode_events(&events.encode()).unwrap()[0],
+			json!({
+				"phase": "Finalization",
+				"event": {
+					"System": {
+						"ExtrinsicSuccess": {
+							"dispatch_info": {
+								"weight": { "ref_time": "5000000000", "proof_size": 1000 },
+								"class": "Normal",
+							}
+						}
+					}
+				},
+				"topics": [],
+			})
+		);
+	}
+
+	#[test]
+	fn decodes_options() {
+		let events: Events = vec![
+			record(
+				Phase::ApplyExtrinsic(0),
+				RuntimeEvent::ParachainSystem(ParachainSystemEvent::UpwardMessageSent {
+					message_hash: Some([1; 32]),
+				}),
+			),
+			record(
+				Phase::ApplyExtrinsic(0),
+				RuntimeEvent::ParachainSystem(ParachainSystemEvent::UpwardMessageSent {
+					message_hash: None,
+				}),
+			),
+		];
+
+		let decoded = decoder().decode_events(&events.encode()).unwrap();
+
+		assert_eq!(
+			decoded[0]["event"],
+			json!({
+				"ParachainSystem": {
+					"UpwardMessageSent": {
+						"message_hash": { "Some": format!("0x{}", "01".repeat(32)) }
+					}
+				}
+			})
+		);
+		assert_eq!(
+			decoded[1]["event"],
+			json!({ "ParachainSystem": { "UpwardMessageSent": { "message_hash": "None" } } })
+		);
+	}
+
+	#[test]
+	fn decodes_u256_and_topics() {
+		let events: Events = vec![EventRecord {
+			phase: Phase::Initialization,
+			event: RuntimeEvent::Rewards(RewardsEvent::Rewarded {
+				account: H160::repeat_byte(3),
+				amount: U256::from(10).pow(20.into()),
+			}),
+			topics: vec![H256::repeat_byte(4)],
+		}];
+
+		assert_eq!(
+			decoder().decode_events(&events.encode()).unwrap()[0],
+			json!({
+				"phase": "Initialization",
+				"event": {
+					"Rewards": {
+						"Rewarded": {
+							"account": format!("{:?}", H160::repeat_byte(3)),
+							"amount": "100000000000000000000",
+						}
+					}
+				},
+				"topics": [format!("{:?}", H256::repeat_byte(4))],
+			})
+		);
+	}
+
+	#[test]
+	fn rejects_truncated_events() {
+		let events: Events = vec![record(
+			Phase::ApplyExtrinsic(0),
+			RuntimeEvent::Ethereum(EthereumEvent::Executed {
+				from: H160::repeat_byte(1),
+				to: H160::repeat_byte(2),
+				transaction_hash: H256::repeat_byte(3),
+				exit_reason: ExitReason::Revert(ExitRevert::Reverted),
+				extra_data: vec![1, 2, 3],
+			}),
+		)];
+		let encoded = events.encode();
+
+		assert!(decoder()
+			.decode_events(&encoded[..encoded.len() - 1])
+			.is_err());
+	}
+}
@@ -13,16 +13,48 @@
 
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
-use fc_rpc::frontier_backend_client::{self, is_canon};
+mod event_decoder;
+mod transaction_effects;
+
+pub use transaction_effects::*;
 
+use fc_rpc::frontier_backend_client::{self, is_canon};
+use fc_rpc::StorageOverride;
+use futures::StreamExt;
 use jsonrpsee::types::error::ErrorObject;
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use jsonrpsee::{
+	core::{RpcResult, SubscriptionResult},
+	proc_macros::rpc,
+	PendingSubscriptionSink, SubscriptionMessage,
+};
+use sc_client_api::BlockchainEvents;
+use serde::Serialize;
 use sp_blockchain::HeaderBackend;
-use sp_core::H256;
+use sp_core::{H256, U256};
 use sp_runtime::traits::Block;
 use std::ops::Deref;
 use std::{marker::PhantomData, sync::Arc};
 
+/// Maximum number of hashes accepted by the batch queries.
+pub const MAX_BATCH_SIZE: usize = 10_000;
+
+/// An Ethereum block, identified by its number and hash.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EthBlockId {
+	pub number: U256,
+	pub hash: H256,
+}
+
+/// The Ethereum transactions of a newly finalized block.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizedTransactions {
+	pub block_number: U256,
+	pub block_hash: H256,
+	pub transaction_hashes: Vec<H256>,
+}
+
 /// An RPC endpoint to check for finality of blocks and transactions in Moonbeam
 #[rpc(server)]
 #[async_trait::async_trait]
@@ -37,32 +69,71 @@ pub trait MoonbeamFinalityApi {
 	#[method(name = "moon_isTxFinalized")]
 	async fn is_tx_finalized(&self, tx_hash: H256) -> RpcResult<bool>;
 
+	/// Reports, for each of the given Substrate or Ethereum block hashes, whether the block is
+	/// finalized. Unknown blocks are reported as not finalized.
+	#[method(name = "moon_areBlocksFinalized")]
+	async fn are_blocks_finalized(&self, block_hashes: Vec<H256>) -> RpcResult<Vec<bool>>;
+
+	/// Reports, for each of the given Ethereum transaction hashes, whether the transaction is
+	/// finalized. Unknown transactions are reported as not finalized.
+	#[method(name = "moon_areTxsFinalized")]
+	async fn are_txs_finalized(&self, tx_hashes: Vec<H256>) -> RpcResult<Vec<bool>>;
+
 	/// Gets the range of blocks that are fully indexed in frontier's backend.
 	#[method(name = "moon_getEthSyncBlockRange")]
 	async fn get_frontier_sync_block_range(&self) -> RpcResult<(H256, H256)>;
+
+	/// Gets the number and hash of the latest finalized Ethereum block.
+	#[method(name = "moon_getFinalizedEthBlock")]
+	async fn get_finalized_eth_block(&self) -> RpcResult<Option<EthBlockId>>;
+
+	/// Emits the hashes of the Ethereum transactions of each block, as the block is finalized.
+	#[subscription(
+		name = "moon_subscribeFinalizedTransactions" => "moon_finalizedTransactions",
+		unsubscribe = "moon_unsubscribeFinalizedTransactions",
+		item = FinalizedTransactions
+	)]
+	async fn subscribe_finalized_transactions(&self) -> SubscriptionResult;
 }
 
 pub struct MoonbeamFinality<B: Block, C> {
 	pub backend: Arc<dyn fc_api::Backend<B>>,
 	pub client: Arc<C>,
+	pub overrides: Arc<dyn StorageOverride<B>>,
 	_phdata: PhantomData<B>,
 }
 
 impl<B: Block, C> MoonbeamFinality<B, C> {
-	pub fn new(client: Arc<C>, backend: Arc<dyn fc_api::Backend<B>>) -> Self {
+	pub fn new(
+		client: Arc<C>,
+		backend: Arc<dyn fc_api::Backend<B>>,
+		overrides: Arc<dyn StorageOverride<B>>,
+	) -> Self {
 		Self {
 			backend,
 			client,
+			overrides,
 			_phdata: Default::default(),
 		}
 	}
 }
 
+fn ensure_batch_size(len: usize) -> RpcResult<()> {
+	if len > MAX_BATCH_SIZE {
+		return Err(ErrorObject::owned(
+			jsonrpsee::types::error::INVALID_PARAMS_CODE,
+			format!("At most {} hashes can be queried at once", MAX_BATCH_SIZE),
+			None::<()>,
+		));
+	}
+	Ok(())
+}
+
 #[async_trait::async_trait]
 impl<B, C> MoonbeamFinalityApiServer for MoonbeamFinality<B, C>
 where
 	B: Block<Hash = H256>,
-	C: HeaderBackend<B> + Send + Sync + 'static,
+	C: HeaderBackend<B> + BlockchainEvents<B> + Send + Sync + 'static,
 {
 	async fn is_block_finalized(&self, raw_hash: H256) -> RpcResult<bool> {
 		let client = self.client.clone();
@@ -71,21 +142,30 @@ where
 
 	async fn is_tx_finalized(&self, tx_hash: H256) -> RpcResult<bool> {
 		let client = self.client.clone();
+		is_tx_finalized_inner::<B, C>(self.backend.as_ref(), &client, tx_hash).await
+	}
 
-		if let Some((ethereum_block_hash, _ethereum_index)) =
-			frontier_backend_client::load_transactions::<B, C>(
-				&client,
-				self.backend.as_ref(),
-				tx_hash,
-				true,
-			)
-			.await?
-		{
-			is_block_finalized_inner::<B, C>(self.backend.as_ref(), &client, ethereum_block_hash)
-				.await
-		} else {
-			Ok(false)
+	async fn are_blocks_finalized(&self, block_hashes: Vec<H256>) -> RpcResult<Vec<bool>> {
+		ensure_batch_size(block_hashes.len())?;
+		let mut finalized = Vec::with_capacity(block_hashes.len());
+		for raw_hash in block_hashes {
+			finalized.push(
+				is_block_finalized_inner::<B, C>(self.backend.as_ref(), &self.client, raw_hash)
+					.await?,
+			);
 		}
+		Ok(finalized)
+	}
+
+	async fn are_txs_finalized(&self, tx_hashes: Vec<H256>) -> RpcResult<Vec<bool>> {
+		ensure_batch_size(tx_hashes.len())?;
+		let mut finalized = Vec::with_capacity(tx_hashes.len());
+		for tx_hash in tx_hashes {
+			finalized.push(
+				is_tx_finalized_inner::<B, C>(self.backend.as_ref(), &self.client, tx_hash).await?,
+			);
+		}
+		Ok(finalized)
 	}
 
 	async fn get_frontier_sync_block_range(&self) -> RpcResult<(H256, H256)> {
@@ -106,6 +186,68 @@ where
 			)),
 		}
 	}
+
+	async fn get_finalized_eth_block(&self) -> RpcResult<Option<EthBlockId>> {
+		let finalized_hash = self.client.info().finalized_hash;
+		Ok(self
+			.overrides
+			.current_block(finalized_hash)
+			.map(|block| EthBlockId {
+				number: block.header.number,
+				hash: block.header.hash(),
+			}))
+	}
+
+	async fn subscribe_finalized_transactions(
+		&self,
+		pending: PendingSubscriptionSink,
+	) -> SubscriptionResult {
+		let sink = pending.accept().await?;
+
+		let mut finality_notifications = self.client.finality_notification_stream();
+		while let Some(notification) = finality_notifications.next().await {
+			// The tree route holds the blocks finalized along with the notified one
+			for substrate_hash in notification
+				.tree_route
+				.iter()
+				.chain(std::iter::once(&notification.hash))
+			{
+				let Some(block) = self.overrides.current_block(*substrate_hash) else {
+					continue;
+				};
+				let finalized = FinalizedTransactions {
+					block_number: block.header.number,
+					block_hash: block.header.hash(),
+					transaction_hashes: block.transactions.iter().map(|tx| tx.hash()).collect(),
+				};
+
+				if sink
+					.send(SubscriptionMessage::from_json(&finalized)?)
+					.await
+					.is_err()
+				{
+					// The subscriber is gone
+					return Ok(());
+				}
+			}
+		}
+
+		Ok(())
+	}
+}
+
+async fn is_tx_finalized_inner<B: Block<Hash = H256>, C: HeaderBackend<B> + 'static>(
+	backend: &(dyn fc_api::Backend<B>),
+	client: &C,
+	tx_hash: H256,
+) -> RpcResult<bool> {
+	if let Some((ethereum_block_hash, _ethereum_index)) =
+		frontier_backend_client::load_transactions::<B, C>(client, backend, tx_hash, true).await?
+	{
+		is_block_finalized_inner::<B, C>(backend, client, ethereum_block_hash).await
+	} else {
+		Ok(false)
+	}
 }
 
 async fn is_block_finalized_inner<B: Block<Hash = H256>, C: HeaderBackend<B> + 'static>(
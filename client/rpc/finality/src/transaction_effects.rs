@@ -0,0 +1,253 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Substrate side effects of Ethereum transactions.
+//!
+//! The extrinsic of an Ethereum transaction is identified by the `Ethereum::Executed` event
+//! carrying its hash. All the events emitted during that extrinsic are returned, decoded with
+//! the runtime metadata of the block, along with the hashes of the XCM messages it sent.
+
+use crate::event_decoder::EventDecoder;
+use fc_rpc::frontier_backend_client;
+use jsonrpsee::types::error::ErrorObject;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_client_api::{Backend, StorageProvider};
+use serde::Serialize;
+use serde_json::Value;
+use sp_api::{Core, Metadata, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_core::{twox_128, H256};
+use sp_runtime::traits::{Block, UniqueSaturatedInto};
+use sp_storage::StorageKey;
+use std::sync::Mutex;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Version of the runtime metadata used to decode the events.
+const METADATA_VERSION: u32 = 15;
+
+/// A runtime event, decoded with the runtime metadata.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubstrateEvent {
+	pub pallet: String,
+	pub event: String,
+	/// Fields of the event, `null` if it has none
+	pub fields: Value,
+}
+
+/// The Substrate side effects of an Ethereum transaction.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionSubstrateEffects {
+	pub block_hash: H256,
+	pub block_number: u32,
+	pub extrinsic_index: u32,
+	pub events: Vec<SubstrateEvent>,
+	/// Hashes of the XCM messages sent to the relay chain and to sibling parachains
+	pub xcm_message_hashes: Vec<H256>,
+}
+
+/// An RPC endpoint to inspect the Substrate side effects of Ethereum transactions in Moonbeam
+#[rpc(server)]
+#[async_trait::async_trait]
+pub trait MoonbeamTransactionEffectsApi {
+	/// Returns the extrinsic of an Ethereum transaction, with the runtime events it emitted and
+	/// the hashes of the XCM messages it sent.
+	/// Returns null if the transaction is not found.
+	#[method(name = "moon_getTransactionSubstrateEffects")]
+	async fn get_transaction_substrate_effects(
+		&self,
+		tx_hash: H256,
+	) -> RpcResult<Option<TransactionSubstrateEffects>>;
+}
+
+pub struct MoonbeamTransactionEffects<B: Block, C, BE> {
+	pub backend: Arc<dyn fc_api::Backend<B>>,
+	pub client: Arc<C>,
+	/// Event decoder of the latest runtime version used, with its spec version
+	decoder: Mutex<Option<(u32, Arc<EventDecoder>)>>,
+	_phdata: PhantomData<(B, BE)>,
+}
+
+impl<B: Block, C, BE> MoonbeamTransactionEffects<B, C, BE> {
+	pub fn new(client: Arc<C>, backend: Arc<dyn fc_api::Backend<B>>) -> Self {
+		Self {
+			backend,
+			client,
+			decoder: Mutex::new(None),
+			_phdata: Default::default(),
+		}
+	}
+}
+
+fn internal_err<T: ToString>(message: T) -> jsonrpsee::types::ErrorObjectOwned {
+	ErrorObject::owned(
+		jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+		message.to_string(),
+		None::<()>,
+	)
+}
+
+impl<B, C, BE> MoonbeamTransactionEffects<B, C, BE>
+where
+	B: Block<Hash = H256>,
+	C: ProvideRuntimeApi<B> + StorageProvider<B, BE> + HeaderBackend<B> + 'static,
+	C::Api: Core<B> + Metadata<B>,
+	BE: Backend<B>,
+{
+	fn event_decoder(&self, at: H256) -> RpcResult<Arc<EventDecoder>> {
+		let api = self.client.runtime_api();
+		let spec_version = api.version(at).map_err(internal_err)?.spec_version;
+
+		let mut decoder = self.decoder.lock().map_err(internal_err)?;
+		match decoder.as_ref() {
+			Some((version, cached)) if *version == spec_version => Ok(cached.clone()),
+			_ => {
+				let metadata = api
+					.metadata_at_version(at, METADATA_VERSION)
+					.map_err(internal_err)?
+					.ok_or_else(|| internal_err("Runtime metadata not available"))?;
+				let new_decoder =
+					Arc::new(EventDecoder::from_metadata(&metadata).map_err(internal_err)?);
+				*decoder = Some((spec_version, new_decoder.clone()));
+				Ok(new_decoder)
+			}
+		}
+	}
+}
+
+/// Split a decoded `{ "<pallet>": { "<event>": <fields> } }` runtime event.
+fn split_event(event: &Value) -> Option<SubstrateEvent> {
+	let (pallet, event) = event.as_object()?.iter().next()?;
+	let (event, fields) = match event {
+		Value::String(event) => (event.clone(), Value::Null),
+		Value::Object(object) => {
+			let (event, fields) = object.iter().next()?;
+			(event.clone(), fields.clone())
+		}
+		_ => return None,
+	};
+	Some(SubstrateEvent {
+		pallet: pallet.clone(),
+		event,
+		fields,
+	})
+}
+
+fn parse_hash(value: &Value) -> Option<H256> {
+	let bytes = hex::decode(value.as_str()?.strip_prefix("0x")?).ok()?;
+	(bytes.len() == 32).then(|| H256::from_slice(&bytes))
+}
+
+/// Hash of the XCM message sent by an event, if any.
+fn xcm_message_hash(event: &SubstrateEvent) -> Option<H256> {
+	match (event.pallet.as_str(), event.event.as_str()) {
+		("XcmpQueue", "XcmpMessageSent") => parse_hash(&event.fields["message_hash"]),
+		// The hash is optional
+		("ParachainSystem", "UpwardMessageSent") => {
+			parse_hash(&event.fields["message_hash"]["Some"])
+		}
+		_ => None,
+	}
+}
+
+#[async_trait::async_trait]
+impl<B, C, BE> MoonbeamTransactionEffectsApiServer for MoonbeamTransactionEffects<B, C, BE>
+where
+	B: Block<Hash = H256>,
+	C: ProvideRuntimeApi<B> + StorageProvider<B, BE> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: Core<B> + Metadata<B>,
+	BE: Backend<B> + 'static,
+{
+	async fn get_transaction_substrate_effects(
+		&self,
+		tx_hash: H256,
+	) -> RpcResult<Option<TransactionSubstrateEffects>> {
+		let client = self.client.as_ref();
+		let Some((ethereum_block_hash, _ethereum_index)) =
+			frontier_backend_client::load_transactions::<B, C>(
+				client,
+				self.backend.as_ref(),
+				tx_hash,
+				true,
+			)
+			.await?
+		else {
+			return Ok(None);
+		};
+		let Some(block_hash) = frontier_backend_client::load_hash::<B, C>(
+			client,
+			self.backend.as_ref(),
+			ethereum_block_hash,
+		)
+		.await?
+		else {
+			return Ok(None);
+		};
+		let block_number = client
+			.number(block_hash)
+			.map_err(internal_err)?
+			.ok_or_else(|| internal_err("Block not found"))?;
+
+		let events_key = StorageKey([twox_128(b"System"), twox_128(b"Events")].concat());
+		let Some(encoded_events) = client
+			.storage(block_hash, &events_key)
+			.map_err(internal_err)?
+		else {
+			return Ok(None);
+		};
+		let records = self
+			.event_decoder(block_hash)?
+			.decode_events(&encoded_events.0)
+			.map_err(internal_err)?;
+
+		// Events by extrinsic index
+		let mut events: Vec<(u32, SubstrateEvent)> = Vec::new();
+		for record in records.as_array().into_iter().flatten() {
+			let Some(extrinsic_index) = record["phase"]["ApplyExtrinsic"].as_u64() else {
+				continue;
+			};
+			if let Some(event) = split_event(&record["event"]) {
+				events.push((extrinsic_index as u32, event));
+			}
+		}
+
+		let tx_hash_value = Value::String(format!("{:?}", tx_hash));
+		let Some(extrinsic_index) = events.iter().find_map(|(index, event)| {
+			(event.pallet == "Ethereum"
+				&& event.event == "Executed"
+				&& event.fields["transaction_hash"] == tx_hash_value)
+				.then_some(*index)
+		}) else {
+			return Ok(None);
+		};
+
+		let events: Vec<SubstrateEvent> = events
+			.into_iter()
+			.filter(|(index, _)| *index == extrinsic_index)
+			.map(|(_, event)| event)
+			.collect();
+		let xcm_message_hashes = events.iter().filter_map(xcm_message_hash).collect();
+
+		Ok(Some(TransactionSubstrateEffects {
+			block_hash,
+			block_number: block_number.unique_saturated_into(),
+			extrinsic_index,
+			events,
+			xcm_message_hashes,
+		}))
+	}
+}
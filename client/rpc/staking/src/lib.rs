@@ -0,0 +1,368 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC wrapper of the `ParachainStakingApi` runtime API.
+//!
+//! Balances are returned as hex encoded integers since they don't fit in JSON numbers.
+
+use jsonrpsee::types::error::ErrorObject;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use moonbeam_rpc_primitives_staking as primitives;
+use moonbeam_rpc_primitives_staking::ParachainStakingApi;
+use parity_scale_codec::Codec;
+use serde::Serialize;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Block;
+use std::{marker::PhantomData, sync::Arc};
+
+/// Placement of a delegation among the delegations of its candidate
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DelegationPlacement {
+	Top,
+	Bottom,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DelegationAction {
+	Revoke,
+	Decrease,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScheduledRequest {
+	pub action: DelegationAction,
+	pub amount: U256,
+	pub when_executable: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delegation {
+	pub candidate: H160,
+	pub amount: U256,
+	/// Auto-compounded percentage of the rewards
+	pub auto_compound: u8,
+	pub placement: DelegationPlacement,
+	pub scheduled_requests: Vec<ScheduledRequest>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegatorPosition {
+	pub total: U256,
+	pub less_total: U256,
+	pub delegations: Vec<Delegation>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollatorStatus {
+	Active,
+	Idle,
+	/// Leaving, with the round the candidate can leave at
+	Leaving(u32),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BondLessRequest {
+	pub amount: U256,
+	pub when_executable: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollatorSummary {
+	pub bond: U256,
+	pub status: CollatorStatus,
+	pub is_selected: bool,
+	pub delegation_count: u32,
+	pub top_delegation_count: u32,
+	pub bottom_delegation_count: u32,
+	pub total_counted: U256,
+	pub total_bottom: U256,
+	pub lowest_top_delegation_amount: U256,
+	pub highest_bottom_delegation_amount: U256,
+	pub lowest_bottom_delegation_amount: U256,
+	pub auto_compounding_delegation_count: u32,
+	pub scheduled_request_delegator_count: u32,
+	pub bond_less_request: Option<BondLessRequest>,
+}
+
+/// Rewards projected for a new delegation.
+/// The points share is in parts per billion, and the APR in basis points.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProjectedRewards {
+	pub counted: bool,
+	pub points_share: u32,
+	pub collator_round_reward: U256,
+	pub delegation_round_reward: U256,
+	pub delegation_annual_reward: U256,
+	pub delegation_apr: u32,
+}
+
+/// Collator selected for a round
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectedCollator {
+	pub collator: H160,
+	pub total_counted: U256,
+	pub delegator_count: u32,
+}
+
+impl<AccountId: Into<H160>, Balance: Into<U256>>
+	From<primitives::DelegatorPosition<AccountId, Balance>> for DelegatorPosition
+{
+	fn from(position: primitives::DelegatorPosition<AccountId, Balance>) -> Self {
+		Self {
+			total: position.total.into(),
+			less_total: position.less_total.into(),
+			delegations: position
+				.delegations
+				.into_iter()
+				.map(|delegation| Delegation {
+					candidate: delegation.candidate.into(),
+					amount: delegation.amount.into(),
+					auto_compound: delegation.auto_compound.deconstruct(),
+					placement: match delegation.placement {
+						primitives::DelegationPlacement::Top => DelegationPlacement::Top,
+						primitives::DelegationPlacement::Bottom => DelegationPlacement::Bottom,
+					},
+					scheduled_requests: delegation
+						.scheduled_requests
+						.into_iter()
+						.map(|request| {
+							let (action, amount) = match request.action {
+								primitives::DelegationAction::Revoke(amount) => {
+									(DelegationAction::Revoke, amount)
+								}
+								primitives::DelegationAction::Decrease(amount) => {
+									(DelegationAction::Decrease, amount)
+								}
+							};
+							ScheduledRequest {
+								action,
+								amount: amount.into(),
+								when_executable: request.when_executable,
+							}
+						})
+						.collect(),
+				})
+				.collect(),
+		}
+	}
+}
+
+impl<Balance: Into<U256>> From<primitives::CollatorSummary<Balance>> for CollatorSummary {
+	fn from(summary: primitives::CollatorSummary<Balance>) -> Self {
+		Self {
+			bond: summary.bond.into(),
+			status: match summary.status {
+				primitives::CollatorStatus::Active => CollatorStatus::Active,
+				primitives::CollatorStatus::Idle => CollatorStatus::Idle,
+				primitives::CollatorStatus::Leaving(round) => CollatorStatus::Leaving(round),
+			},
+			is_selected: summary.is_selected,
+			delegation_count: summary.delegation_count,
+			top_delegation_count: summary.top_delegation_count,
+			bottom_delegation_count: summary.bottom_delegation_count,
+			total_counted: summary.total_counted.into(),
+			total_bottom: summary.total_bottom.into(),
+			lowest_top_delegation_amount: summary.lowest_top_delegation_amount.into(),
+			highest_bottom_delegation_amount: summary.highest_bottom_delegation_amount.into(),
+			lowest_bottom_delegation_amount: summary.lowest_bottom_delegation_amount.into(),
+			auto_compounding_delegation_count: summary.auto_compounding_delegation_count,
+			scheduled_request_delegator_count: summary.scheduled_request_delegator_count,
+			bond_less_request: summary.bond_less_request.map(|request| BondLessRequest {
+				amount: request.amount.into(),
+				when_executable: request.when_executable,
+			}),
+		}
+	}
+}
+
+impl<Balance: Into<U256>> From<primitives::ProjectedRewards<Balance>> for ProjectedRewards {
+	fn from(rewards: primitives::ProjectedRewards<Balance>) -> Self {
+		Self {
+			counted: rewards.counted,
+			points_share: rewards.points_share.deconstruct(),
+			collator_round_reward: rewards.collator_round_reward.into(),
+			delegation_round_reward: rewards.delegation_round_reward.into(),
+			delegation_annual_reward: rewards.delegation_annual_reward.into(),
+			delegation_apr: rewards.delegation_apr,
+		}
+	}
+}
+
+impl<AccountId: Into<H160>, Balance: Into<U256>>
+	From<primitives::SelectedCollator<AccountId, Balance>> for SelectedCollator
+{
+	fn from(selected: primitives::SelectedCollator<AccountId, Balance>) -> Self {
+		Self {
+			collator: selected.collator.into(),
+			total_counted: selected.total_counted.into(),
+			delegator_count: selected.delegator_count,
+		}
+	}
+}
+
+/// An RPC endpoint to query delegators and collator candidates in Moonbeam
+#[rpc(server)]
+#[async_trait::async_trait]
+pub trait MoonbeamStakingApi {
+	/// Returns the delegations of a delegator, with their auto-compound percentage, pending
+	/// requests and placement among the delegations of the candidate.
+	/// Returns null if the account is not delegating.
+	#[method(name = "staking_getDelegatorPosition")]
+	async fn get_delegator_position(
+		&self,
+		delegator: H160,
+		at: Option<H256>,
+	) -> RpcResult<Option<DelegatorPosition>>;
+
+	/// Returns the summary of a collator candidate.
+	/// Returns null if the account is not a candidate.
+	#[method(name = "staking_getCollatorSummary")]
+	async fn get_collator_summary(
+		&self,
+		candidate: H160,
+		at: Option<H256>,
+	) -> RpcResult<Option<CollatorSummary>>;
+
+	/// Returns the rewards projected for a new delegation of `stake` to a collator, per round and
+	/// per year, with the current inflation and commission.
+	/// Returns null if the account is not a candidate.
+	#[method(name = "staking_getProjectedRewards")]
+	async fn get_projected_rewards(
+		&self,
+		collator: H160,
+		stake: U256,
+		at: Option<H256>,
+	) -> RpcResult<Option<ProjectedRewards>>;
+
+	/// Returns the collators selected for a round, with their counted stake and delegator count
+	/// when they were selected.
+	/// Returns an empty list if the round is not kept in the selection history.
+	#[method(name = "staking_getRoundSelection")]
+	async fn get_round_selection(
+		&self,
+		round: u32,
+		at: Option<H256>,
+	) -> RpcResult<Vec<SelectedCollator>>;
+}
+
+pub struct MoonbeamStaking<B: Block, C, AccountId, Balance> {
+	pub client: Arc<C>,
+	_phdata: PhantomData<(B, AccountId, Balance)>,
+}
+
+impl<B: Block, C, AccountId, Balance> MoonbeamStaking<B, C, AccountId, Balance> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_phdata: Default::default(),
+		}
+	}
+}
+
+fn internal_err<T: ToString>(message: T) -> jsonrpsee::types::ErrorObjectOwned {
+	ErrorObject::owned(
+		jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+		message.to_string(),
+		None::<()>,
+	)
+}
+
+#[async_trait::async_trait]
+impl<B, C, AccountId, Balance> MoonbeamStakingApiServer
+	for MoonbeamStaking<B, C, AccountId, Balance>
+where
+	B: Block<Hash = H256>,
+	C: ProvideRuntimeApi<B> + HeaderBackend<B> + Send + Sync + 'static,
+	C::Api: ParachainStakingApi<B, AccountId, Balance>,
+	AccountId: Codec + From<H160> + Into<H160> + Send + Sync + 'static,
+	Balance: Codec + Into<U256> + TryFrom<U256> + Send + Sync + 'static,
+{
+	async fn get_delegator_position(
+		&self,
+		delegator: H160,
+		at: Option<H256>,
+	) -> RpcResult<Option<DelegatorPosition>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let position = self
+			.client
+			.runtime_api()
+			.delegator_position(at, delegator.into())
+			.map_err(internal_err)?;
+		Ok(position.map(Into::into))
+	}
+
+	async fn get_collator_summary(
+		&self,
+		candidate: H160,
+		at: Option<H256>,
+	) -> RpcResult<Option<CollatorSummary>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let summary = self
+			.client
+			.runtime_api()
+			.collator_summary(at, candidate.into())
+			.map_err(internal_err)?;
+		Ok(summary.map(Into::into))
+	}
+
+	async fn get_projected_rewards(
+		&self,
+		collator: H160,
+		stake: U256,
+		at: Option<H256>,
+	) -> RpcResult<Option<ProjectedRewards>> {
+		let stake = Balance::try_from(stake).map_err(|_| {
+			ErrorObject::owned(
+				jsonrpsee::types::error::INVALID_PARAMS_CODE,
+				"Stake is too large",
+				None::<()>,
+			)
+		})?;
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let rewards = self
+			.client
+			.runtime_api()
+			.projected_rewards(at, collator.into(), stake)
+			.map_err(internal_err)?;
+		Ok(rewards.map(Into::into))
+	}
+
+	async fn get_round_selection(
+		&self,
+		round: u32,
+		at: Option<H256>,
+	) -> RpcResult<Vec<SelectedCollator>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let selection = self
+			.client
+			.runtime_api()
+			.round_selection(at, round)
+			.map_err(internal_err)?;
+		Ok(selection.into_iter().map(Into::into).collect())
+	}
+}
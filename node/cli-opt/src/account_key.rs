@@ -21,7 +21,8 @@ use bip32::{
 use bip39::{Language, Mnemonic, MnemonicType, Seed};
 use clap::{Parser, ValueEnum};
 use libsecp256k1::{PublicKey, SecretKey};
-use primitive_types::H256;
+use primitive_types::{H256, U256};
+use sp_core::{crypto::Pair as _, hashing::keccak_256, hexdisplay::HexDisplay, sr25519};
 use sp_runtime::traits::IdentifyAccount;
 
 #[derive(Debug, Clone, ValueEnum)]
@@ -58,56 +59,224 @@ pub struct GenerateAccountKey {
 	#[clap(long = "account-index", short = 'a')]
 	account_index: Option<u32>,
 
+	/// The number of consecutive accounts to derive, starting at the account index
+	#[clap(
+		long,
+		short = 'c',
+		default_value = "1",
+		value_parser = clap::value_parser!(u32).range(1..)
+	)]
+	count: u32,
+
 	/// The network to use for derivation path
 	#[clap(long, short = 'n', default_value = "moonbeam")]
 	pub network: Network,
+
+	/// Output the accounts as JSON
+	#[clap(long)]
+	json: bool,
+}
+
+/// An account derived from a mnemonic.
+pub struct DerivedAccount {
+	pub index: u32,
+	pub path: String,
+	pub address: account::AccountId20,
+	pub private_key: H256,
+}
+
+/// Derive the account at `index` of the given network from a BIP39 seed.
+pub fn derive_account(seed: &Seed, network: &Network, index: u32) -> DerivedAccount {
+	let path = format!("m/44'/{}'/0'/0/{}", network.coin_type(), index);
+	let derivation_path = path.parse().expect("derivation path is valid; qed");
+	let private_key =
+		ExtendedPrivateKey::<Secp256k1SecretKey>::derive_from_path(seed, &derivation_path)
+			.expect("invalid extended private key")
+			.private_key()
+			.0;
+
+	// Convert into Ethereum-style address.
+	let signer: account::EthereumSigner = PublicKey::from_secret_key(&private_key).into();
+
+	DerivedAccount {
+		index,
+		path,
+		address: signer.into_account(),
+		private_key: H256::from(private_key.serialize()),
+	}
+}
+
+/// Private key of the account at `index` of the given network, derived from a mnemonic.
+pub fn private_key_from_mnemonic(
+	phrase: &str,
+	network: &Network,
+	index: u32,
+) -> Result<H256, String> {
+	let mnemonic = Mnemonic::from_phrase(phrase, Language::English)
+		.map_err(|e| format!("Invalid mnemonic: {}", e))?;
+
+	Ok(derive_account(&Seed::new(&mnemonic, ""), network, index).private_key)
+}
+
+/// Parse the given mnemonic, or generate a random one.
+fn mnemonic_or_random(phrase: Option<&String>, w12: bool) -> Mnemonic {
+	match phrase {
+		Some(phrase) => Mnemonic::from_phrase(phrase, Language::English).expect("invalid mnemonic"),
+		None => match w12 {
+			true => Mnemonic::new(MnemonicType::Words12, Language::English),
+			false => Mnemonic::new(MnemonicType::Words24, Language::English),
+		},
+	}
 }
 
 impl GenerateAccountKey {
 	pub fn run(&self) {
 		// Retrieve the mnemonic from the args or generate random ones
-		let mnemonic = if let Some(phrase) = &self.mnemonic {
-			Mnemonic::from_phrase(phrase, Language::English).expect("invalid mnemonic")
-		} else {
-			match self.w12 {
-				true => Mnemonic::new(MnemonicType::Words12, Language::English),
-				false => Mnemonic::new(MnemonicType::Words24, Language::English),
-			}
-		};
+		let mnemonic = mnemonic_or_random(self.mnemonic.as_ref(), self.w12);
 
 		// Retrieves the seed from the mnemonic
 		let seed = Seed::new(&mnemonic, "");
-		// Use network parameter to determine chain ID for derivation path
-		let derivation_path = format!(
-			"m/44'/{}'/0'/0/{}",
-			self.network.coin_type(),
-			self.account_index.unwrap_or(0)
-		);
-		let private_key = if let Some(private_key) =
-			derivation_path.parse().ok().and_then(|derivation_path| {
-				let extended = ExtendedPrivateKey::<Secp256k1SecretKey>::derive_from_path(
-					&seed,
-					&derivation_path,
-				)
-				.expect("invalid extended private key");
-				Some(extended.private_key().0)
-			}) {
-			private_key
-		} else {
-			panic!("invalid extended private key");
+		let first_index = self.account_index.unwrap_or(0);
+		let accounts = (0..self.count)
+			.map(|offset| derive_account(&seed, &self.network, first_index.saturating_add(offset)));
+
+		if self.json {
+			let accounts: Vec<_> = accounts
+				.map(|account| {
+					serde_json::json!({
+						"index": account.index,
+						"path": account.path,
+						"address": format!("{:?}", account.address),
+						"privateKey": format!("{:?}", account.private_key),
+					})
+				})
+				.collect();
+			let output = serde_json::json!({
+				"mnemonic": mnemonic.phrase(),
+				"accounts": accounts,
+			});
+			println!(
+				"{}",
+				serde_json::to_string_pretty(&output).expect("JSON value is serializable; qed")
+			);
+			return;
+		}
+
+		for (position, account) in accounts.enumerate() {
+			if position > 0 {
+				println!();
+			}
+			println!("Address:      {:?}", account.address);
+			if position == 0 {
+				println!("Mnemonic:     {}", mnemonic.phrase());
+			}
+			println!("Private Key:  {:?}", account.private_key);
+			println!("Path:         {}", account.path);
+		}
+	}
+}
+
+/// Nimbus and VRF session keys of a collator.
+pub struct SessionKeys {
+	pub nimbus_suri: String,
+	pub nimbus_id: sr25519::Public,
+	pub vrf_suri: String,
+	pub vrf_id: sr25519::Public,
+}
+
+impl SessionKeys {
+	/// Derive the session keys from a secret URI, with the `//nimbus` and `//vrf` hard
+	/// junctions respectively.
+	pub fn derive(suri: &str) -> Result<Self, String> {
+		let nimbus_suri = format!("{}//nimbus", suri);
+		let vrf_suri = format!("{}//vrf", suri);
+		let public = |suri: &str| {
+			sr25519::Pair::from_string(suri, None)
+				.map(|pair| pair.public())
+				.map_err(|e| format!("Invalid secret URI: {:?}", e))
 		};
 
-		// Retrieves the public key
-		let public_key = PublicKey::from_secret_key(&private_key);
+		Ok(Self {
+			nimbus_id: public(&nimbus_suri)?,
+			nimbus_suri,
+			vrf_id: public(&vrf_suri)?,
+			vrf_suri,
+		})
+	}
+
+	/// Keys as expected by `AuthorMapping::set_keys`: the NimbusId followed by the VrfId.
+	pub fn encoded(&self) -> Vec<u8> {
+		[self.nimbus_id.0, self.vrf_id.0].concat()
+	}
+
+	/// Call data of `setKeys(bytes)` of the author mapping precompile.
+	pub fn set_keys_call_data(&self) -> Vec<u8> {
+		let keys = self.encoded();
+		let mut call_data = keccak_256(b"setKeys(bytes)")[..4].to_vec();
+		// Offset of the bytes argument, followed by its length and its padded content
+		call_data.extend_from_slice(&U256::from(32).to_big_endian());
+		call_data.extend_from_slice(&U256::from(keys.len()).to_big_endian());
+		call_data.extend_from_slice(&keys);
+		call_data.resize(call_data.len() + (32 - keys.len() % 32) % 32, 0);
+		call_data
+	}
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct GenerateSessionKeys {
+	/// The secret URI (mnemonic, seed or derivation) the session keys are derived from.
+	/// A random mnemonic is generated if not provided.
+	#[clap(long)]
+	pub suri: Option<String>,
+
+	/// Generate 12 words mnemonic instead of 24, when no secret URI is provided
+	#[clap(long, short = 'w')]
+	pub w12: bool,
+
+	/// Output the keys as JSON
+	#[clap(long)]
+	pub json: bool,
+}
+
+impl GenerateSessionKeys {
+	/// The secret URI of the session keys, generated if not provided.
+	pub fn suri(&self) -> String {
+		match &self.suri {
+			Some(suri) => suri.clone(),
+			None => mnemonic_or_random(None, self.w12).phrase().to_string(),
+		}
+	}
+
+	/// Print the session keys derived from `suri`.
+	pub fn print(&self, suri: &str, keys: &SessionKeys) {
+		let to_hex = |bytes: &[u8]| format!("0x{}", HexDisplay::from(&bytes));
 
-		// Convert into Ethereum-style address.
-		let signer: account::EthereumSigner = public_key.into();
-		let address = signer.into_account();
+		if self.json {
+			let mut output = serde_json::json!({
+				"nimbusId": to_hex(&keys.nimbus_id.0),
+				"nimbusSuri": keys.nimbus_suri,
+				"vrfId": to_hex(&keys.vrf_id.0),
+				"vrfSuri": keys.vrf_suri,
+				"keys": to_hex(&keys.encoded()),
+				"setKeysCallData": to_hex(&keys.set_keys_call_data()),
+			});
+			if self.suri.is_none() {
+				output["mnemonic"] = suri.into();
+			}
+			println!(
+				"{}",
+				serde_json::to_string_pretty(&output).expect("JSON value is serializable; qed")
+			);
+			return;
+		}
 
-		println!("Address:      {:?}", address);
-		println!("Mnemonic:     {}", mnemonic.phrase());
-		println!("Private Key:  {:?}", H256::from(private_key.serialize()));
-		println!("Path:         {}", derivation_path);
+		if self.suri.is_none() {
+			println!("Mnemonic:           {}", suri);
+		}
+		println!("NimbusId:           {}", to_hex(&keys.nimbus_id.0));
+		println!("VrfId:              {}", to_hex(&keys.vrf_id.0));
+		println!("Keys:               {}", to_hex(&keys.encoded()));
+		println!("setKeys call data:  {}", to_hex(&keys.set_keys_call_data()));
 	}
 }
 
@@ -157,3 +326,106 @@ impl PrivateKeyT for Secp256k1SecretKey {
 		Secp256k1PublicKey(PublicKey::from_secret_key(&self.0))
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const DEV_MNEMONIC: &str =
+		"bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+	fn derive(network: Network, index: u32) -> (String, String, String) {
+		let mnemonic = Mnemonic::from_phrase(DEV_MNEMONIC, Language::English).unwrap();
+		let account = derive_account(&Seed::new(&mnemonic, ""), &network, index);
+		(
+			account.path,
+			format!("{:?}", account.address),
+			format!("{:?}", account.private_key),
+		)
+	}
+
+	#[test]
+	fn derives_dev_accounts() {
+		assert_eq!(
+			derive(Network::Ethereum, 0),
+			(
+				"m/44'/60'/0'/0/0".to_string(),
+				"0xf24ff3a9cf04c71dbc94d0b566f7a27b94566cac".to_string(),
+				"0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133".to_string(),
+			)
+		);
+		assert_eq!(
+			derive(Network::Ethereum, 1),
+			(
+				"m/44'/60'/0'/0/1".to_string(),
+				"0x3cd0a705a2dc65e5b1e1205896baa2be8a07c6e0".to_string(),
+				"0x8075991ce870b93a8870eca0c0f91913d12f47948ca0fd25b49c6fa7cdbeee8b".to_string(),
+			)
+		);
+	}
+
+	#[test]
+	fn derives_with_network_coin_type() {
+		assert_eq!(
+			derive(Network::Moonbeam, 0),
+			(
+				"m/44'/1284'/0'/0/0".to_string(),
+				"0x1330640771e7fd9bedb5c6e07e5d35176b7894d0".to_string(),
+				"0xc2f4c1c3e0f1da126229e5226c7786636a46d32e6b9e977ade5053599a44c495".to_string(),
+			)
+		);
+		assert_eq!(
+			derive(Network::Moonbeam, 1),
+			(
+				"m/44'/1284'/0'/0/1".to_string(),
+				"0x33d09e6c60cc23dabcd61f60e6d1651d736211ec".to_string(),
+				"0x63e0c39374bafb14966204a1ed0b3a82dca779a712550e8b2a8976181639c4d6".to_string(),
+			)
+		);
+		assert_eq!(
+			private_key_from_mnemonic(DEV_MNEMONIC, &Network::Moonbeam, 1)
+				.map(|key| format!("{:?}", key)),
+			Ok("0x63e0c39374bafb14966204a1ed0b3a82dca779a712550e8b2a8976181639c4d6".to_string())
+		);
+	}
+
+	#[test]
+	fn rejects_zero_count() {
+		assert!(GenerateAccountKey::try_parse_from(["key", "--count", "0"]).is_err());
+		assert!(GenerateAccountKey::try_parse_from(["key", "--count", "2"]).is_ok());
+	}
+
+	#[test]
+	fn set_keys_call_data_is_abi_encoded() {
+		let keys = SessionKeys {
+			nimbus_suri: "//nimbus".to_string(),
+			nimbus_id: sr25519::Public::from_raw([1; 32]),
+			vrf_suri: "//vrf".to_string(),
+			vrf_id: sr25519::Public::from_raw([2; 32]),
+		};
+
+		// `setKeys(bytes)` selector, offset of the bytes, their length and the keys
+		let mut expected = vec![0xf1, 0xec, 0x91, 0x9c];
+		expected.extend([0; 31]);
+		expected.push(0x20);
+		expected.extend([0; 31]);
+		expected.push(0x40);
+		expected.extend([1; 32]);
+		expected.extend([2; 32]);
+
+		assert_eq!(keys.set_keys_call_data(), expected);
+	}
+
+	#[test]
+	fn session_keys_use_distinct_junctions() {
+		let keys = SessionKeys::derive("//Alice").unwrap();
+		let public = |suri: &str| sr25519::Pair::from_string(suri, None).unwrap().public();
+
+		assert_eq!(keys.nimbus_suri, "//Alice//nimbus");
+		assert_eq!(keys.nimbus_id, public("//Alice//nimbus"));
+		assert_eq!(keys.vrf_suri, "//Alice//vrf");
+		assert_eq!(keys.vrf_id, public("//Alice//vrf"));
+		assert_ne!(keys.nimbus_id, keys.vrf_id);
+		assert_eq!(keys.encoded(), [keys.nimbus_id.0, keys.vrf_id.0].concat());
+	}
+}
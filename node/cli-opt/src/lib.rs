@@ -119,11 +119,14 @@ pub struct RpcConfig {
 #[derive(Clone)]
 pub struct LazyLoadingConfig {
 	pub state_rpc: url::Url,
+	pub fallback_rpcs: Vec<url::Url>,
+	pub rpc_headers: Vec<(String, String)>,
 	pub from_block: Option<H256>,
 	pub state_overrides_path: Option<PathBuf>,
 	pub runtime_override: Option<PathBuf>,
 	pub delay_between_requests: u32,
 	pub max_retries_per_request: u32,
+	pub dev_relay_state: Option<PathBuf>,
 }
 /// Extra args that are passed when creating a new node spec.
 #[derive(Clone)]
@@ -142,4 +145,11 @@ pub struct NodeExtraArgs {
 
 	/// Enable the legacy block import strategy
 	pub legacy_block_import_strategy: bool,
+
+	/// Dev service only: sibling dev nodes, by parachain id, exchanging HRMP messages with
+	/// this node.
+	pub dev_hrmp_peers: Vec<(u32, url::Url)>,
+
+	/// Dev service only: relay storage entries to include in the mocked relay state proofs.
+	pub dev_relay_state: Option<PathBuf>,
 }
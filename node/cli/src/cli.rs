@@ -21,11 +21,13 @@
 
 use clap::Parser;
 use moonbeam_cli_opt::{
-	account_key::{GenerateAccountKey, Network},
+	account_key::{GenerateAccountKey, GenerateSessionKeys, Network, SessionKeys},
 	AuthoringPolicy, EthApi, FrontierBackendType, NodeExtraArgs, Sealing,
 };
 use moonbeam_service::chain_spec;
 use sc_cli::{Error as CliError, SubstrateCli};
+use sp_keystore::Keystore;
+use sp_runtime::RuntimeAppPublic;
 use std::path::PathBuf;
 use std::time::Duration;
 use url::Url;
@@ -36,11 +38,35 @@ fn parse_block_hash(s: &str) -> Result<sp_core::H256, String> {
 	sp_core::H256::from_str(s).map_err(|err| err.to_string())
 }
 
+#[cfg(feature = "lazy-loading")]
+fn parse_header(s: &str) -> Result<(String, String), String> {
+	let (name, value) = s
+		.split_once(':')
+		.ok_or(format!("'{}' is not a `name: value` header", s))?;
+	Ok((name.trim().to_string(), value.trim().to_string()))
+}
+
+fn parse_hrmp_peer(arg: &str) -> Result<(u32, Url), String> {
+	let (para_id, url) = arg
+		.split_once('=')
+		.ok_or(format!("'{}' is not a `PARA_ID=URL` peer", arg))?;
+	let para_id = para_id
+		.trim()
+		.parse()
+		.map_err(|e| format!("Invalid parachain id: {}", e))?;
+	let url = Url::parse(url.trim()).map_err(|e| e.to_string())?;
+
+	match url.scheme() {
+		"http" | "https" => Ok((para_id, url)),
+		scheme => Err(format!("'{}' URL scheme not supported.", scheme)),
+	}
+}
+
 fn validate_url(arg: &str) -> Result<Url, String> {
 	let url = Url::parse(arg).map_err(|e| e.to_string())?;
 
 	let scheme = url.scheme();
-	if scheme == "http" || scheme == "https" {
+	if ["http", "https", "ws", "wss"].contains(&scheme) {
 		Ok(url)
 	} else {
 		Err(format!("'{}' URL scheme not supported.", url.scheme()))
@@ -70,6 +96,9 @@ pub enum Subcommand {
 	/// Export the state of a given block into a chain spec.
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Export the balance, nonce, code and storage of EVM accounts at a given block.
+	ExportEvmState(ExportEvmStateCommand),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -90,6 +119,10 @@ pub enum Subcommand {
 
 	/// Precompile the WASM runtime into native code
 	PrecompileWasm(sc_cli::PrecompileWasmCmd),
+
+	/// Sign a transaction offline, with the embedded runtimes
+	#[clap(subcommand)]
+	SignTransaction(crate::sign_transaction::SignTransactionCmd),
 }
 
 #[derive(Debug, Parser)]
@@ -106,6 +139,34 @@ pub struct BuildSpecCommand {
 	/// Warning: This flag implies a development spec and overrides any explicitly supplied spec
 	#[clap(long, conflicts_with = "chain")]
 	pub mnemonic: Option<String>,
+
+	/// EVM state file, produced by the `export-evm-state` subcommand, whose accounts are added
+	/// to the genesis
+	/// Warning: This flag implies a development spec and overrides any explicitly supplied spec
+	#[clap(long, value_name = "PATH", conflicts_with = "chain")]
+	pub evm_state: Option<PathBuf>,
+
+	/// Genesis overlay file declaring funded accounts, collators and their delegations, foreign
+	/// assets, fee payment assets and contracts to add to the genesis
+	/// Warning: This flag implies a development spec and overrides any explicitly supplied spec
+	#[clap(long, value_name = "PATH", conflicts_with = "chain")]
+	pub genesis_overlay: Option<PathBuf>,
+}
+
+/// Command for exporting EVM accounts into a file that can be imported in a development genesis
+/// or used as lazy loading state overrides.
+#[derive(Debug, Parser)]
+pub struct ExportEvmStateCommand {
+	#[clap(flatten)]
+	pub base: sc_cli::ExportStateCmd,
+
+	/// Address of an EVM account to export. Can be repeated.
+	#[clap(long = "address", value_name = "ADDRESS", required = true)]
+	pub addresses: Vec<sp_core::H160>,
+
+	/// Output file name or stdout if unspecified.
+	#[clap(long, value_name = "PATH")]
+	pub output: Option<PathBuf>,
 }
 
 /// Command for exporting the genesis wasm file.
@@ -156,6 +217,26 @@ pub struct RunCmd {
 	)]
 	pub lazy_loading_remote_rpc: Option<Url>,
 
+	/// Fallback RPC endpoints used when the remote RPC becomes unavailable.
+	///
+	/// Endpoints are health-checked periodically and requests switch to the next healthy one
+	/// whenever the active endpoint fails.
+	#[cfg(feature = "lazy-loading")]
+	#[arg(
+		long,
+		value_name = "URL",
+		value_parser = validate_url,
+		requires = "lazy_loading_remote_rpc"
+	)]
+	pub lazy_loading_fallback_rpc: Vec<Url>,
+
+	/// Custom header sent with every lazy loading RPC request, as `name: value`.
+	///
+	/// Can be used multiple times, e.g. to authenticate with an RPC provider requiring an API key.
+	#[cfg(feature = "lazy-loading")]
+	#[arg(long, value_name = "HEADER", value_parser = parse_header)]
+	pub lazy_loading_rpc_header: Vec<(String, String)>,
+
 	/// Optional parameter to specify the block hash for lazy loading.
 	///
 	/// This parameter allows the user to specify a block hash from which to start loading data.
@@ -173,7 +254,8 @@ pub struct RunCmd {
 	/// Optional parameter to specify state overrides during lazy loading.
 	///
 	/// This parameter allows the user to provide a path to a file containing state overrides.
-	/// The file can contain any custom state modifications that should be applied.
+	/// The file can contain any custom state modifications that should be applied, or be an
+	/// EVM state file produced by the `export-evm-state` subcommand.
 	#[cfg(feature = "lazy-loading")]
 	#[clap(
 		long,
@@ -207,6 +289,18 @@ pub struct RunCmd {
 	#[clap(long, default_value = "10")]
 	pub lazy_loading_max_retries_per_request: u32,
 
+	/// Replay the upstream blocks following the fork block, up to the given block number, then exit.
+	///
+	/// Each block is executed locally (with the runtime override if provided) and the storage
+	/// it writes and the events it emits are compared against the upstream chain.
+	#[cfg(feature = "lazy-loading")]
+	#[clap(
+		long,
+		value_name = "BLOCK_NUMBER",
+		requires = "lazy_loading_remote_rpc"
+	)]
+	pub lazy_loading_replay_to: Option<u32>,
+
 	/// When blocks should be sealed in the dev service.
 	///
 	/// Options are "instant", "manual", or timer interval in milliseconds
@@ -291,6 +385,19 @@ pub struct RunCmd {
 	#[clap(long)]
 	pub parachain_id: Option<u32>,
 
+	/// Sibling dev node to exchange HRMP messages with, as `PARA_ID=URL` where `URL` is the
+	/// http(s) RPC endpoint of the peer. The HRMP messages sent by this node to `PARA_ID` are
+	/// injected into the peer, as if they came through the relay chain. Can be passed multiple
+	/// times. Only for the development service, with a distinct `--parachain-id` per node.
+	#[clap(long, value_name = "PARA_ID=URL", value_parser = parse_hrmp_peer)]
+	pub dev_hrmp_peer: Vec<(u32, Url)>,
+
+	/// JSON file of raw relay storage entries to include in the mocked relay state proofs of
+	/// the development service and of the lazy loading mode, formatted as `{ "storage": { "0x<key>": "0x<value>" } }`.
+	/// The entries can be updated at runtime with the `test_setRelayStorage` RPC.
+	#[clap(long, value_name = "PATH")]
+	pub dev_relay_state: Option<PathBuf>,
+
 	/// Maximum fee history cache size.
 	#[clap(long, default_value = "2048")]
 	pub fee_history_limit: u64,
@@ -389,6 +496,66 @@ pub enum KeyCmd {
 	GenerateAccountKey(GenerateAccountKey),
 	/// Generate a Moonbeam account.
 	GenerateMoonbeamKey(GenerateAccountKey),
+	/// Generate the Nimbus and VRF session keys of a collator, optionally inserting them into
+	/// the node keystore.
+	GenerateSessionKeys(SessionKeysCmd),
+}
+
+#[derive(Debug, Clone, Parser)]
+pub struct SessionKeysCmd {
+	#[clap(flatten)]
+	pub keys: GenerateSessionKeys,
+
+	/// Insert the session keys into the keystore of the node.
+	#[clap(long)]
+	pub insert: bool,
+
+	#[clap(flatten)]
+	pub shared_params: sc_cli::SharedParams,
+
+	#[clap(flatten)]
+	pub keystore_params: sc_cli::KeystoreParams,
+}
+
+impl SessionKeysCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), CliError> {
+		let suri = self.keys.suri();
+		let keys = SessionKeys::derive(&suri).map_err(CliError::Input)?;
+
+		if self.insert {
+			let base_path = self.shared_params.base_path()?.unwrap_or_else(|| {
+				sc_service::BasePath::from_project("", "", &C::executable_name())
+			});
+			let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+			let chain_spec = cli.load_spec(&chain_id)?;
+			let config_dir = base_path.config_dir(chain_spec.id());
+
+			let keystore = match self.keystore_params.keystore_config(&config_dir)? {
+				sc_service::config::KeystoreConfig::Path { path, password } => {
+					sc_keystore::LocalKeystore::open(path, password)?
+				}
+				_ => unreachable!("keystore_config always returns path and password; qed"),
+			};
+
+			keystore
+				.insert(
+					nimbus_primitives::NimbusId::ID,
+					&keys.nimbus_suri,
+					&keys.nimbus_id.0,
+				)
+				.map_err(|_| CliError::KeystoreOperation)?;
+			keystore
+				.insert(
+					session_keys_primitives::VrfId::ID,
+					&keys.vrf_suri,
+					&keys.vrf_id.0,
+				)
+				.map_err(|_| CliError::KeystoreOperation)?;
+		}
+
+		self.keys.print(&suri, &keys);
+		Ok(())
+	}
 }
 
 impl KeyCmd {
@@ -420,6 +587,7 @@ impl KeyCmd {
 				cmd.run();
 				Ok(())
 			}
+			KeyCmd::GenerateSessionKeys(cmd) => cmd.run(cli),
 		}
 	}
 }
@@ -449,6 +617,8 @@ impl Cli {
 			export_pov: self.run.export_pov_to_path.clone(),
 			max_pov_percentage: Some(self.run.max_pov_percentage),
 			legacy_block_import_strategy: self.run.legacy_block_import_strategy,
+			dev_hrmp_peers: self.run.dev_hrmp_peer.clone(),
+			dev_relay_state: self.run.dev_relay_state.clone(),
 		}
 	}
 }
@@ -40,7 +40,7 @@ use sc_cli::{
 };
 use sc_service::{
 	config::{BasePath, PrometheusConfig},
-	DatabaseSource, PartialComponents,
+	DatabaseSource, PartialComponents, TaskManager,
 };
 use sp_core::hexdisplay::HexDisplay;
 use sp_runtime::{
@@ -64,9 +64,9 @@ fn load_spec(
 		#[cfg(feature = "moonbase-native")]
 		"moonbase-local" => Box::new(chain_spec::moonbase::get_chain_spec(para_id)),
 		#[cfg(feature = "moonbase-native")]
-		"moonbase-dev" | "dev" | "development" => {
-			Box::new(chain_spec::moonbase::development_chain_spec(None, None))
-		}
+		"moonbase-dev" | "dev" | "development" => Box::new(chain_spec::moonbase::development_chain_spec(
+			None, None, None,
+		)),
 		#[cfg(feature = "moonbeam-native")]
 		"staking" => Box::new(chain_spec::moonbeam::get_chain_spec(para_id)),
 		// Moonriver networks
@@ -74,7 +74,9 @@ fn load_spec(
 			&include_bytes!("../../../specs/moonriver/parachain-embedded-specs.json")[..],
 		)?),
 		#[cfg(feature = "moonriver-native")]
-		"moonriver-dev" => Box::new(chain_spec::moonriver::development_chain_spec(None, None)),
+		"moonriver-dev" => Box::new(chain_spec::moonriver::development_chain_spec(
+			None, None, None,
+		)),
 		#[cfg(feature = "moonriver-native")]
 		"moonriver-local" => Box::new(chain_spec::moonriver::get_chain_spec(para_id)),
 
@@ -83,7 +85,9 @@ fn load_spec(
 			&include_bytes!("../../../specs/moonbeam/parachain-embedded-specs.json")[..],
 		)?),
 		#[cfg(feature = "moonbeam-native")]
-		"moonbeam-dev" => Box::new(chain_spec::moonbeam::development_chain_spec(None, None)),
+		"moonbeam-dev" => Box::new(chain_spec::moonbeam::development_chain_spec(
+			None, None, None,
+		)),
 		#[cfg(feature = "moonbeam-native")]
 		"moonbeam-local" => Box::new(chain_spec::moonbeam::get_chain_spec(para_id)),
 
@@ -210,6 +214,24 @@ fn validate_trace_environment(cli: &Cli) -> Result<()> {
 	Ok(())
 }
 
+#[cfg(feature = "lazy-loading")]
+fn lazy_loading_config(run_cmd: &RunCmd) -> Option<moonbeam_cli_opt::LazyLoadingConfig> {
+	run_cmd
+		.lazy_loading_remote_rpc
+		.clone()
+		.map(|state_rpc| moonbeam_cli_opt::LazyLoadingConfig {
+			state_rpc,
+			fallback_rpcs: run_cmd.lazy_loading_fallback_rpc.clone(),
+			rpc_headers: run_cmd.lazy_loading_rpc_header.clone(),
+			from_block: run_cmd.lazy_loading_block,
+			state_overrides_path: run_cmd.lazy_loading_state_overrides.clone(),
+			runtime_override: run_cmd.lazy_loading_runtime_override.clone(),
+			delay_between_requests: run_cmd.lazy_loading_delay_between_requests,
+			max_retries_per_request: run_cmd.lazy_loading_max_retries_per_request,
+			dev_relay_state: run_cmd.dev_relay_state.clone(),
+		})
+}
+
 /// Parse command line arguments into service configuration.
 pub fn run() -> Result<()> {
 	let cli = Cli::from_args();
@@ -219,12 +241,28 @@ pub fn run() -> Result<()> {
 		Some(Subcommand::BuildSpec(params)) => {
 			let runner = cli.create_runner(&params.base)?;
 			runner.sync_run(|config| {
-				if params.mnemonic.is_some() || params.accounts.is_some() {
+				if params.mnemonic.is_some()
+					|| params.accounts.is_some()
+					|| params.evm_state.is_some()
+					|| params.genesis_overlay.is_some()
+				{
+					let evm_state = params
+						.evm_state
+						.as_deref()
+						.map(moonbeam_service::evm_state::read)
+						.transpose()?;
+					let overlay = params
+						.genesis_overlay
+						.as_deref()
+						.map(chain_spec::genesis_overlay::read)
+						.transpose()?;
 					if config.chain_spec.is_moonbeam() {
 						params.base.run(
 							Box::new(chain_spec::moonbeam::development_chain_spec(
 								params.mnemonic.clone(),
 								params.accounts,
+								evm_state,
+								overlay,
 							)),
 							config.network,
 						)
@@ -233,6 +271,8 @@ pub fn run() -> Result<()> {
 							Box::new(chain_spec::moonriver::development_chain_spec(
 								params.mnemonic.clone(),
 								params.accounts,
+								evm_state,
+								overlay,
 							)),
 							config.network,
 						)
@@ -241,6 +281,8 @@ pub fn run() -> Result<()> {
 							Box::new(chain_spec::moonbase::development_chain_spec(
 								params.mnemonic.clone(),
 								params.accounts,
+								evm_state,
+								overlay,
 							)),
 							config.network,
 						)
@@ -286,6 +328,38 @@ pub fn run() -> Result<()> {
 				Ok((cmd.run(client, config.chain_spec), task_manager))
 			})
 		}
+		Some(Subcommand::ExportEvmState(cmd)) => {
+			let runner = cli.create_runner(&cmd.base)?;
+			let rpc_config = cli.run.new_rpc_config();
+			runner.async_run(|mut config| {
+				let (client, _, _, task_manager) = moonbeam_service::new_chain_ops(
+					&mut config,
+					&rpc_config,
+					cli.node_extra_args(),
+				)?;
+				let at = cmd
+					.base
+					.input
+					.as_ref()
+					.map(|input| input.parse::<moonbeam_core_primitives::Block>())
+					.transpose()?;
+				Ok((
+					async move {
+						let state =
+							moonbeam_service::evm_state::export(&*client, at, &cmd.addresses)?;
+						let json = serde_json::to_vec_pretty(&state)
+							.map_err(|e| format!("Failed to serialize the EVM state: {}", e))?;
+						if let Some(output) = &cmd.output {
+							std::fs::write(output, json)?;
+						} else {
+							std::io::stdout().write_all(&json)?;
+						}
+						Ok::<_, sc_cli::Error>(())
+					},
+					task_manager,
+				))
+			})
+		}
 		Some(Subcommand::ImportBlocks(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			let rpc_config = cli.run.new_rpc_config();
@@ -604,6 +678,7 @@ pub fn run() -> Result<()> {
 			}
 		}
 		Some(Subcommand::Key(cmd)) => Ok(cmd.run(&cli)?),
+		Some(Subcommand::SignTransaction(cmd)) => Ok(cmd.run()?),
 		Some(Subcommand::PrecompileWasm(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 			let rpc_config = cli.run.new_rpc_config();
@@ -655,6 +730,26 @@ pub fn run() -> Result<()> {
 			let runner = cli.create_runner(&(*cli.run).normalize())?;
 			let collator_options = cli.run.collator_options();
 
+			#[cfg(feature = "lazy-loading")]
+			if let (Some(lazy_loading_config), Some(replay_to)) = (
+				lazy_loading_config(&cli.run),
+				cli.run.lazy_loading_replay_to,
+			) {
+				return runner.async_run(|config| {
+					let task_manager = TaskManager::new(config.tokio_handle.clone(), None)?;
+					let replay = async move {
+						moonbeam_service::lazy_loading::replay_upstream_blocks(
+							config,
+							lazy_loading_config,
+							replay_to,
+						)
+						.await
+						.map_err(Into::into)
+					};
+					Ok((replay, task_manager))
+				});
+			}
+
 			runner.run_node_until_exit(|mut config| async move {
 				let hwbench = if !cli.run.no_hardware_benchmarks {
 					config.database.path().map(|database_path| {
@@ -746,20 +841,11 @@ pub fn run() -> Result<()> {
 					};
 				}
 				#[cfg(feature = "lazy-loading")]
-				if let Some(lazy_loading_remote_rpc) = cli.run.lazy_loading_remote_rpc {
+				if let Some(lazy_loading_config) = lazy_loading_config(&cli.run) {
 					let author_id = Some(chain_spec::get_from_seed::<nimbus_primitives::NimbusId>(
 						"Alice",
 					));
 
-					let lazy_loading_config = moonbeam_cli_opt::LazyLoadingConfig {
-						state_rpc: lazy_loading_remote_rpc,
-						from_block: cli.run.lazy_loading_block,
-						state_overrides_path: cli.run.lazy_loading_state_overrides,
-						runtime_override: cli.run.lazy_loading_runtime_override,
-						delay_between_requests: cli.run.lazy_loading_delay_between_requests,
-						max_retries_per_request: cli.run.lazy_loading_max_retries_per_request,
-					};
-
 					let spec_builder = lazy_loading::spec_builder();
 					config.chain_spec = Box::new(spec_builder.build());
 
@@ -0,0 +1,496 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Offline signing of Substrate extrinsics and Ethereum transactions.
+//!
+//! The transactions are built against the runtimes embedded in the node, without any RPC
+//! connection: the nonce, genesis hash and (for mortal extrinsics) checkpoint block must be
+//! provided by the caller.
+
+use clap::{Args, Parser, ValueEnum};
+use ethereum::{
+	eip1559::{EIP1559Transaction, EIP1559TransactionMessage, TransactionSignature},
+	EnvelopedEncodable, TransactionAction, TransactionV2,
+};
+use moonbeam_cli_opt::account_key::{private_key_from_mnemonic, Network};
+use parity_scale_codec::{Compact, DecodeAll, Encode};
+use sc_cli::Error as CliError;
+use sp_core::{
+	ecdsa,
+	hashing::{blake2_256, keccak_256},
+	hexdisplay::HexDisplay,
+	Bytes, Pair, H160, H256, U256,
+};
+use sp_runtime::{
+	generic::{Era, SignedPayload},
+	traits::{IdentifyAccount, TransactionExtension},
+};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Runtime {
+	Moonbeam,
+	Moonriver,
+	Moonbase,
+}
+
+impl Runtime {
+	fn network(&self) -> Network {
+		match self {
+			Runtime::Moonbeam => Network::Moonbeam,
+			Runtime::Moonriver => Network::Moonriver,
+			Runtime::Moonbase => Network::Moonbase,
+		}
+	}
+
+	/// Default EVM chain id of the public network using this runtime.
+	fn chain_id(&self) -> u64 {
+		match self {
+			Runtime::Moonbeam => 1284,
+			Runtime::Moonriver => 1285,
+			Runtime::Moonbase => 1287,
+		}
+	}
+}
+
+/// Run `$body` with `$r` aliased to the embedded runtime crate matching `$runtime`.
+macro_rules! with_runtime {
+	($runtime:expr, $r:ident => $body:expr) => {
+		match $runtime {
+			#[cfg(feature = "moonbeam-native")]
+			Runtime::Moonbeam => {
+				use moonbeam_service::moonbeam_runtime as $r;
+				$body
+			}
+			#[cfg(feature = "moonriver-native")]
+			Runtime::Moonriver => {
+				use moonbeam_service::moonriver_runtime as $r;
+				$body
+			}
+			#[cfg(feature = "moonbase-native")]
+			Runtime::Moonbase => {
+				use moonbeam_service::moonbase_runtime as $r;
+				$body
+			}
+			#[allow(unreachable_patterns)]
+			runtime => Err(CliError::Input(format!(
+				"The {:?} runtime is not included in this build",
+				runtime
+			))),
+		}
+	};
+}
+
+fn parse_h256(s: &str) -> Result<H256, String> {
+	H256::from_str(s).map_err(|e| e.to_string())
+}
+
+fn parse_h160(s: &str) -> Result<H160, String> {
+	H160::from_str(s).map_err(|e| e.to_string())
+}
+
+fn parse_u256(s: &str) -> Result<U256, String> {
+	match s.strip_prefix("0x") {
+		Some(hex) => U256::from_str_radix(hex, 16),
+		None => U256::from_dec_str(s),
+	}
+	.map_err(|e| format!("{:?}", e))
+}
+
+fn parse_bytes(s: &str) -> Result<Bytes, String> {
+	Bytes::from_str(s).map_err(|e| format!("{:?}", e))
+}
+
+/// Key signing the transaction.
+#[derive(Debug, Clone, Args)]
+#[group(required = true, multiple = false)]
+pub struct SignerParams {
+	/// Private key of the signer.
+	#[clap(long, value_parser = parse_h256)]
+	private_key: Option<H256>,
+
+	/// Mnemonic of the signer, derived with the derivation path of the runtime network.
+	#[clap(long)]
+	mnemonic: Option<String>,
+}
+
+impl SignerParams {
+	fn pair(&self, network: &Network, account_index: u32) -> Result<ecdsa::Pair, CliError> {
+		let private_key = match (&self.private_key, &self.mnemonic) {
+			(Some(private_key), _) => *private_key,
+			(None, Some(mnemonic)) => private_key_from_mnemonic(mnemonic, network, account_index)
+				.map_err(CliError::Input)?,
+			(None, None) => return Err(CliError::Input("No signer provided".into())),
+		};
+
+		ecdsa::Pair::from_seed_slice(private_key.as_bytes())
+			.map_err(|e| CliError::Input(format!("Invalid private key: {:?}", e)))
+	}
+}
+
+fn signer_address(pair: &ecdsa::Pair) -> account::AccountId20 {
+	account::EthereumSigner::from(pair.public()).into_account()
+}
+
+/// Sign a transaction offline and print it hex encoded, ready to be broadcast.
+#[derive(Debug, clap::Subcommand)]
+pub enum SignTransactionCmd {
+	/// Sign a Substrate extrinsic, from its SCALE encoded call.
+	Substrate(SignExtrinsicCmd),
+	/// Sign an EIP-1559 Ethereum transaction.
+	Ethereum(SignEthereumTransactionCmd),
+}
+
+impl SignTransactionCmd {
+	pub fn run(&self) -> Result<(), CliError> {
+		match self {
+			SignTransactionCmd::Substrate(cmd) => cmd.run(),
+			SignTransactionCmd::Ethereum(cmd) => cmd.run(),
+		}
+	}
+}
+
+#[derive(Debug, Parser)]
+pub struct SignExtrinsicCmd {
+	/// Runtime the extrinsic is built for.
+	#[clap(long, value_enum)]
+	runtime: Runtime,
+
+	/// SCALE encoded call, as a hex string.
+	#[clap(long, value_parser = parse_bytes)]
+	call: Bytes,
+
+	/// Nonce of the signer.
+	#[clap(long)]
+	nonce: u32,
+
+	/// Genesis hash of the chain.
+	#[clap(long, value_parser = parse_h256)]
+	genesis_hash: H256,
+
+	/// Tip to the block author, in the smallest unit of the native currency.
+	#[clap(long, default_value = "0")]
+	tip: u128,
+
+	/// Number of blocks the extrinsic is valid for, after the checkpoint block. The extrinsic
+	/// is immortal if not provided.
+	#[clap(long, requires = "checkpoint_hash", requires = "checkpoint_number")]
+	mortality: Option<u64>,
+
+	/// Hash of the block the mortality period starts at.
+	#[clap(long, value_parser = parse_h256, requires = "mortality")]
+	checkpoint_hash: Option<H256>,
+
+	/// Number of the block the mortality period starts at.
+	#[clap(long, requires = "mortality")]
+	checkpoint_number: Option<u64>,
+
+	/// Spec version to sign for, the one of the embedded runtime if not provided.
+	#[clap(long)]
+	spec_version: Option<u32>,
+
+	/// Transaction version to sign for, the one of the embedded runtime if not provided.
+	#[clap(long)]
+	transaction_version: Option<u32>,
+
+	/// Index of the account derived from the mnemonic.
+	#[clap(long, default_value = "0")]
+	account_index: u32,
+
+	#[clap(flatten)]
+	signer: SignerParams,
+}
+
+impl SignExtrinsicCmd {
+	pub fn run(&self) -> Result<(), CliError> {
+		let (signer, extrinsic) = self.sign()?;
+
+		eprintln!("Signer:  {:?}", signer);
+		eprintln!("Hash:    {:?}", H256::from(blake2_256(&extrinsic)));
+		println!("0x{}", HexDisplay::from(&extrinsic));
+
+		Ok(())
+	}
+
+	/// Signs the extrinsic, returning the signer and the SCALE encoded extrinsic.
+	fn sign(&self) -> Result<(account::AccountId20, Vec<u8>), CliError> {
+		let (era, checkpoint_hash) =
+			match (self.mortality, self.checkpoint_hash, self.checkpoint_number) {
+				(Some(period), Some(hash), Some(number)) => (Era::mortal(period, number), hash),
+				_ => (Era::Immortal, self.genesis_hash),
+			};
+
+		let pair = self
+			.signer
+			.pair(&self.runtime.network(), self.account_index)?;
+		let signer = signer_address(&pair);
+
+		let extrinsic = with_runtime!(self.runtime, runtime => {
+			let call = runtime::RuntimeCall::decode_all(&mut self.call.0.as_slice()).map_err(|_| {
+				CliError::Input(format!(
+					"The call can't be decoded with the embedded {:?} runtime",
+					self.runtime
+				))
+			})?;
+
+			// Explicit and implicit data of the transaction extensions, in order:
+			// CheckNonZeroSender, CheckSpecVersion, CheckTxVersion, CheckGenesis, CheckEra,
+			// CheckNonce, CheckWeight, ChargeTransactionPayment, (bridge extension,)
+			// CheckMetadataHash
+			let tx_ext = runtime::TxExtension::decode_all(
+				&mut (era, Compact(self.nonce), Compact(self.tip), 0u8)
+					.encode()
+					.as_slice(),
+			)
+			.map_err(|e| CliError::Input(format!("Invalid transaction extension: {:?}", e)))?;
+			let implicit = <runtime::TxExtension as TransactionExtension<
+				runtime::RuntimeCall,
+			>>::Implicit::decode_all(
+				&mut (
+					self.spec_version.unwrap_or(runtime::VERSION.spec_version),
+					self.transaction_version
+						.unwrap_or(runtime::VERSION.transaction_version),
+					self.genesis_hash,
+					checkpoint_hash,
+					Option::<[u8; 32]>::None,
+				)
+					.encode()
+					.as_slice(),
+			)
+			.map_err(|e| CliError::Input(format!("Invalid transaction extension: {:?}", e)))?;
+
+			let payload = SignedPayload::from_raw(call.clone(), tx_ext.clone(), implicit);
+			let signature =
+				payload.using_encoded(|payload| pair.sign_prehashed(&keccak_256(payload)));
+
+			Ok(runtime::UncheckedExtrinsic::new_signed(
+				call,
+				signer,
+				signature.into(),
+				tx_ext,
+			)
+			.encode())
+		})?;
+
+		Ok((signer, extrinsic))
+	}
+}
+
+#[derive(Debug, Parser)]
+pub struct SignEthereumTransactionCmd {
+	/// Runtime the transaction is built for, used for the default chain id.
+	#[clap(long, value_enum)]
+	runtime: Runtime,
+
+	/// EVM chain id, the one of the public network of the runtime if not provided.
+	#[clap(long)]
+	chain_id: Option<u64>,
+
+	/// Nonce of the signer.
+	#[clap(long, value_parser = parse_u256)]
+	nonce: U256,
+
+	/// Recipient of the transaction, a contract creation if not provided.
+	#[clap(long, value_parser = parse_h160)]
+	to: Option<H160>,
+
+	/// Value transferred, in wei.
+	#[clap(long, value_parser = parse_u256, default_value = "0")]
+	value: U256,
+
+	/// Input data, as a hex string.
+	#[clap(long, value_parser = parse_bytes, default_value = "0x")]
+	data: Bytes,
+
+	#[clap(long, value_parser = parse_u256)]
+	gas_limit: U256,
+
+	#[clap(long, value_parser = parse_u256)]
+	max_fee_per_gas: U256,
+
+	#[clap(long, value_parser = parse_u256, default_value = "0")]
+	max_priority_fee_per_gas: U256,
+
+	/// Index of the account derived from the mnemonic.
+	#[clap(long, default_value = "0")]
+	account_index: u32,
+
+	#[clap(flatten)]
+	signer: SignerParams,
+}
+
+impl SignEthereumTransactionCmd {
+	pub fn run(&self) -> Result<(), CliError> {
+		let (signer, encoded) = self.sign()?;
+
+		eprintln!("Signer:  {:?}", signer);
+		eprintln!("Hash:    {:?}", H256::from(keccak_256(&encoded)));
+		println!("0x{}", HexDisplay::from(&&encoded[..]));
+
+		Ok(())
+	}
+
+	/// Signs the transaction, returning the signer and the enveloped encoded transaction.
+	fn sign(&self) -> Result<(account::AccountId20, Vec<u8>), CliError> {
+		let message = EIP1559TransactionMessage {
+			chain_id: self.chain_id.unwrap_or(self.runtime.chain_id()),
+			nonce: self.nonce,
+			max_priority_fee_per_gas: self.max_priority_fee_per_gas,
+			max_fee_per_gas: self.max_fee_per_gas,
+			gas_limit: self.gas_limit,
+			action: match self.to {
+				Some(to) => TransactionAction::Call(to),
+				None => TransactionAction::Create,
+			},
+			value: self.value,
+			input: self.data.0.clone(),
+			access_list: Vec::new(),
+		};
+
+		let pair = self
+			.signer
+			.pair(&self.runtime.network(), self.account_index)?;
+		let signature = pair.sign_prehashed(&message.hash().0);
+		let signature = signature.as_ref();
+		let signature = TransactionSignature::new(
+			signature[64] == 1,
+			H256::from_slice(&signature[0..32]),
+			H256::from_slice(&signature[32..64]),
+		)
+		.ok_or(CliError::Input("Invalid transaction signature".into()))?;
+
+		let transaction = TransactionV2::EIP1559(EIP1559Transaction {
+			chain_id: message.chain_id,
+			nonce: message.nonce,
+			max_priority_fee_per_gas: message.max_priority_fee_per_gas,
+			max_fee_per_gas: message.max_fee_per_gas,
+			gas_limit: message.gas_limit,
+			action: message.action,
+			value: message.value,
+			input: message.input,
+			access_list: message.access_list,
+			signature,
+		});
+
+		Ok((
+			signer_address(&pair),
+			EnvelopedEncodable::encode(&transaction).to_vec(),
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Private key of Alith, the first development account
+	const ALITH_PRIVATE_KEY: &str =
+		"0x5fb92d6e98884f76de468fa3f6278f8807c48bebc13595d45af5bdc4da702133";
+	const ALITH: [u8; 20] = [
+		0xf2, 0x4f, 0xf3, 0xa9, 0xcf, 0x04, 0xc7, 0x1d, 0xbc, 0x94, 0xd0, 0xb5, 0x66, 0xf7, 0xa2,
+		0x7b, 0x94, 0x56, 0x6c, 0xac,
+	];
+
+	#[cfg(feature = "moonbase-native")]
+	fn check_remark_signed_for(signed_genesis: H256, chain_genesis: H256) -> bool {
+		use moonbeam_service::moonbase_runtime::{AccountId, System, UncheckedExtrinsic};
+		use sp_runtime::traits::{Checkable, IdentityLookup};
+
+		// System::remark(0x010203)
+		let genesis_hash = format!("{:?}", signed_genesis);
+		let cmd = SignExtrinsicCmd::try_parse_from([
+			"substrate",
+			"--runtime",
+			"moonbase",
+			"--call",
+			"0x00000c010203",
+			"--nonce",
+			"0",
+			"--genesis-hash",
+			genesis_hash.as_str(),
+			"--private-key",
+			ALITH_PRIVATE_KEY,
+		])
+		.expect("valid arguments");
+
+		let (signer, extrinsic) = cmd.sign().expect("the extrinsic is signed");
+		assert_eq!(signer, account::AccountId20(ALITH));
+
+		let extrinsic =
+			UncheckedExtrinsic::decode_all(&mut extrinsic.as_slice()).expect("valid extrinsic");
+		sp_io::TestExternalities::default().execute_with(|| {
+			// Stores the genesis hash as the hash of block 0
+			System::initialize(&1, &chain_genesis, &Default::default());
+			extrinsic
+				.check(&IdentityLookup::<AccountId>::default())
+				.is_ok()
+		})
+	}
+
+	#[cfg(feature = "moonbase-native")]
+	#[test]
+	fn signed_extrinsic_is_accepted_by_the_runtime() {
+		let genesis = H256::repeat_byte(0x11);
+		assert!(check_remark_signed_for(genesis, genesis));
+	}
+
+	#[cfg(feature = "moonbase-native")]
+	#[test]
+	fn signed_extrinsic_is_rejected_on_another_chain() {
+		assert!(!check_remark_signed_for(
+			H256::repeat_byte(0x11),
+			H256::repeat_byte(0x22)
+		));
+	}
+
+	#[test]
+	fn signs_eip1559_transaction() {
+		let cmd = SignEthereumTransactionCmd::try_parse_from([
+			"ethereum",
+			"--runtime",
+			"moonbase",
+			"--nonce",
+			"0",
+			"--to",
+			"0x3Cd0A705a2DC65e5b1E1205896BaA2be8A07c6e0",
+			"--value",
+			"1000000000000000000",
+			"--gas-limit",
+			"21000",
+			"--max-fee-per-gas",
+			"10000000000",
+			"--max-priority-fee-per-gas",
+			"1000000000",
+			"--private-key",
+			ALITH_PRIVATE_KEY,
+		])
+		.expect("valid arguments");
+
+		let (signer, transaction) = cmd.sign().expect("the transaction is signed");
+		assert_eq!(signer, account::AccountId20(ALITH));
+		assert_eq!(
+			format!("0x{}", HexDisplay::from(&transaction)),
+			"0x02f87582050780843b9aca008502540be400825208943cd0a705a2dc65e5b1e1205896baa2be8a\
+			07c6e0880de0b6b3a764000080c080a0f0efedecffabea4fbc4c950379afada492eb96c5fbc5c8a8e1\
+			36356b42424916a019836e4c8d5af5e3d0324f426b034e043ace98729d295ff8f8e61b75fa50faba"
+		);
+		assert_eq!(
+			H256::from(keccak_256(&transaction)),
+			H256::from_str("0x1bb33dd197835d65eb042ff58eeb0cc12ce4b66945553a568bfc0045204874ad")
+				.unwrap()
+		);
+	}
+}
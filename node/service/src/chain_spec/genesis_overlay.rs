@@ -0,0 +1,421 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Customization of the genesis of development specs.
+//!
+//! A genesis overlay file extends the genesis built by the runtime `testnet_genesis` preset:
+//!
+//! ```json
+//! {
+//!   "accounts": [{ "address": "0x<address>", "balance": "1000000000000000000000" }],
+//!   "collators": [{
+//!     "account": "0x<address>",
+//!     "authorId": "Bob",
+//!     "bond": "1000000000000000000000",
+//!     "delegations": [{ "delegator": "0x<address>", "amount": "5000000000000000000", "autoCompound": 50 }]
+//!   }],
+//!   "foreignAssets": [{
+//!     "assetId": "1",
+//!     "location": { "parents": 1, "interior": "Here" },
+//!     "decimals": 12,
+//!     "symbol": "xcDOT",
+//!     "name": "xcDOT"
+//!   }],
+//!   "feePaymentAssets": [{
+//!     "location": { "parents": 1, "interior": "Here" },
+//!     "relativePrice": "1000000000000000000"
+//!   }],
+//!   "contracts": {
+//!     "0x<address>": { "code": "0x<hex encoded bytecode>", "storage": { "0x<slot>": "0x<value>" } }
+//!   }
+//! }
+//! ```
+//!
+//! The author id of a collator is either a hex encoded sr25519 public key or a dev seed. Funded
+//! accounts replace the endowment of the dev accounts with the same address, and `contracts`
+//! uses the account format of [`crate::evm_state`].
+//!
+//! Foreign assets and fee payment assets are registered by executing the pallet logic over the
+//! genesis storage, the resulting chain spec then holds the raw genesis storage.
+
+use super::{get_from_seed, Extensions};
+use crate::evm_state::{self, EvmAccount, EvmState};
+use crate::HostFunctions;
+use moonbeam_core_primitives::{AccountId, Balance};
+use nimbus_primitives::NimbusId;
+use serde::{de::Error as _, Deserialize, Deserializer};
+use serde_json::Value;
+use sp_core::{sr25519, H160};
+use sp_runtime::{BuildStorage, Percent};
+use sp_state_machine::BasicExternalities;
+use std::collections::BTreeMap;
+use std::path::Path;
+use xcm::latest::Location;
+
+type ChainSpec = sc_service::GenericChainSpec<Extensions, HostFunctions>;
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GenesisOverlay {
+	#[serde(default)]
+	pub accounts: Vec<FundedAccount>,
+	#[serde(default)]
+	pub collators: Vec<Collator>,
+	#[serde(default)]
+	pub foreign_assets: Vec<ForeignAsset>,
+	#[serde(default)]
+	pub fee_payment_assets: Vec<FeePaymentAsset>,
+	#[serde(default)]
+	pub contracts: BTreeMap<H160, EvmAccount>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FundedAccount {
+	pub address: H160,
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub balance: Balance,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Collator {
+	pub account: H160,
+	#[serde(deserialize_with = "deserialize_author_id")]
+	pub author_id: NimbusId,
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub bond: Balance,
+	#[serde(default)]
+	pub delegations: Vec<Delegation>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Delegation {
+	pub delegator: H160,
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub amount: Balance,
+	/// Percentage of the rewards to auto-compound
+	#[serde(default)]
+	pub auto_compound: u8,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ForeignAsset {
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub asset_id: u128,
+	pub location: Location,
+	pub decimals: u8,
+	pub symbol: String,
+	pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FeePaymentAsset {
+	pub location: Location,
+	/// Price of the asset relative to the native currency, with 18 decimals
+	#[serde(deserialize_with = "deserialize_u128")]
+	pub relative_price: u128,
+}
+
+/// Accept integers either as JSON numbers or as (decimal or hex) strings, since JSON numbers
+/// can't hold most balances.
+fn deserialize_u128<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u128, D::Error> {
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum NumberOrString {
+		Number(u128),
+		String(String),
+	}
+
+	match NumberOrString::deserialize(deserializer)? {
+		NumberOrString::Number(number) => Ok(number),
+		NumberOrString::String(string) => match string.strip_prefix("0x") {
+			Some(hex) => u128::from_str_radix(hex, 16),
+			None => string.parse(),
+		}
+		.map_err(D::Error::custom),
+	}
+}
+
+fn deserialize_author_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<NimbusId, D::Error> {
+	let author_id = String::deserialize(deserializer)?;
+	match author_id.strip_prefix("0x") {
+		Some(public) => hex::decode(public)
+			.ok()
+			.and_then(|public| <[u8; 32]>::try_from(public).ok())
+			.map(|public| sr25519::Public::from_raw(public).into())
+			.ok_or_else(|| D::Error::custom("Invalid author id public key")),
+		None => Ok(get_from_seed::<NimbusId>(&author_id)),
+	}
+}
+
+/// Read a genesis overlay file.
+pub fn read(path: &Path) -> Result<GenesisOverlay, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+	serde_json::from_reader(file).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+fn to_value<T: serde::Serialize>(value: T) -> Value {
+	serde_json::to_value(value).expect("Genesis config values can be serialized to JSON")
+}
+
+/// Get the array at the given path of the genesis config, creating it if missing.
+fn array_at<'a>(genesis: &'a mut Value, pallet: &str, field: &str) -> &'a mut Vec<Value> {
+	let value = &mut genesis[pallet][field];
+	if !value.is_array() {
+		*value = Value::Array(Vec::new());
+	}
+	value
+		.as_array_mut()
+		.expect("Value was just set to an array")
+}
+
+fn hex_storage<'a>(entries: impl IntoIterator<Item = (&'a Vec<u8>, &'a Vec<u8>)>) -> Value {
+	entries
+		.into_iter()
+		.map(|(key, value)| {
+			(
+				format!("0x{}", hex::encode(key)),
+				Value::String(format!("0x{}", hex::encode(value))),
+			)
+		})
+		.collect::<serde_json::Map<_, _>>()
+		.into()
+}
+
+impl GenesisOverlay {
+	/// Apply the accounts, collators and contracts of the overlay to a genesis config built by a
+	/// runtime `testnet_genesis` preset.
+	pub fn apply(&self, genesis: &mut Value) {
+		let balances = array_at(genesis, "balances", "balances");
+		for account in &self.accounts {
+			let address = to_value(AccountId::from(account.address));
+			// Genesis balances can't contain the same account twice
+			balances.retain(|entry| entry[0] != address);
+			balances.push(to_value((address, account.balance)));
+		}
+
+		for collator in &self.collators {
+			let account = AccountId::from(collator.account);
+			array_at(genesis, "parachainStaking", "candidates")
+				.push(to_value((account, collator.bond)));
+			array_at(genesis, "authorMapping", "mappings")
+				.push(to_value((collator.author_id.clone(), account)));
+
+			let delegations = array_at(genesis, "parachainStaking", "delegations");
+			for delegation in &collator.delegations {
+				delegations.push(to_value((
+					AccountId::from(delegation.delegator),
+					account,
+					delegation.amount,
+					Percent::from_percent(delegation.auto_compound),
+				)));
+			}
+		}
+
+		evm_state::extend_genesis(
+			genesis,
+			EvmState {
+				block_hash: None,
+				accounts: self.contracts.clone(),
+			},
+		);
+	}
+
+	/// Register the foreign assets and fee payment assets of the overlay over the genesis
+	/// storage of `chain_spec`. Returns `chain_spec` unchanged if there are none, or a chain spec
+	/// with the resulting raw genesis storage otherwise.
+	pub fn register_assets<Runtime>(&self, chain_spec: ChainSpec) -> Result<ChainSpec, String>
+	where
+		Runtime: pallet_moonbeam_foreign_assets::Config + pallet_xcm_weight_trader::Config,
+	{
+		if self.foreign_assets.is_empty() && self.fee_payment_assets.is_empty() {
+			return Ok(chain_spec);
+		}
+
+		let mut storage = chain_spec.build_storage()?;
+		BasicExternalities::execute_with_storage(&mut storage, || {
+			for asset in &self.foreign_assets {
+				pallet_moonbeam_foreign_assets::Pallet::<Runtime>::register_foreign_asset(
+					asset.asset_id,
+					asset.location.clone(),
+					asset.decimals,
+					asset
+						.symbol
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.map_err(|_| format!("Symbol of asset {} is too long", asset.asset_id))?,
+					asset
+						.name
+						.as_bytes()
+						.to_vec()
+						.try_into()
+						.map_err(|_| format!("Name of asset {} is too long", asset.asset_id))?,
+				)
+				.map_err(|e| format!("Failed to register asset {}: {:?}", asset.asset_id, e))?;
+			}
+			for asset in &self.fee_payment_assets {
+				pallet_xcm_weight_trader::Pallet::<Runtime>::do_add_asset(
+					asset.location.clone(),
+					asset.relative_price,
+				)
+				.map_err(|e| {
+					format!(
+						"Failed to add fee payment asset {:?}: {:?}",
+						asset.location, e
+					)
+				})?;
+			}
+			Ok::<_, String>(())
+		})?;
+
+		let mut spec: Value =
+			serde_json::from_str(&chain_spec.as_json(false)?).map_err(|e| e.to_string())?;
+		spec["genesis"] = serde_json::json!({
+			"raw": {
+				"top": hex_storage(&storage.top),
+				"childrenDefault": storage
+					.children_default
+					.values()
+					.map(|child| {
+						(
+							format!("0x{}", hex::encode(child.child_info.storage_key())),
+							hex_storage(&child.data),
+						)
+					})
+					.collect::<serde_json::Map<_, _>>(),
+			}
+		});
+		ChainSpec::from_json_bytes(serde_json::to_vec(&spec).map_err(|e| e.to_string())?)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[derive(Debug, Deserialize)]
+	struct Amount(#[serde(deserialize_with = "deserialize_u128")] u128);
+
+	#[derive(Debug, Deserialize)]
+	struct AuthorId(#[serde(deserialize_with = "deserialize_author_id")] NimbusId);
+
+	#[test]
+	fn integers_are_read_from_numbers_and_strings() {
+		for (value, expected) in [
+			(json!(42), 42),
+			(
+				json!("1000000000000000000000000"),
+				1_000_000_000_000_000_000_000_000,
+			),
+			(json!("0xff"), 255),
+		] {
+			let amount: Amount = serde_json::from_value(value).expect("valid integer");
+			assert_eq!(amount.0, expected);
+		}
+		assert!(serde_json::from_value::<Amount>(json!("0xzz")).is_err());
+		assert!(serde_json::from_value::<Amount>(json!("-1")).is_err());
+	}
+
+	#[test]
+	fn author_ids_are_read_from_seeds_and_public_keys() {
+		let bob = get_from_seed::<NimbusId>("Bob");
+		let from_seed: AuthorId = serde_json::from_value(json!("Bob")).expect("valid seed");
+		assert_eq!(from_seed.0, bob);
+
+		let public = format!("0x{}", hex::encode(&bob));
+		let from_public: AuthorId = serde_json::from_value(json!(public)).expect("valid key");
+		assert_eq!(from_public.0, bob);
+
+		assert!(serde_json::from_value::<AuthorId>(json!("0x1234")).is_err());
+	}
+
+	#[test]
+	fn unknown_fields_are_rejected() {
+		assert!(serde_json::from_value::<GenesisOverlay>(json!({ "account": [] })).is_err());
+		assert!(serde_json::from_value::<GenesisOverlay>(json!({
+			"accounts": [{ "address": "0x1111111111111111111111111111111111111111", "balance": 1, "nonce": 0 }]
+		}))
+		.is_err());
+	}
+
+	#[test]
+	fn apply_extends_the_genesis_config() {
+		let funded = AccountId::from(H160::repeat_byte(1));
+		let other = AccountId::from(H160::repeat_byte(2));
+		let collator = AccountId::from(H160::repeat_byte(3));
+		let contract = H160::repeat_byte(4);
+
+		let overlay: GenesisOverlay = serde_json::from_value(json!({
+			"accounts": [{ "address": "0x0101010101010101010101010101010101010101", "balance": "500" }],
+			"collators": [{
+				"account": "0x0303030303030303030303030303030303030303",
+				"authorId": "Bob",
+				"bond": "1000",
+				"delegations": [{
+					"delegator": "0x0101010101010101010101010101010101010101",
+					"amount": "50",
+					"autoCompound": 25
+				}]
+			}],
+			"contracts": {
+				"0x0404040404040404040404040404040404040404": { "code": "0x60006000fd" }
+			}
+		}))
+		.expect("valid genesis overlay");
+
+		let mut genesis = json!({
+			"balances": { "balances": [to_value((funded, 10u128)), to_value((other, 20u128))] },
+			"parachainStaking": { "candidates": [to_value((other, 100u128))] }
+		});
+		overlay.apply(&mut genesis);
+
+		assert_eq!(
+			genesis["balances"]["balances"],
+			json!([to_value((other, 20u128)), to_value((funded, 500u128))])
+		);
+		assert_eq!(
+			genesis["parachainStaking"]["candidates"],
+			json!([to_value((other, 100u128)), to_value((collator, 1_000u128))])
+		);
+		assert_eq!(
+			genesis["parachainStaking"]["delegations"],
+			json!([to_value((
+				funded,
+				collator,
+				50u128,
+				Percent::from_percent(25)
+			))])
+		);
+		assert_eq!(
+			genesis["authorMapping"]["mappings"],
+			json!([to_value((get_from_seed::<NimbusId>("Bob"), collator))])
+		);
+		assert_eq!(
+			genesis["evm"]["accounts"][format!("{:?}", contract)]["code"],
+			to_value(fp_evm::GenesisAccount::from(EvmAccount {
+				code: vec![0x60, 0x00, 0x60, 0x00, 0xfd].into(),
+				..Default::default()
+			}))["code"]
+		);
+	}
+}
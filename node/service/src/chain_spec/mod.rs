@@ -31,6 +31,8 @@ pub mod moonbeam;
 #[cfg(feature = "moonriver-native")]
 pub mod moonriver;
 
+pub mod genesis_overlay;
+
 pub type RawChainSpec = sc_service::GenericChainSpec<Extensions>;
 
 #[derive(Default, Clone, Serialize, Deserialize, ChainSpecExtension, ChainSpecGroup)]
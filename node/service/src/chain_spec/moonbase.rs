@@ -21,8 +21,13 @@
 
 #[cfg(test)]
 use crate::chain_spec::{derive_bip44_pairs_from_mnemonic, get_account_id_from_pair};
-use crate::chain_spec::{generate_accounts, get_from_seed, Extensions};
-use crate::HostFunctions;
+use crate::chain_spec::{
+	generate_accounts, genesis_overlay::GenesisOverlay, get_from_seed, Extensions,
+};
+use crate::{
+	evm_state::{self, EvmState},
+	HostFunctions,
+};
 use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
 use moonbase_runtime::{
@@ -37,7 +42,12 @@ use sp_core::ecdsa;
 pub type ChainSpec = sc_service::GenericChainSpec<Extensions, HostFunctions>;
 
 /// Generate a chain spec for use with the development service.
-pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32>) -> ChainSpec {
+pub fn development_chain_spec(
+	mnemonic: Option<String>,
+	num_accounts: Option<u32>,
+	evm_state: Option<EvmState>,
+	overlay: Option<GenesisOverlay>,
+) -> ChainSpec {
 	// Default mnemonic if none was provided
 	let parent_mnemonic = mnemonic.unwrap_or_else(|| {
 		"bottom drive obey lake curtain smoke basket hold race lonely fit walk".to_string()
@@ -54,23 +64,7 @@ pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32
 		"1000000000000000000000000000000000000001"
 	)));
 
-	ChainSpec::builder(
-		WASM_BINARY.expect("WASM binary was not build, please build it!"),
-		Extensions {
-			relay_chain: "dev-service".into(),
-			para_id: Default::default(),
-		},
-	)
-	.with_name("Moonbase Development Testnet")
-	.with_id("moonbase_dev")
-	.with_chain_type(ChainType::Development)
-	.with_properties(
-		serde_json::from_str(
-			"{\"tokenDecimals\": 18, \"tokenSymbol\": \"DEV\", \"SS58Prefix\": 1287}",
-		)
-		.expect("Provided valid json map"),
-	)
-	.with_genesis_config(testnet_genesis(
+	let mut genesis = testnet_genesis(
 		// Alith is Sudo
 		accounts[0],
 		// Treasury Council members: Baltathar, Charleth and Dorothy
@@ -88,8 +82,39 @@ pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32
 		accounts.clone(),
 		Default::default(), // para_id
 		1281,               //ChainId
-	))
-	.build()
+	);
+	if let Some(evm_state) = evm_state {
+		evm_state::extend_genesis(&mut genesis, evm_state);
+	}
+	if let Some(overlay) = &overlay {
+		overlay.apply(&mut genesis);
+	}
+
+	let chain_spec = ChainSpec::builder(
+		WASM_BINARY.expect("WASM binary was not build, please build it!"),
+		Extensions {
+			relay_chain: "dev-service".into(),
+			para_id: Default::default(),
+		},
+	)
+	.with_name("Moonbase Development Testnet")
+	.with_id("moonbase_dev")
+	.with_chain_type(ChainType::Development)
+	.with_properties(
+		serde_json::from_str(
+			"{\"tokenDecimals\": 18, \"tokenSymbol\": \"DEV\", \"SS58Prefix\": 1287}",
+		)
+		.expect("Provided valid json map"),
+	)
+	.with_genesis_config(genesis)
+	.build();
+
+	match overlay {
+		Some(overlay) => overlay
+			.register_assets::<moonbase_runtime::Runtime>(chain_spec)
+			.unwrap_or_else(|e| panic!("Invalid genesis overlay: {}", e)),
+		None => chain_spec,
+	}
 }
 
 /// Generate a default spec for the parachain service. Use this as a starting point when launching
@@ -196,4 +221,70 @@ mod tests {
 		assert_eq!(last_account, expected_last_account);
 		assert_eq!(pairs.len(), 20);
 	}
+	#[test]
+	fn test_genesis_overlay() {
+		use sp_core::{blake2_128, twox_128};
+		use sp_runtime::BuildStorage;
+
+		let contract = hex!("2222222222222222222222222222222222222222");
+		let overlay: GenesisOverlay = serde_json::from_value(serde_json::json!({
+			"accounts": [{
+				"address": "0x1111111111111111111111111111111111111111",
+				"balance": "1000000000000000000000000"
+			}],
+			"collators": [{
+				"account": "0x3Cd0A705a2DC65e5b1E1205896BaA2be8A07c6e0",
+				"authorId": "Bob",
+				"bond": "1000000000000000000000",
+				"delegations": [{
+					"delegator": "0x1111111111111111111111111111111111111111",
+					"amount": "5000000000000000000",
+					"autoCompound": 50
+				}]
+			}],
+			"foreignAssets": [{
+				"assetId": "1",
+				"location": { "parents": 1, "interior": "Here" },
+				"decimals": 12,
+				"symbol": "xcUNIT",
+				"name": "xcUNIT"
+			}],
+			"feePaymentAssets": [{
+				"location": { "parents": 1, "interior": "Here" },
+				"relativePrice": "1000000000000000000"
+			}],
+			"contracts": {
+				"0x2222222222222222222222222222222222222222": { "code": "0x60006000fd" }
+			}
+		}))
+		.expect("Valid genesis overlay");
+
+		let storage = development_chain_spec(None, None, None, Some(overlay))
+			.build_storage()
+			.expect("Genesis overlay is accepted by the runtime");
+
+		let code_key = [
+			twox_128(b"EVM").as_slice(),
+			&twox_128(b"AccountCodes"),
+			&blake2_128(&contract),
+			&contract,
+		]
+		.concat();
+		assert!(storage.top.contains_key(&code_key));
+
+		let asset_key = [
+			twox_128(b"EvmForeignAssets").as_slice(),
+			&twox_128(b"AssetsById"),
+			&blake2_128(&1u128.to_le_bytes()),
+			&1u128.to_le_bytes(),
+		]
+		.concat();
+		assert!(storage.top.contains_key(&asset_key));
+		let fee_payment_prefix =
+			[twox_128(b"XcmWeightTrader"), twox_128(b"SupportedAssets")].concat();
+		assert!(storage
+			.top
+			.keys()
+			.any(|key| key.starts_with(&fee_payment_prefix)));
+	}
 }
@@ -21,8 +21,13 @@
 
 #[cfg(test)]
 use crate::chain_spec::{derive_bip44_pairs_from_mnemonic, get_account_id_from_pair};
-use crate::chain_spec::{generate_accounts, get_from_seed, Extensions};
-use crate::HostFunctions;
+use crate::chain_spec::{
+	generate_accounts, genesis_overlay::GenesisOverlay, get_from_seed, Extensions,
+};
+use crate::{
+	evm_state::{self, EvmState},
+	HostFunctions,
+};
 use cumulus_primitives_core::ParaId;
 use hex_literal::hex;
 use moonbeam_runtime::{
@@ -39,7 +44,12 @@ use sp_core::ecdsa;
 pub type ChainSpec = sc_service::GenericChainSpec<Extensions, HostFunctions>;
 
 /// Generate a chain spec for use with the development service.
-pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32>) -> ChainSpec {
+pub fn development_chain_spec(
+	mnemonic: Option<String>,
+	num_accounts: Option<u32>,
+	evm_state: Option<EvmState>,
+	overlay: Option<GenesisOverlay>,
+) -> ChainSpec {
 	// Default mnemonic if none was provided
 	let parent_mnemonic = mnemonic.unwrap_or_else(|| {
 		"bottom drive obey lake curtain smoke basket hold race lonely fit walk".to_string()
@@ -50,23 +60,7 @@ pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32
 		"6Be02d1d3665660d22FF9624b7BE0551ee1Ac91b"
 	)));
 
-	ChainSpec::builder(
-		WASM_BINARY.expect("WASM binary was not build, please build it!"),
-		Extensions {
-			relay_chain: "dev-service".into(),
-			para_id: Default::default(),
-		},
-	)
-	.with_name("Moonbeam Development Testnet")
-	.with_id("moonbeam_dev")
-	.with_chain_type(ChainType::Development)
-	.with_properties(
-		serde_json::from_str(
-			"{\"tokenDecimals\": 18, \"tokenSymbol\": \"GLMR\", \"SS58Prefix\": 1284}",
-		)
-		.expect("Provided valid json map"),
-	)
-	.with_genesis_config(testnet_genesis(
+	let mut genesis = testnet_genesis(
 		// Treasury Council members: Baltathar, Charleth and Dorothy
 		vec![accounts[1], accounts[2], accounts[3]],
 		// Open Tech committee members: Alith and Baltathar
@@ -82,8 +76,39 @@ pub fn development_chain_spec(mnemonic: Option<String>, num_accounts: Option<u32
 		accounts.clone(),
 		Default::default(), // para_id
 		1281,               //ChainId
-	))
-	.build()
+	);
+	if let Some(evm_state) = evm_state {
+		evm_state::extend_genesis(&mut genesis, evm_state);
+	}
+	if let Some(overlay) = &overlay {
+		overlay.apply(&mut genesis);
+	}
+
+	let chain_spec = ChainSpec::builder(
+		WASM_BINARY.expect("WASM binary was not build, please build it!"),
+		Extensions {
+			relay_chain: "dev-service".into(),
+			para_id: Default::default(),
+		},
+	)
+	.with_name("Moonbeam Development Testnet")
+	.with_id("moonbeam_dev")
+	.with_chain_type(ChainType::Development)
+	.with_properties(
+		serde_json::from_str(
+			"{\"tokenDecimals\": 18, \"tokenSymbol\": \"GLMR\", \"SS58Prefix\": 1284}",
+		)
+		.expect("Provided valid json map"),
+	)
+	.with_genesis_config(genesis)
+	.build();
+
+	match overlay {
+		Some(overlay) => overlay
+			.register_assets::<moonbeam_runtime::Runtime>(chain_spec)
+			.unwrap_or_else(|e| panic!("Invalid genesis overlay: {}", e)),
+		None => chain_spec,
+	}
 }
 
 /// Generate a default spec for the parachain service. Use this as a starting point when launching
@@ -0,0 +1,151 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! HRMP loopback between local dev nodes.
+//!
+//! Each dev node forwards the HRMP messages left in its outbox by every imported block to the
+//! peer dev node of the recipient parachain, through the `xcm_injectHrmpMessage` dev RPC of that
+//! peer. The mocked relay state of each node declares an open egress channel to every peer, so
+//! that messages to them are accepted by the parachain system pallet.
+
+use crate::dev_outbound_xcm::DevOutboundXcm;
+use cumulus_primitives_core::relay_chain::{well_known_keys, HrmpChannelId};
+use cumulus_primitives_core::{AbridgedHrmpChannel, ParaId};
+use futures::StreamExt;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::http_client::{HttpClient, HttpClientBuilder};
+use jsonrpsee::rpc_params;
+use moonbeam_core_primitives::Block;
+use moonbeam_dev_rpc::OutboundXcmMessages;
+use parity_scale_codec::Encode;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "dev-hrmp";
+
+/// Capacity of the mocked channels, large enough to never be the limiting factor.
+const CHANNEL_MAX_CAPACITY: u32 = 1_000;
+const CHANNEL_MAX_TOTAL_SIZE: u32 = 1_048_576;
+const CHANNEL_MAX_MESSAGE_SIZE: u32 = 102_400;
+
+/// Relay storage entries declaring an open egress channel from `para_id` to each of `peers`.
+pub fn egress_channels_key_values(para_id: ParaId, peers: &[ParaId]) -> Vec<(Vec<u8>, Vec<u8>)> {
+	if peers.is_empty() {
+		return Vec::new();
+	}
+
+	// The relay chain keeps the channel index sorted
+	let mut recipients = peers.to_vec();
+	recipients.sort();
+	recipients.dedup();
+
+	let mut key_values = vec![(
+		well_known_keys::hrmp_egress_channel_index(para_id),
+		recipients.encode(),
+	)];
+	key_values.extend(recipients.into_iter().map(|recipient| {
+		(
+			well_known_keys::hrmp_channels(HrmpChannelId {
+				sender: para_id,
+				recipient,
+			}),
+			AbridgedHrmpChannel {
+				max_capacity: CHANNEL_MAX_CAPACITY,
+				max_total_size: CHANNEL_MAX_TOTAL_SIZE,
+				max_message_size: CHANNEL_MAX_MESSAGE_SIZE,
+				msg_count: 0,
+				total_size: 0,
+				mqc_head: None,
+			}
+			.encode(),
+		)
+	}));
+
+	key_values
+}
+
+/// Forward the HRMP messages sent by each block imported by this node to the peer dev nodes.
+pub async fn forward_hrmp_messages<C, BE>(
+	client: Arc<C>,
+	para_id: ParaId,
+	peers: Vec<(ParaId, url::Url)>,
+) where
+	C: StorageProvider<Block, BE> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	BE: sc_client_api::Backend<Block> + 'static,
+{
+	let mut peer_clients = BTreeMap::<ParaId, HttpClient>::new();
+	for (peer_id, url) in peers {
+		match HttpClientBuilder::default().build(url.as_str()) {
+			Ok(peer_client) => {
+				peer_clients.insert(peer_id, peer_client);
+			}
+			Err(e) => log::error!(
+				target: LOG_TARGET,
+				"Invalid HRMP peer {} url {}: {}",
+				peer_id,
+				url,
+				e
+			),
+		}
+	}
+
+	let outbound = DevOutboundXcm::<C, BE>::new(client);
+	let mut imported_blocks = outbound.imported_blocks();
+	while let Some(block_hash) = imported_blocks.next().await {
+		let messages = match outbound.outbound_messages(Some(block_hash)) {
+			Ok((_, messages)) => messages.horizontal,
+			Err(e) => {
+				log::warn!(target: LOG_TARGET, "{}", e);
+				continue;
+			}
+		};
+
+		for message in messages {
+			let Some(peer_client) = peer_clients.get(&message.recipient) else {
+				log::debug!(
+					target: LOG_TARGET,
+					"Dropping HRMP message to {}, which is not a known peer",
+					message.recipient
+				);
+				continue;
+			};
+
+			let result = peer_client
+				.request::<(), _>(
+					"xcm_injectHrmpMessage",
+					rpc_params![u32::from(para_id), message.data],
+				)
+				.await;
+			match result {
+				Ok(()) => log::info!(
+					target: LOG_TARGET,
+					"Forwarded HRMP message from block {:?} to {}",
+					block_hash,
+					message.recipient
+				),
+				Err(e) => log::warn!(
+					target: LOG_TARGET,
+					"Failed to forward HRMP message to {}: {}",
+					message.recipient,
+					e
+				),
+			}
+		}
+	}
+}
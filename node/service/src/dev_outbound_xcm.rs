@@ -0,0 +1,94 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Outbound XCM messages for the `xcm_getOutboundMessages`/`xcm_subscribeOutbound` dev RPCs.
+//!
+//! The parachain system pallet leaves the messages sent by a block in its `UpwardMessages` and
+//! `HrmpOutboundMessages` storage items, for the collator to include in the candidate. Dev nodes
+//! don't build candidates, so the messages are read back from the state of each block.
+//!
+//! HRMP messages are only sent to the recipients of an open egress channel in the mocked relay
+//! state, declared for each `--dev-hrmp-peer` or with the `test_setRelayStorage` dev RPC. The
+//! messages to other recipients stay in the XCMP queue and are never captured.
+
+use cumulus_primitives_core::OutboundHrmpMessage;
+use futures::stream::{BoxStream, StreamExt};
+use moonbeam_core_primitives::{Block, Hash};
+use moonbeam_dev_rpc::{OutboundXcmMessages, RawOutboundMessages};
+use parity_scale_codec::Decode;
+use sc_client_api::{BlockchainEvents, StorageProvider};
+use sp_blockchain::HeaderBackend;
+use sp_core::twox_128;
+use sp_storage::StorageKey;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+pub struct DevOutboundXcm<C, BE> {
+	client: Arc<C>,
+	_backend: PhantomData<BE>,
+}
+
+impl<C, BE> DevOutboundXcm<C, BE> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_backend: PhantomData,
+		}
+	}
+}
+
+impl<C, BE> DevOutboundXcm<C, BE>
+where
+	C: StorageProvider<Block, BE>,
+	BE: sc_client_api::Backend<Block>,
+{
+	fn read<T: Decode + Default>(&self, at: Hash, storage: &[u8]) -> Result<T, String> {
+		let key = StorageKey([twox_128(b"ParachainSystem"), twox_128(storage)].concat());
+		match self
+			.client
+			.storage(at, &key)
+			.map_err(|e| format!("Failed to read outbound messages: {:?}", e))?
+		{
+			Some(data) => T::decode(&mut data.0.as_slice())
+				.map_err(|e| format!("Failed to decode outbound messages: {:?}", e)),
+			None => Ok(T::default()),
+		}
+	}
+}
+
+impl<C, BE> OutboundXcmMessages for DevOutboundXcm<C, BE>
+where
+	C: StorageProvider<Block, BE> + HeaderBackend<Block> + BlockchainEvents<Block>,
+	C: Send + Sync + 'static,
+	BE: sc_client_api::Backend<Block> + 'static,
+{
+	fn outbound_messages(&self, at: Option<Hash>) -> Result<(Hash, RawOutboundMessages), String> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let messages = RawOutboundMessages {
+			upward: self.read::<Vec<Vec<u8>>>(at, b"UpwardMessages")?,
+			horizontal: self.read::<Vec<OutboundHrmpMessage>>(at, b"HrmpOutboundMessages")?,
+		};
+
+		Ok((at, messages))
+	}
+
+	fn imported_blocks(&self) -> BoxStream<'static, Hash> {
+		self.client
+			.import_notification_stream()
+			.map(|notification| notification.hash)
+			.boxed()
+	}
+}
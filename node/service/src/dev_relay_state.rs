@@ -0,0 +1,60 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Configuration of the mocked relay chain state of the development service.
+//!
+//! The file lists raw relay storage entries, included in the relay state proof of every block:
+//!
+//! ```json
+//! {
+//!   "storage": {
+//!     "0x<hex encoded key>": "0x<hex encoded value>"
+//!   }
+//! }
+//! ```
+//!
+//! The entries override the ones of the mocked relay state (relay slot, host configuration, HRMP
+//! channels...) and can be updated at runtime with the `test_setRelayStorage` dev RPC.
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RelayStateConfig {
+	#[serde(default)]
+	storage: BTreeMap<String, String>,
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+	hex::decode(value.strip_prefix("0x").unwrap_or(value))
+		.map_err(|e| format!("Invalid hex value '{}': {}", value, e))
+}
+
+/// Load the relay storage entries of a mocked relay state configuration file.
+pub fn load_relay_storage(path: &Path) -> Result<BTreeMap<Vec<u8>, Vec<u8>>, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+	let config: RelayStateConfig = serde_json::from_reader(file)
+		.map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+	config
+		.storage
+		.iter()
+		.map(|(key, value)| Ok((decode_hex(key)?, decode_hex(value)?)))
+		.collect()
+}
@@ -0,0 +1,227 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Snapshot support for the development service.
+//!
+//! Snapshots only record the best block. Reverting relies on the database backend to unwind
+//! the blocks imported since then, so it is limited to blocks that were not finalized: sealing
+//! commands don't finalize blocks while snapshots are held.
+//!
+//! The frontier mapping database is rewound by resetting the syncing tips of the mapping sync
+//! worker, which then resumes from the restored head. The mappings written for the reverted
+//! blocks are left in place, frontier ignores the ones of non canonical blocks.
+//!
+//! The mocked timestamp of the dev service is rewound as well, so that the blocks sealed after
+//! a revert continue from the timestamp of the snapshot.
+
+use futures::{future, Stream, StreamExt};
+use moonbeam_core_primitives::{Block, BlockNumber, Hash};
+use moonbeam_dev_rpc::ChainSnapshots;
+use parking_lot::Mutex;
+use sc_client_api::Backend;
+use sc_consensus_manual_seal::{rpc, EngineCommand, Error};
+use sp_blockchain::HeaderBackend;
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+pub struct DevSnapshots<BE> {
+	backend: Arc<BE>,
+	snapshots: Mutex<BTreeMap<u64, (Hash, BlockNumber)>>,
+	next_snapshot_id: AtomicU64,
+}
+
+impl<BE> DevSnapshots<BE> {
+	pub fn new(backend: Arc<BE>) -> Self {
+		Self {
+			backend,
+			snapshots: Default::default(),
+			next_snapshot_id: AtomicU64::new(1),
+		}
+	}
+}
+
+impl<BE> ChainSnapshots for DevSnapshots<BE>
+where
+	BE: Backend<Block> + 'static,
+{
+	fn snapshot(&self) -> Result<u64, String> {
+		let _import_lock = self.backend.get_import_lock().write();
+
+		let info = self.backend.blockchain().info();
+		let snapshot_id = self.next_snapshot_id.fetch_add(1, Ordering::SeqCst);
+		self.snapshots
+			.lock()
+			.insert(snapshot_id, (info.best_hash, info.best_number));
+
+		Ok(snapshot_id)
+	}
+
+	fn revert(&self, snapshot_id: u64) -> Result<bool, String> {
+		let _import_lock = self.backend.get_import_lock().write();
+
+		let info = self.backend.blockchain().info();
+		let Some((snapshot_hash, snapshot_number)) =
+			self.snapshots.lock().get(&snapshot_id).cloned()
+		else {
+			return Ok(false);
+		};
+		if info.finalized_number > snapshot_number {
+			return Err(format!(
+				"Block #{} is finalized, the chain can't be reverted to block #{}",
+				info.finalized_number, snapshot_number
+			));
+		}
+
+		let to_revert = info.best_number.saturating_sub(snapshot_number);
+		let (reverted, _) = self
+			.backend
+			.revert(to_revert, false)
+			.map_err(|e| e.to_string())?;
+
+		if reverted < to_revert {
+			return Err(format!(
+				"Only {} out of {} blocks could be reverted",
+				reverted, to_revert
+			));
+		}
+
+		let best_hash = self.backend.blockchain().info().best_hash;
+		if best_hash != snapshot_hash {
+			return Err(format!(
+				"Snapshot block {:?} is no longer in the best chain (head is {:?})",
+				snapshot_hash, best_hash
+			));
+		}
+
+		// Reverting consumes the snapshot and every snapshot taken after it.
+		let _ = self.snapshots.lock().split_off(&snapshot_id);
+
+		log::debug!(
+			"Reverted to snapshot {} at block #{} ({:?})",
+			snapshot_id,
+			snapshot_number,
+			snapshot_hash
+		);
+
+		Ok(true)
+	}
+
+	fn has_snapshots(&self) -> bool {
+		!self.snapshots.lock().is_empty()
+	}
+}
+
+/// Snapshots of the chain rewinding the frontier mapping sync and the mocked timestamp along
+/// with it.
+pub struct FrontierSnapshots {
+	snapshots: Arc<dyn ChainSnapshots>,
+	/// Metadata of the key-value frontier backend. The SQL backend follows the reorgs of the
+	/// chain by itself.
+	frontier_meta: Option<Arc<fc_db::kv::MetaDb<Block>>>,
+	/// Mocked timestamp of the dev service when each snapshot was taken.
+	timestamps: Mutex<BTreeMap<u64, u64>>,
+}
+
+impl FrontierSnapshots {
+	pub fn new<C: HeaderBackend<Block>>(
+		snapshots: Arc<dyn ChainSnapshots>,
+		frontier_backend: &fc_db::Backend<Block, C>,
+	) -> Self {
+		Self {
+			snapshots,
+			frontier_meta: match frontier_backend {
+				fc_db::Backend::KeyValue(backend) => Some(backend.meta().clone()),
+				fc_db::Backend::Sql(_) => None,
+			},
+			timestamps: Default::default(),
+		}
+	}
+}
+
+impl ChainSnapshots for FrontierSnapshots {
+	fn snapshot(&self) -> Result<u64, String> {
+		let timestamp = crate::TIMESTAMP.load(Ordering::SeqCst);
+		let snapshot_id = self.snapshots.snapshot()?;
+		self.timestamps.lock().insert(snapshot_id, timestamp);
+
+		Ok(snapshot_id)
+	}
+
+	fn revert(&self, snapshot_id: u64) -> Result<bool, String> {
+		let reverted = self.snapshots.revert(snapshot_id)?;
+		if !reverted {
+			return Ok(false);
+		}
+
+		let mut timestamps = self.timestamps.lock();
+		if let Some(timestamp) = timestamps.get(&snapshot_id) {
+			crate::TIMESTAMP.store(*timestamp, Ordering::SeqCst);
+		}
+		let _ = timestamps.split_off(&snapshot_id);
+		drop(timestamps);
+
+		if let Some(frontier_meta) = &self.frontier_meta {
+			// Without tips, the mapping sync worker starts over from the leaves of the chain.
+			frontier_meta.write_current_syncing_tips(Vec::new())?;
+		}
+
+		Ok(true)
+	}
+
+	fn has_snapshots(&self) -> bool {
+		self.snapshots.has_snapshots()
+	}
+}
+
+/// Sealing commands, which don't finalize blocks while snapshots are held. Explicit requests to
+/// finalize a block are answered with an error meanwhile.
+pub fn defer_finality<S>(
+	commands: S,
+	snapshots: Arc<dyn ChainSnapshots>,
+) -> impl Stream<Item = EngineCommand<Hash>> + Send + Sync + Unpin
+where
+	S: Stream<Item = EngineCommand<Hash>> + Send + Sync + Unpin,
+{
+	commands.filter_map(move |command| {
+		future::ready(match command {
+			EngineCommand::SealNewBlock {
+				create_empty,
+				finalize,
+				parent_hash,
+				sender,
+			} => Some(EngineCommand::SealNewBlock {
+				create_empty,
+				finalize: finalize && !snapshots.has_snapshots(),
+				parent_hash,
+				sender,
+			}),
+			EngineCommand::FinalizeBlock {
+				hash, mut sender, ..
+			} if snapshots.has_snapshots() => {
+				rpc::send_result(
+					&mut sender,
+					Err(Error::StringError(format!(
+						"Block {:?} can't be finalized while snapshots are held",
+						hash
+					))),
+				);
+				None
+			}
+			command => Some(command),
+		})
+	})
+}
@@ -0,0 +1,66 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM processing reports for the `xcm_sendDownwardXcm`/`xcm_sendHrmpXcm` dev RPCs.
+
+use moonbeam_core_primitives::{Block, Hash};
+use moonbeam_dev_rpc::XcmProcessingReports;
+use moonbeam_rpc_primitives_xcm::{XcmProcessingApi, XcmProcessingReport};
+use parity_scale_codec::Decode;
+use sc_client_api::{CallExecutor, ExecutorProvider};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_core::traits::CallContext;
+use std::sync::Arc;
+
+/// Runtime function of `XcmProcessingApi::xcm_processing_report`
+const XCM_PROCESSING_REPORT: &str = "XcmProcessingApi_xcm_processing_report";
+
+pub struct DevXcmReports<C> {
+	client: Arc<C>,
+}
+
+impl<C> DevXcmReports<C> {
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client }
+	}
+}
+
+// `XcmProcessingApi` is only implemented by the runtimes used for development, so it is called
+// by name rather than being required from every runtime.
+impl<C> XcmProcessingReports for DevXcmReports<C>
+where
+	C: ProvideRuntimeApi<Block> + ExecutorProvider<Block> + Send + Sync,
+	C::Api: ApiExt<Block>,
+{
+	fn report(&self, at: Hash) -> Result<XcmProcessingReport, String> {
+		let supported = self
+			.client
+			.runtime_api()
+			.has_api::<dyn XcmProcessingApi<Block>>(at)
+			.map_err(|e| format!("Failed to get the runtime APIs: {:?}", e))?;
+		if !supported {
+			return Err("The runtime doesn't support XCM processing reports".into());
+		}
+
+		let report = self
+			.client
+			.executor()
+			.call(at, XCM_PROCESSING_REPORT, &[], CallContext::Offchain)
+			.map_err(|e| format!("Failed to get the XCM processing report: {:?}", e))?;
+		XcmProcessingReport::decode(&mut report.as_slice())
+			.map_err(|e| format!("Invalid XCM processing report: {:?}", e))
+	}
+}
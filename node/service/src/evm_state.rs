@@ -0,0 +1,435 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Export and import of EVM accounts.
+//!
+//! The `export-evm-state` subcommand dumps the balance, nonce, code and storage of a set of EVM
+//! accounts at a given block:
+//!
+//! ```json
+//! {
+//!   "blockHash": "0x<hex encoded block hash>",
+//!   "accounts": {
+//!     "0x<address>": {
+//!       "nonce": "0x1",
+//!       "balance": "0xde0b6b3a7640000",
+//!       "code": "0x<hex encoded bytecode>",
+//!       "storage": {
+//!         "0x<slot>": "0x<value>"
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+//!
+//! The file can be imported in the genesis of a development spec (`build-spec --evm-state`) or
+//! used as the state overrides of a lazy loading node (`--lazy-loading-state-overrides`).
+
+use moonbeam_core_primitives::{Balance, Block, Hash};
+use parity_scale_codec::Decode;
+use sc_client_api::{Backend, StorageProvider};
+use serde::{Deserialize, Serialize};
+use sp_blockchain::HeaderBackend;
+use sp_core::{blake2_128, twox_128, Bytes, H160, H256, U256};
+use sp_runtime::generic::BlockId;
+use sp_storage::StorageKey;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// EVM accounts exported at a given block.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EvmState {
+	/// Block the accounts were exported at.
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub block_hash: Option<Hash>,
+	pub accounts: BTreeMap<H160, EvmAccount>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EvmAccount {
+	#[serde(default)]
+	pub nonce: U256,
+	#[serde(default)]
+	pub balance: U256,
+	#[serde(default)]
+	pub code: Bytes,
+	#[serde(default)]
+	pub storage: BTreeMap<H256, H256>,
+}
+
+impl From<EvmAccount> for fp_evm::GenesisAccount {
+	fn from(account: EvmAccount) -> Self {
+		Self {
+			nonce: account.nonce,
+			balance: account.balance,
+			storage: account.storage,
+			code: account.code.0,
+		}
+	}
+}
+
+/// Read an EVM state file.
+pub fn read(path: &Path) -> Result<EvmState, String> {
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+	serde_json::from_reader(file).map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+}
+
+/// Add the accounts of an EVM state to the `pallet_evm` genesis accounts of a genesis config.
+pub fn extend_genesis(genesis: &mut serde_json::Value, state: EvmState) {
+	let accounts = &mut genesis["evm"]["accounts"];
+	if !accounts.is_object() {
+		*accounts = serde_json::Value::Object(Default::default());
+	}
+	for (address, account) in state.accounts {
+		accounts[format!("{:?}", address)] =
+			serde_json::to_value(fp_evm::GenesisAccount::from(account))
+				.expect("Genesis accounts can be serialized to JSON");
+	}
+}
+
+fn blake2_128_concat(data: &[u8]) -> Vec<u8> {
+	[blake2_128(data).as_slice(), data].concat()
+}
+
+fn storage_key(pallet: &[u8], storage: &[u8], key: &[u8]) -> StorageKey {
+	StorageKey([&twox_128(pallet)[..], &twox_128(storage)[..], key].concat())
+}
+
+/// Export the balance, nonce, code and storage of the given EVM accounts.
+///
+/// The accounts are read at the given block, or at the best block if none is provided.
+pub fn export<C, BE>(
+	client: &C,
+	at: Option<BlockId<Block>>,
+	addresses: &[H160],
+) -> Result<EvmState, String>
+where
+	C: StorageProvider<Block, BE> + HeaderBackend<Block>,
+	BE: Backend<Block>,
+{
+	let at = match at {
+		Some(BlockId::Hash(hash)) => hash,
+		Some(BlockId::Number(number)) => client
+			.hash(number)
+			.map_err(|e| format!("Failed to read block #{}: {:?}", number, e))?
+			.ok_or_else(|| format!("Block #{} not found", number))?,
+		None => client.info().best_hash,
+	};
+
+	let accounts = export_accounts(
+		|key| {
+			client
+				.storage(at, key)
+				.map(|data| data.map(|data| data.0))
+				.map_err(|e| format!("Failed to read storage at {:?}: {:?}", at, e))
+		},
+		|prefix| {
+			client
+				.storage_keys(at, Some(prefix), None)
+				.map(|keys| keys.collect())
+				.map_err(|e| format!("Failed to iterate storage at {:?}: {:?}", at, e))
+		},
+		addresses,
+	)?;
+
+	Ok(EvmState {
+		block_hash: Some(at),
+		accounts,
+	})
+}
+
+/// Read the given EVM accounts from the storage, accessed through `read` and through `keys`
+/// listing the keys starting with a prefix.
+fn export_accounts(
+	read: impl Fn(&StorageKey) -> Result<Option<Vec<u8>>, String>,
+	keys: impl Fn(&StorageKey) -> Result<Vec<StorageKey>, String>,
+	addresses: &[H160],
+) -> Result<BTreeMap<H160, EvmAccount>, String> {
+	let mut accounts = BTreeMap::new();
+	for address in addresses {
+		let account_key = blake2_128_concat(address.as_bytes());
+
+		let info = read(&storage_key(b"System", b"Account", &account_key))?
+			.map(|data| {
+				frame_system::AccountInfo::<u32, pallet_balances::AccountData<Balance>>::decode(
+					&mut &data[..],
+				)
+			})
+			.transpose()
+			.map_err(|e| format!("Invalid account info for {:?}: {:?}", address, e))?
+			.unwrap_or_default();
+
+		let code = read(&storage_key(b"EVM", b"AccountCodes", &account_key))?
+			.map(|data| Vec::<u8>::decode(&mut &data[..]))
+			.transpose()
+			.map_err(|e| format!("Invalid code for {:?}: {:?}", address, e))?
+			.unwrap_or_default();
+
+		let storage_prefix = storage_key(b"EVM", b"AccountStorages", &account_key);
+		let mut storage = BTreeMap::new();
+		for key in keys(&storage_prefix)? {
+			// The key ends with the `Blake2_128Concat` hashed slot
+			let Some(slot) = key.0.len().checked_sub(32).map(|start| &key.0[start..]) else {
+				continue;
+			};
+			if let Some(value) = read(&key)? {
+				storage.insert(
+					H256::from_slice(slot),
+					H256::decode(&mut &value[..])
+						.map_err(|e| format!("Invalid storage value for {:?}: {:?}", address, e))?,
+				);
+			}
+		}
+
+		accounts.insert(
+			*address,
+			EvmAccount {
+				nonce: info.nonce.into(),
+				balance: info.data.free.into(),
+				code: code.into(),
+				storage,
+			},
+		);
+	}
+
+	Ok(accounts)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use parity_scale_codec::Encode;
+
+	type AccountInfo = frame_system::AccountInfo<u32, pallet_balances::AccountData<Balance>>;
+
+	fn account(nonce: u32, balance: u128, code: &[u8], storage: &[(u8, u8)]) -> EvmAccount {
+		EvmAccount {
+			nonce: nonce.into(),
+			balance: balance.into(),
+			code: code.to_vec().into(),
+			storage: storage
+				.iter()
+				.map(|(slot, value)| (H256::repeat_byte(*slot), H256::repeat_byte(*value)))
+				.collect(),
+		}
+	}
+
+	fn export_from(
+		storage: &BTreeMap<Vec<u8>, Vec<u8>>,
+		addresses: &[H160],
+	) -> BTreeMap<H160, EvmAccount> {
+		export_accounts(
+			|key| Ok(storage.get(&key.0).cloned()),
+			|prefix| {
+				Ok(storage
+					.keys()
+					.filter(|key| key.starts_with(&prefix.0))
+					.map(|key| StorageKey(key.clone()))
+					.collect())
+			},
+			addresses,
+		)
+		.expect("valid storage")
+	}
+
+	#[test]
+	fn export_reads_accounts_from_storage() {
+		let contract = H160::repeat_byte(1);
+		let eoa = H160::repeat_byte(2);
+		let missing = H160::repeat_byte(3);
+
+		let mut storage = BTreeMap::new();
+		for (address, nonce, free) in [(contract, 1, 10), (eoa, 7, 1_000)] {
+			let mut info = AccountInfo::default();
+			info.nonce = nonce;
+			info.data.free = free;
+			info.data.reserved = 5;
+			storage.insert(
+				storage_key(
+					b"System",
+					b"Account",
+					&blake2_128_concat(address.as_bytes()),
+				)
+				.0,
+				info.encode(),
+			);
+		}
+		let contract_key = blake2_128_concat(contract.as_bytes());
+		storage.insert(
+			storage_key(b"EVM", b"AccountCodes", &contract_key).0,
+			vec![0x60u8, 0x00].encode(),
+		);
+		for (slot, value) in [(1u8, 2u8), (3, 4)] {
+			let slot = H256::repeat_byte(slot);
+			storage.insert(
+				storage_key(
+					b"EVM",
+					b"AccountStorages",
+					&[contract_key.as_slice(), &blake2_128_concat(slot.as_bytes())].concat(),
+				)
+				.0,
+				H256::repeat_byte(value).encode(),
+			);
+		}
+
+		let accounts = export_from(&storage, &[contract, eoa, missing]);
+
+		assert_eq!(
+			accounts,
+			BTreeMap::from([
+				(contract, account(1, 10, &[0x60, 0x00], &[(1, 2), (3, 4)])),
+				(eoa, account(7, 1_000, &[], &[])),
+				(missing, EvmAccount::default()),
+			])
+		);
+	}
+
+	#[test]
+	fn extend_genesis_adds_accounts() {
+		let existing = H160::repeat_byte(1);
+		let contract = H160::repeat_byte(2);
+		let mut genesis = serde_json::json!({
+			"evm": {
+				"accounts": {
+					format!("{:?}", existing): fp_evm::GenesisAccount {
+						nonce: 1.into(),
+						balance: 2.into(),
+						storage: Default::default(),
+						code: vec![0x00],
+					},
+				},
+			},
+		});
+
+		extend_genesis(
+			&mut genesis,
+			EvmState {
+				block_hash: None,
+				accounts: BTreeMap::from([(contract, account(3, 4, &[0x60, 0x00], &[(5, 6)]))]),
+			},
+		);
+
+		let accounts: BTreeMap<H160, fp_evm::GenesisAccount> =
+			serde_json::from_value(genesis["evm"]["accounts"].clone())
+				.expect("valid genesis accounts");
+		assert_eq!(accounts.len(), 2);
+		assert_eq!(accounts[&existing].code, vec![0x00]);
+		let added = &accounts[&contract];
+		assert_eq!(added.nonce, 3.into());
+		assert_eq!(added.balance, 4.into());
+		assert_eq!(added.code, vec![0x60, 0x00]);
+		assert_eq!(
+			added.storage,
+			BTreeMap::from([(H256::repeat_byte(5), H256::repeat_byte(6))])
+		);
+	}
+
+	#[test]
+	fn extend_genesis_creates_missing_accounts() {
+		let mut genesis = serde_json::json!({ "system": {} });
+
+		extend_genesis(
+			&mut genesis,
+			EvmState {
+				block_hash: None,
+				accounts: BTreeMap::from([(H160::repeat_byte(1), account(0, 1, &[], &[]))]),
+			},
+		);
+
+		assert!(genesis["evm"]["accounts"][format!("{:?}", H160::repeat_byte(1))].is_object());
+	}
+
+	#[cfg(feature = "lazy-loading")]
+	#[test]
+	fn state_overrides_round_trip() {
+		use crate::lazy_loading::state_overrides::{evm_state_overrides, StateEntry};
+
+		let existing = H160::repeat_byte(1);
+		let contract = H160::repeat_byte(2);
+
+		// The forked chain holds 1_000 in the existing account, among other accounts
+		let mut upstream = BTreeMap::new();
+		let mut info = AccountInfo::default();
+		info.nonce = 4;
+		info.consumers = 1;
+		info.providers = 1;
+		info.data.free = 1_000;
+		info.data.reserved = 50;
+		upstream.insert(
+			storage_key(
+				b"System",
+				b"Account",
+				&blake2_128_concat(existing.as_bytes()),
+			)
+			.0,
+			info.encode(),
+		);
+		upstream.insert(
+			storage_key(b"Balances", b"TotalIssuance", &[]).0,
+			10_000u128.encode(),
+		);
+
+		let state = EvmState {
+			block_hash: None,
+			accounts: BTreeMap::from([
+				(existing, account(9, 400, &[], &[])),
+				(contract, account(1, 100, &[0x60, 0x00], &[(1, 2)])),
+			]),
+		};
+		let overrides =
+			evm_state_overrides(state.clone(), |key| Ok(upstream.get(key).cloned())).unwrap();
+
+		let mut storage = upstream.clone();
+		for entry in overrides {
+			let StateEntry::Concrete(entry) = entry else {
+				panic!("EVM state overrides are concrete entries");
+			};
+			let key = storage_key(
+				entry.pallet.as_bytes(),
+				entry.storage.as_bytes(),
+				&entry.key.unwrap_or_default(),
+			);
+			storage.insert(key.0, entry.value);
+		}
+
+		assert_eq!(export_from(&storage, &[existing, contract]), state.accounts);
+
+		let read_info = |address: H160| {
+			let key = storage_key(
+				b"System",
+				b"Account",
+				&blake2_128_concat(address.as_bytes()),
+			);
+			AccountInfo::decode(&mut storage[&key.0].as_slice()).unwrap()
+		};
+		let patched = read_info(existing);
+		assert_eq!(patched.consumers, 1);
+		assert_eq!(patched.providers, 1);
+		assert_eq!(patched.data.reserved, 50);
+		let created = read_info(contract);
+		assert_eq!(created.providers, 1);
+		assert_eq!(created.sufficients, 1);
+
+		let total_issuance_key = storage_key(b"Balances", b"TotalIssuance", &[]);
+		assert_eq!(
+			u128::decode(&mut storage[&total_issuance_key.0].as_slice()).unwrap(),
+			10_000 - 1_000 + 400 + 100
+		);
+	}
+}
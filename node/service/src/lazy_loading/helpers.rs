@@ -26,12 +26,12 @@ use std::sync::Arc;
 
 pub fn produce_first_block<Block: BlockT + sp_runtime::DeserializeOwned>(
 	backend: Arc<lazy_loading::substrate_backend::Backend<Block>>,
-	fork_checkpoint: Block,
+	fork_checkpoint: Block::Header,
 	mut state_overrides: Vec<(Vec<u8>, Vec<u8>)>,
 ) -> sp_blockchain::Result<()> {
 	let mut op = backend.begin_operation()?;
 
-	let header = fork_checkpoint.header().clone();
+	let header = fork_checkpoint;
 	let next_block_number = header.number().saturating_add(One::one());
 
 	let header: Block::Header = Block::Header::new(
@@ -95,3 +95,51 @@ pub fn get_parachain_id(rpc_client: Arc<super::rpc_client::RPC>) -> Option<u32>
 		.ok()
 		.flatten()
 }
+
+/// Upstream chain state at the fork block that the mocked relay chain must continue from.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct UpstreamChainState {
+	/// `Timestamp::Now` of the fork block, in milliseconds.
+	pub timestamp: u64,
+	/// `ParachainSystem::LastRelayChainBlockNumber` of the fork block.
+	pub relay_block_number: u32,
+	/// Relay slot stored in `AsyncBacking::SlotInfo`.
+	pub relay_slot: u64,
+	/// `Randomness::RelayEpoch` of the fork block.
+	pub relay_epoch: u64,
+}
+
+fn get_upstream_value<T: parity_scale_codec::Decode>(
+	rpc_client: &super::rpc_client::RPC,
+	pallet: &[u8],
+	storage: &[u8],
+	at: H256,
+) -> Option<T> {
+	let key = [twox_128(pallet), twox_128(storage)].concat();
+	rpc_client
+		.storage::<H256>(StorageKey(key), Some(at))
+		.ok()
+		.flatten()
+		.and_then(|data| T::decode(&mut data.0.as_slice()).ok())
+}
+
+pub fn get_upstream_chain_state(
+	rpc_client: Arc<super::rpc_client::RPC>,
+	at: H256,
+) -> UpstreamChainState {
+	UpstreamChainState {
+		timestamp: get_upstream_value(&rpc_client, b"Timestamp", b"Now", at).unwrap_or_default(),
+		relay_block_number: get_upstream_value(
+			&rpc_client,
+			b"ParachainSystem",
+			b"LastRelayChainBlockNumber",
+			at,
+		)
+		.unwrap_or_default(),
+		relay_slot: get_upstream_value::<(u64, u32)>(&rpc_client, b"AsyncBacking", b"SlotInfo", at)
+			.map(|(slot, _)| slot)
+			.unwrap_or_default(),
+		relay_epoch: get_upstream_value(&rpc_client, b"Randomness", b"RelayEpoch", at)
+			.unwrap_or_default(),
+	}
+}
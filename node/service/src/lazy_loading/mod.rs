@@ -52,10 +52,10 @@ use sp_api::{ConstructRuntimeApi, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::traits::CodeExecutor;
 use sp_core::H256;
-use sp_runtime::traits::NumberFor;
+use sp_runtime::traits::{Header as _, NumberFor};
 use std::collections::BTreeMap;
 use std::str::FromStr;
-use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
@@ -65,12 +65,18 @@ pub mod frontier_backend;
 mod helpers;
 mod lock;
 mod manual_sealing;
+mod replay;
 mod rpc_client;
-mod state_overrides;
+pub(crate) mod state_overrides;
 pub mod substrate_backend;
 
+pub use replay::replay_upstream_blocks;
+
 pub const LAZY_LOADING_LOG_TARGET: &'static str = "lazy-loading";
 
+/// Number of parachain blocks per mocked relay epoch.
+const PARA_BLOCKS_PER_RELAY_EPOCH: u32 = 10;
+
 /// Lazy loading client type.
 pub type TLazyLoadingClient<TBl, TRtApi, TExec> = sc_service::client::Client<
 	TLazyLoadingBackend<TBl>,
@@ -479,7 +485,7 @@ where
 	let overrides = Arc::new(StorageOverrideHandler::new(client.clone()));
 	let fee_history_limit = rpc_config.fee_history_limit;
 	let mut command_sink = None;
-	let mut dev_rpc_data = None;
+	let mut dev_rpc = None;
 	let collator = config.role.is_authority();
 
 	let parachain_id: ParaId = helpers::get_parachain_id(backend.rpc_client.clone())
@@ -528,6 +534,17 @@ where
 				)),
 			};
 
+		// Sealing commands issued by the dev RPC, whatever the sealing mode.
+		let (dev_command_sink, dev_commands_stream) = futures::channel::mpsc::channel(1000);
+		let snapshots: Arc<dyn moonbeam_dev_rpc::ChainSnapshots> = Arc::new(
+			crate::dev_snapshots::FrontierSnapshots::new(backend.clone(), &frontier_backend),
+		);
+		let commands_stream: Box<dyn Stream<Item = EngineCommand<H256>> + Send + Sync + Unpin> =
+			Box::new(crate::dev_snapshots::defer_finality(
+				futures::stream::select(commands_stream, dev_commands_stream),
+				snapshots.clone(),
+			));
+
 		let select_chain = maybe_select_chain.expect(
 			"`new_lazy_loading_partial` builds a `LongestChainRule` when building dev service.\
 				We specified the dev service when calling `new_partial`.\
@@ -537,12 +554,54 @@ where
 		// Create channels for mocked XCM messages.
 		let (downward_xcm_sender, downward_xcm_receiver) = flume::bounded::<Vec<u8>>(100);
 		let (hrmp_xcm_sender, hrmp_xcm_receiver) = flume::bounded::<(ParaId, Vec<u8>)>(100);
-		let additional_relay_offset = Arc::new(std::sync::atomic::AtomicU32::new(0));
-		dev_rpc_data = Some((
-			downward_xcm_sender,
-			hrmp_xcm_sender,
-			additional_relay_offset.clone(),
+		// Continue the upstream timestamp and mocked relay chain from the fork block, so that
+		// `block.number`, timestamps and relay-based randomness match the upstream chain.
+		let fork_block_number = backend.fork_checkpoint.number;
+		let upstream = helpers::get_upstream_chain_state(
+			backend.rpc_client.clone(),
+			backend.fork_checkpoint.hash(),
+		);
+		if upstream.timestamp != 0 {
+			TIMESTAMP.store(upstream.timestamp, Ordering::SeqCst);
+		}
+		let additional_relay_offset = Arc::new(std::sync::atomic::AtomicU32::new(
+			upstream
+				.relay_block_number
+				.saturating_sub(fork_block_number),
 		));
+		let additional_relay_slot_offset = Arc::new(AtomicU64::new(
+			upstream.relay_slot.saturating_sub(
+				upstream
+					.timestamp
+					.saturating_div(RELAY_CHAIN_SLOT_DURATION_MILLIS),
+			),
+		));
+		let next_timestamp = Arc::new(AtomicU64::new(0));
+		let relay_storage = Arc::new(Mutex::new(match &lazy_loading_config.dev_relay_state {
+			Some(path) => {
+				crate::dev_relay_state::load_relay_storage(path).map_err(ServiceError::Other)?
+			}
+			None => Default::default(),
+		}));
+		dev_rpc = Some(moonbeam_dev_rpc::DevRpc {
+			downward_message_channel: downward_xcm_sender,
+			hrmp_message_channel: hrmp_xcm_sender,
+			additional_relay_offset: additional_relay_offset.clone(),
+			additional_relay_slot_offset: additional_relay_slot_offset.clone(),
+			relay_storage: relay_storage.clone(),
+			next_timestamp: next_timestamp.clone(),
+			current_timestamp: &TIMESTAMP,
+			slot_duration: RELAY_CHAIN_SLOT_DURATION_MILLIS,
+			command_sink: dev_command_sink,
+			snapshots: Some(snapshots),
+			xcm_reports: Some(Arc::new(crate::dev_xcm_reports::DevXcmReports::new(
+				client.clone(),
+			))),
+			outbound_messages: Some(Arc::new(crate::dev_outbound_xcm::DevOutboundXcm::<
+				_,
+				LazyLoadingBackend,
+			>::new(client.clone()))),
+		});
 
 		// Need to clone it and store here to avoid moving of `client`
 		// variable in closure below.
@@ -585,20 +644,26 @@ where
 					let downward_xcm_receiver = downward_xcm_receiver.clone();
 					let hrmp_xcm_receiver = hrmp_xcm_receiver.clone();
 					let additional_relay_offset = additional_relay_offset.clone();
+					let additional_relay_slot_offset = additional_relay_slot_offset.clone();
+					let next_timestamp = next_timestamp.clone();
+					let relay_storage = relay_storage.clone();
 
 					// Need to clone it and store here to avoid moving of `client`
 					// variable in closure below.
 					let client_for_xcm = client_for_cidp.clone();
 
 					async move {
-						MockTimestampInherentDataProvider::advance_timestamp(
+						MockTimestampInherentDataProvider::next_timestamp(
+							&next_timestamp,
 							RELAY_CHAIN_SLOT_DURATION_MILLIS,
 						);
 
 						// Get the mocked timestamp
 						let timestamp = TIMESTAMP.load(Ordering::SeqCst);
 						// Calculate mocked slot number
-						let slot = timestamp.saturating_div(RELAY_CHAIN_SLOT_DURATION_MILLIS);
+						let slot = timestamp
+							.saturating_div(RELAY_CHAIN_SLOT_DURATION_MILLIS)
+							.saturating_add(additional_relay_slot_offset.load(Ordering::SeqCst));
 
 						let current_para_block = maybe_current_para_block?
 							.ok_or(sp_blockchain::Error::UnknownBlock(block.to_string()))?;
@@ -607,7 +672,7 @@ where
 							maybe_current_para_head?.encode(),
 						));
 
-						let additional_key_values = vec![
+						let mut additional_key_values = vec![
 							(
 								// TIMESTAMP_NOW was deprecated in runtime 4000, but should
 								// be kept for backwards compatibility with old runtimes
@@ -620,6 +685,19 @@ where
 								relay_chain::well_known_keys::CURRENT_SLOT.to_vec(),
 								Slot::from(slot).encode(),
 							),
+							// Continue the upstream relay epoch
+							(
+								relay_chain::well_known_keys::EPOCH_INDEX.to_vec(),
+								upstream
+									.relay_epoch
+									.saturating_add(
+										current_para_block
+											.saturating_sub(fork_block_number)
+											.saturating_div(PARA_BLOCKS_PER_RELAY_EPOCH)
+											.into(),
+									)
+									.encode(),
+							),
 							(
 								relay_chain::well_known_keys::ACTIVE_CONFIG.to_vec(),
 								AbridgedHostConfiguration {
@@ -640,6 +718,14 @@ where
 								.encode(),
 							),
 						];
+						// Relay storage entries set through the dev RPC take precedence
+						additional_key_values.extend(
+							relay_storage
+								.lock()
+								.expect("Relay storage lock poisoned")
+								.iter()
+								.map(|(key, value)| (key.clone(), value.clone())),
+						);
 
 						let current_para_head = client_for_xcm
 							.header(block)
@@ -673,7 +759,7 @@ where
 							relay_offset: relay_parent_offset
 								.saturating_add(additional_relay_offset.load(Ordering::SeqCst)),
 							relay_blocks_per_para_block: 1,
-							para_blocks_per_relay_epoch: 10,
+							para_blocks_per_relay_epoch: PARA_BLOCKS_PER_RELAY_EPOCH,
 							relay_randomness_config: (),
 							xcm_config: MockXcmConfig::new(
 								&*client_for_xcm,
@@ -781,7 +867,7 @@ where
 				fee_history_cache: fee_history_cache.clone(),
 				network: network.clone(),
 				sync: sync.clone(),
-				dev_rpc_data: dev_rpc_data.clone(),
+				dev_rpc: dev_rpc.clone(),
 				overrides: overrides.clone(),
 				block_data_cache: block_data_cache.clone(),
 				forced_parent_hashes: None,
@@ -0,0 +1,279 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Replay of upstream blocks on top of the lazy loading fork point.
+//!
+//! Blocks are executed against the upstream state of the fork block, accumulating their changes
+//! in a single overlay, optionally with a different runtime. The state overrides applied when
+//! running a lazy loading node are left out, so that the replay starts from the upstream state. The lazy loading state only holds
+//! the keys it has touched, so a full state root cannot be computed locally. Instead, every key
+//! written by a replayed block is compared against the upstream value at that block, as well as
+//! the `System::Events` it emitted.
+
+use super::substrate_backend::{self, ForkedLazyBackend};
+use super::LAZY_LOADING_LOG_TARGET;
+use crate::HostFunctions;
+use moonbeam_cli_opt::LazyLoadingConfig;
+use moonbeam_core_primitives::{Block, BlockNumber, Hash};
+use parity_scale_codec::{Compact, Decode, Encode};
+use sc_client_api::backend::{Backend as _, TrieCacheContext};
+use sc_executor::WasmExecutor;
+use sc_service::{Configuration, Error};
+use sp_core::storage::well_known_keys;
+use sp_core::traits::{CallContext, ReadRuntimeVersionExt, RuntimeCode, WrappedRuntimeCode};
+use sp_core::{blake2_256, twox_128};
+use sp_runtime::generic::DigestItem;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT};
+use sp_runtime::ApplyExtrinsicResult;
+use sp_state_machine::{Backend as _, OverlayedChanges, StateMachine};
+use sp_storage::StorageKey;
+use std::collections::BTreeMap;
+
+type Overlay = OverlayedChanges<sp_runtime::traits::HashingFor<Block>>;
+
+/// Outcome of replaying a single upstream block.
+#[derive(Debug)]
+struct ReplayedBlock {
+	number: BlockNumber,
+	hash: Hash,
+	failed_extrinsics: usize,
+	/// Keys written by the block whose local value differs from the upstream one.
+	diverging_keys: Vec<Vec<u8>>,
+	local_events: u32,
+	upstream_events: u32,
+	events_match: bool,
+}
+
+impl ReplayedBlock {
+	fn diverged(&self) -> bool {
+		!self.diverging_keys.is_empty() || !self.events_match
+	}
+}
+
+struct Replayer {
+	rpc_client: std::sync::Arc<super::rpc_client::RPC>,
+	state: ForkedLazyBackend<Block>,
+	executor: WasmExecutor<HostFunctions>,
+	runtime_override: Option<Vec<u8>>,
+	overlay: Overlay,
+}
+
+impl Replayer {
+	fn storage(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, String> {
+		match self.overlay.storage(key) {
+			Some(value) => Ok(value.map(|v| v.to_vec())),
+			None => self.state.storage(key).map_err(|e| format!("{:?}", e)),
+		}
+	}
+
+	fn call(&mut self, method: &str, call_data: &[u8]) -> Result<Vec<u8>, String> {
+		let code = match &self.runtime_override {
+			Some(code) => code.clone(),
+			None => self
+				.storage(well_known_keys::CODE)?
+				.ok_or("Runtime code not found in state".to_string())?,
+		};
+		let runtime_code = RuntimeCode {
+			code_fetcher: &WrappedRuntimeCode(code.as_slice().into()),
+			heap_pages: None,
+			hash: blake2_256(&code).to_vec(),
+		};
+		// Only the extensions registered for on-chain block execution are provided. The offchain,
+		// keystore and transaction pool extensions are reserved to offchain workers and
+		// transaction validation, and are never used while executing a block.
+		let mut extensions = sp_externalities::Extensions::default();
+		extensions.register(ReadRuntimeVersionExt::new(self.executor.clone()));
+
+		StateMachine::new(
+			&self.state,
+			&mut self.overlay,
+			&self.executor,
+			method,
+			call_data,
+			&mut extensions,
+			&runtime_code,
+			CallContext::Onchain,
+		)
+		.execute()
+		.map_err(|e| format!("{} failed: {:?}", method, e))
+	}
+
+	fn overlay_entries(&mut self) -> BTreeMap<Vec<u8>, Option<Vec<u8>>> {
+		self.overlay
+			.changes_mut()
+			.map(|(key, value)| (key.clone(), value.value_ref().cloned()))
+			.collect()
+	}
+
+	fn replay_block(&mut self, number: BlockNumber) -> Result<ReplayedBlock, String> {
+		let hash = self
+			.rpc_client
+			.block_hash::<Block>(Some(number))
+			.map_err(|e| format!("{:?}", e))?
+			.ok_or(format!("Upstream block #{} not found", number))?;
+		let block = self
+			.rpc_client
+			.block::<Block, _>(Some(hash))
+			.map_err(|e| format!("{:?}", e))?
+			.ok_or(format!("Upstream block {:?} not found", hash))?
+			.block;
+		let (upstream_header, extrinsics) = block.deconstruct();
+
+		// Execute the block with the upstream pre-runtime digests, without the seal
+		let mut header = upstream_header.clone();
+		header
+			.digest_mut()
+			.logs
+			.retain(|item| !matches!(item, DigestItem::Seal(..)));
+		header.set_state_root(Default::default());
+		header.set_extrinsics_root(Default::default());
+
+		let before = self.overlay_entries();
+		self.call("Core_initialize_block", &header.encode())?;
+
+		let mut failed_extrinsics = 0;
+		for extrinsic in extrinsics {
+			let result = self.call("BlockBuilder_apply_extrinsic", &extrinsic.encode())?;
+			match ApplyExtrinsicResult::decode(&mut result.as_slice()) {
+				Ok(Ok(Ok(()))) => {}
+				_ => failed_extrinsics += 1,
+			}
+		}
+		self.call("BlockBuilder_finalize_block", &[])?;
+
+		let mut diverging_keys = Vec::new();
+		for (key, value) in self.overlay_entries() {
+			if before.get(&key) == Some(&value) {
+				continue;
+			}
+			let upstream = self
+				.rpc_client
+				.storage(StorageKey(key.clone()), Some(hash))
+				.map_err(|e| format!("{:?}", e))?
+				.map(|data| data.0);
+			if upstream != value {
+				diverging_keys.push(key);
+			}
+		}
+
+		let events_key = [twox_128(b"System"), twox_128(b"Events")].concat();
+		let local_events = self.storage(&events_key)?.unwrap_or_default();
+		let upstream_events = self
+			.rpc_client
+			.storage(StorageKey(events_key), Some(hash))
+			.map_err(|e| format!("{:?}", e))?
+			.map(|data| data.0)
+			.unwrap_or_default();
+		let events_count =
+			|events: &[u8]| Compact::<u32>::decode(&mut &events[..]).map_or(0, |c| c.0);
+
+		Ok(ReplayedBlock {
+			number,
+			hash,
+			failed_extrinsics,
+			diverging_keys,
+			local_events: events_count(&local_events),
+			upstream_events: events_count(&upstream_events),
+			events_match: local_events == upstream_events,
+		})
+	}
+}
+
+/// Replay the upstream blocks following the fork block, up to `replay_to` included, and log
+/// the divergences found between the local execution and the upstream chain.
+pub async fn replay_upstream_blocks(
+	mut config: Configuration,
+	lazy_loading_config: LazyLoadingConfig,
+	replay_to: BlockNumber,
+) -> Result<(), Error> {
+	let backend = substrate_backend::new_fork_backend::<Block>(&mut config, &lazy_loading_config)?;
+	let fork_block = backend.fork_checkpoint.clone();
+
+	let runtime_override = lazy_loading_config
+		.runtime_override
+		.as_ref()
+		.map(std::fs::read)
+		.transpose()
+		.map_err(|e| Error::Other(format!("Failed to read runtime override: {}", e)))?;
+
+	let mut replayer = Replayer {
+		rpc_client: backend.rpc_client.clone(),
+		state: backend.state_at(fork_block.hash(), TrieCacheContext::Untrusted)?,
+		executor: WasmExecutor::builder().build(),
+		runtime_override,
+		overlay: Default::default(),
+	};
+
+	let first = fork_block.number().saturating_add(1);
+	if replay_to < first {
+		return Err(Error::Other(format!(
+			"Nothing to replay: the fork block is #{}",
+			fork_block.number()
+		)));
+	}
+
+	log::info!(
+		target: LAZY_LOADING_LOG_TARGET,
+		"Replaying upstream blocks #{} to #{}",
+		first,
+		replay_to
+	);
+
+	let mut diverged_blocks = 0;
+	for number in first..=replay_to {
+		let replayed = replayer.replay_block(number).map_err(Error::Other)?;
+
+		if replayed.diverged() {
+			diverged_blocks += 1;
+			log::warn!(
+				target: LAZY_LOADING_LOG_TARGET,
+				"#{} ({:?}) diverged: {} keys differ, events match: {} ({} local, {} upstream), {} failed extrinsics",
+				replayed.number,
+				replayed.hash,
+				replayed.diverging_keys.len(),
+				replayed.events_match,
+				replayed.local_events,
+				replayed.upstream_events,
+				replayed.failed_extrinsics,
+			);
+			for key in &replayed.diverging_keys {
+				log::warn!(
+					target: LAZY_LOADING_LOG_TARGET,
+					"    0x{}",
+					hex::encode(key)
+				);
+			}
+		} else {
+			log::info!(
+				target: LAZY_LOADING_LOG_TARGET,
+				"#{} ({:?}) matches upstream ({} events, {} failed extrinsics)",
+				replayed.number,
+				replayed.hash,
+				replayed.local_events,
+				replayed.failed_extrinsics,
+			);
+		}
+	}
+
+	log::info!(
+		target: LAZY_LOADING_LOG_TARGET,
+		"Replay completed: {} of {} blocks diverged from upstream",
+		diverged_blocks,
+		replay_to - first + 1
+	);
+
+	Ok(())
+}
@@ -16,99 +16,397 @@
 
 use cumulus_primitives_core::BlockT;
 use fc_rpc_v2_api::types::H256;
-use jsonrpsee::http_client::HttpClient;
+use jsonrpsee::core::client::{
+	BatchResponse, ClientT, Error as ClientError, Subscription, SubscriptionClientT,
+};
+use jsonrpsee::core::params::BatchRequestBuilder;
+use jsonrpsee::core::traits::ToRpcParams;
+use jsonrpsee::http_client::{HeaderMap, HttpClient, HttpClientBuilder};
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
 use moonbeam_core_primitives::BlockNumber;
+use parking_lot::RwLock;
 use serde::de::DeserializeOwned;
 use sp_api::__private::HeaderT;
 use sp_rpc::list::ListOrValue;
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::generic::SignedBlock;
 use sp_storage::{StorageData, StorageKey};
+use std::collections::HashMap;
 use std::future::Future;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_retry::strategy::FixedInterval;
-use tokio_retry::Retry;
+use tokio_retry::RetryIf;
+use url::Url;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Whether the error comes from the endpoint itself (connection, transport or timeout), in which
+/// case the request can be retried on another endpoint. JSON-RPC errors returned by the remote
+/// node are answers to the request and would be the same on any endpoint.
+fn is_endpoint_error(err: &ClientError) -> bool {
+	matches!(
+		err,
+		ClientError::Transport(_) | ClientError::RestartNeeded(_) | ClientError::RequestTimeout
+	)
+}
+
+/// Build the headers sent with every request, keeping every value of repeated header names.
+fn parse_headers(headers: &[(String, String)]) -> Result<HeaderMap, String> {
+	let mut header_map = HeaderMap::new();
+	for (name, value) in headers {
+		let header = HeaderMap::try_from(&HashMap::from([(name.clone(), value.clone())]))
+			.map_err(|e| format!("Invalid RPC header {}: {:?}", name, e))?;
+		for (name, value) in header.iter() {
+			header_map.append(name, value.clone());
+		}
+	}
+	Ok(header_map)
+}
+
+/// Connection to a remote RPC endpoint, over HTTP or WebSocket.
+#[derive(Debug, Clone)]
+pub enum RpcTransport {
+	Http(HttpClient),
+	Ws(Arc<WsClient>),
+}
+
+impl RpcTransport {
+	async fn connect(url: &Url, headers: &HeaderMap) -> Result<Self, ClientError> {
+		match url.scheme() {
+			"ws" | "wss" => WsClientBuilder::default()
+				.set_headers(headers.clone())
+				.max_request_size(u32::MAX)
+				.max_response_size(u32::MAX)
+				.request_timeout(REQUEST_TIMEOUT)
+				.build(url.as_str())
+				.await
+				.map(|client| Self::Ws(Arc::new(client))),
+			_ => HttpClientBuilder::default()
+				.set_headers(headers.clone())
+				.max_request_size(u32::MAX)
+				.max_response_size(u32::MAX)
+				.request_timeout(REQUEST_TIMEOUT)
+				.build(url.as_str())
+				.map(Self::Http),
+		}
+	}
+
+	fn is_connected(&self) -> bool {
+		match self {
+			Self::Http(_) => true,
+			Self::Ws(client) => client.is_connected(),
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl ClientT for RpcTransport {
+	async fn notification<Params>(&self, method: &str, params: Params) -> Result<(), ClientError>
+	where
+		Params: ToRpcParams + Send,
+	{
+		match self {
+			Self::Http(client) => client.notification(method, params).await,
+			Self::Ws(client) => client.notification(method, params).await,
+		}
+	}
+
+	async fn request<R, Params>(&self, method: &str, params: Params) -> Result<R, ClientError>
+	where
+		R: DeserializeOwned,
+		Params: ToRpcParams + Send,
+	{
+		match self {
+			Self::Http(client) => client.request(method, params).await,
+			Self::Ws(client) => client.request(method, params).await,
+		}
+	}
+
+	async fn batch_request<'a, R>(
+		&self,
+		batch: BatchRequestBuilder<'a>,
+	) -> Result<BatchResponse<'a, R>, ClientError>
+	where
+		R: DeserializeOwned + std::fmt::Debug + 'a,
+	{
+		match self {
+			Self::Http(client) => client.batch_request(batch).await,
+			Self::Ws(client) => client.batch_request(batch).await,
+		}
+	}
+}
+
+#[async_trait::async_trait]
+impl SubscriptionClientT for RpcTransport {
+	async fn subscribe<'a, Notif, Params>(
+		&self,
+		subscribe_method: &'a str,
+		params: Params,
+		unsubscribe_method: &'a str,
+	) -> Result<Subscription<Notif>, ClientError>
+	where
+		Params: ToRpcParams + Send,
+		Notif: DeserializeOwned,
+	{
+		match self {
+			Self::Http(client) => {
+				client
+					.subscribe(subscribe_method, params, unsubscribe_method)
+					.await
+			}
+			Self::Ws(client) => {
+				client
+					.subscribe(subscribe_method, params, unsubscribe_method)
+					.await
+			}
+		}
+	}
+
+	async fn subscribe_to_method<'a, Notif>(
+		&self,
+		method: &'a str,
+	) -> Result<Subscription<Notif>, ClientError>
+	where
+		Notif: DeserializeOwned,
+	{
+		match self {
+			Self::Http(client) => client.subscribe_to_method(method).await,
+			Self::Ws(client) => client.subscribe_to_method(method).await,
+		}
+	}
+}
+
+#[derive(Debug)]
+struct Endpoint {
+	url: Url,
+	client: RwLock<Option<RpcTransport>>,
+	healthy: AtomicBool,
+}
+
+impl Endpoint {
+	fn is_available(&self) -> bool {
+		self.healthy.load(Ordering::SeqCst) && self.client.read().is_some()
+	}
+
+	/// Check that the endpoint answers requests, reconnecting it if the connection was lost.
+	async fn check_health(&self, headers: &HeaderMap) {
+		let client = self.client.read().clone();
+		let client = match client {
+			Some(client) if client.is_connected() => Some(client),
+			_ => match RpcTransport::connect(&self.url, headers).await {
+				Ok(client) => {
+					*self.client.write() = Some(client.clone());
+					Some(client)
+				}
+				Err(err) => {
+					log::debug!(
+						target: super::LAZY_LOADING_LOG_TARGET,
+						"Failed to connect to {}: {:?}",
+						self.url,
+						err
+					);
+					None
+				}
+			},
+		};
+
+		let healthy = match client {
+			Some(client) => {
+				substrate_rpc_client::SystemApi::<H256, BlockNumber>::system_chain(&client)
+					.await
+					.is_ok()
+			}
+			None => false,
+		};
+
+		if self.healthy.swap(healthy, Ordering::SeqCst) != healthy {
+			if healthy {
+				log::info!(
+					target: super::LAZY_LOADING_LOG_TARGET,
+					"RPC endpoint {} is healthy again",
+					self.url
+				);
+			} else {
+				log::warn!(
+					target: super::LAZY_LOADING_LOG_TARGET,
+					"RPC endpoint {} is unhealthy",
+					self.url
+				);
+			}
+		}
+	}
+}
 
 #[derive(Debug, Clone)]
 pub struct RPC {
-	http_client: HttpClient,
+	endpoints: Arc<Vec<Endpoint>>,
+	active_endpoint: Arc<AtomicUsize>,
+	headers: HeaderMap,
 	delay_between_requests_ms: u32,
 	max_retries_per_request: u32,
 	counter: Arc<AtomicU64>,
 }
 
 impl RPC {
-	pub fn new(
-		http_client: HttpClient,
+	/// Connect to the given endpoints, the first one being used until it fails.
+	/// The `headers` are sent with every request, e.g. to authenticate with the RPC provider.
+	pub fn connect(
+		urls: Vec<Url>,
+		headers: &[(String, String)],
 		delay_between_requests_ms: u32,
 		max_retries_per_request: u32,
-	) -> Self {
-		Self {
-			http_client,
+	) -> Result<Self, String> {
+		let headers = parse_headers(headers)?;
+
+		let endpoints: Vec<Endpoint> = urls
+			.into_iter()
+			.map(|url| {
+				let client = tokio::task::block_in_place(|| {
+					tokio::runtime::Handle::current()
+						.block_on(RpcTransport::connect(&url, &headers))
+				})
+				.map_err(|e| {
+					log::warn!(
+						target: super::LAZY_LOADING_LOG_TARGET,
+						"Failed to connect to {}: {:?}",
+						url,
+						e
+					)
+				})
+				.ok();
+
+				Endpoint {
+					url,
+					healthy: AtomicBool::new(client.is_some()),
+					client: RwLock::new(client),
+				}
+			})
+			.collect();
+
+		let active_endpoint = endpoints
+			.iter()
+			.position(Endpoint::is_available)
+			.ok_or("Could not connect to any RPC endpoint".to_string())?;
+
+		Ok(Self {
+			endpoints: Arc::new(endpoints),
+			active_endpoint: Arc::new(AtomicUsize::new(active_endpoint)),
+			headers,
 			delay_between_requests_ms,
 			max_retries_per_request,
 			counter: Default::default(),
-		}
+		})
 	}
-	pub fn system_chain(&self) -> Result<String, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			substrate_rpc_client::SystemApi::<H256, BlockNumber>::system_chain(&self.http_client)
-		};
 
-		self.block_on(request)
+	/// Periodically check the health of every endpoint, switching away from the active one
+	/// when it becomes unhealthy.
+	pub fn spawn_health_checks(&self) {
+		let rpc = self.clone();
+		tokio::spawn(async move {
+			let mut interval = tokio::time::interval(HEALTH_CHECK_INTERVAL);
+			loop {
+				interval.tick().await;
+				for endpoint in rpc.endpoints.iter() {
+					endpoint.check_health(&rpc.headers).await;
+				}
+				if !rpc.endpoints[rpc.active_endpoint.load(Ordering::SeqCst)].is_available() {
+					rpc.failover();
+				}
+			}
+		});
 	}
 
-	pub fn system_properties(
-		&self,
-	) -> Result<sc_chain_spec::Properties, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			substrate_rpc_client::SystemApi::<H256, BlockNumber>::system_properties(
-				&self.http_client,
-			)
-		};
+	fn client(&self) -> Result<RpcTransport, ClientError> {
+		let endpoint = &self.endpoints[self.active_endpoint.load(Ordering::SeqCst)];
+		endpoint.client.read().clone().ok_or_else(|| {
+			ClientError::RestartNeeded(Arc::new(ClientError::Custom(format!(
+				"Not connected to {}",
+				endpoint.url
+			))))
+		})
+	}
 
-		self.block_on(request)
+	/// Switch to the next available endpoint, if any.
+	fn failover(&self) {
+		let count = self.endpoints.len();
+		if count < 2 {
+			return;
+		}
+
+		let current = self.active_endpoint.load(Ordering::SeqCst);
+		let next = (1..count)
+			.map(|offset| (current + offset) % count)
+			.find(|index| self.endpoints[*index].is_available())
+			.unwrap_or((current + 1) % count);
+
+		if self
+			.active_endpoint
+			.compare_exchange(current, next, Ordering::SeqCst, Ordering::SeqCst)
+			.is_ok()
+		{
+			log::warn!(
+				target: super::LAZY_LOADING_LOG_TARGET,
+				"Switching RPC endpoint from {} to {}",
+				self.endpoints[current].url,
+				self.endpoints[next].url
+			);
+		}
+	}
+
+	pub fn system_chain(&self) -> Result<String, ClientError> {
+		self.block_on(&|client| async move {
+			substrate_rpc_client::SystemApi::<H256, BlockNumber>::system_chain(&client).await
+		})
+	}
+
+	pub fn system_properties(&self) -> Result<sc_chain_spec::Properties, ClientError> {
+		self.block_on(&|client| async move {
+			substrate_rpc_client::SystemApi::<H256, BlockNumber>::system_properties(&client).await
+		})
 	}
 
 	pub fn block<Block, Hash: Clone>(
 		&self,
 		hash: Option<Hash>,
-	) -> Result<Option<SignedBlock<Block>>, jsonrpsee::core::ClientError>
+	) -> Result<Option<SignedBlock<Block>>, ClientError>
 	where
 		Block: BlockT + DeserializeOwned,
 		Hash: 'static + Send + Sync + sp_runtime::Serialize + DeserializeOwned,
 	{
-		let request = &|| {
-			substrate_rpc_client::ChainApi::<
-				BlockNumber,
-				Hash,
-				Block::Header,
-				SignedBlock<Block>,
-			>::block(&self.http_client, hash.clone())
-		};
-
-		self.block_on(request)
+		self.block_on(&|client| {
+			let hash = hash.clone();
+			async move {
+				substrate_rpc_client::ChainApi::<
+					BlockNumber,
+					Hash,
+					Block::Header,
+					SignedBlock<Block>,
+				>::block(&client, hash)
+				.await
+			}
+		})
 	}
 
 	pub fn block_hash<Block: BlockT + DeserializeOwned>(
 		&self,
 		block_number: Option<<Block::Header as HeaderT>::Number>,
-	) -> Result<Option<Block::Hash>, jsonrpsee::core::ClientError> {
-		let request = &|| {
+	) -> Result<Option<Block::Hash>, ClientError> {
+		self.block_on(&|client| async move {
 			substrate_rpc_client::ChainApi::<
 				<Block::Header as HeaderT>::Number,
 				Block::Hash,
 				Block::Header,
 				SignedBlock<Block>,
 			>::block_hash(
-				&self.http_client,
+				&client,
 				block_number.map(|n| ListOrValue::Value(NumberOrHex::Hex(n.into()))),
 			)
-		};
-
-		self.block_on(request).map(|ok| match ok {
+			.await
+		})
+		.map(|ok| match ok {
 			ListOrValue::List(v) => v.get(0).map_or(None, |some| *some),
 			ListOrValue::Value(v) => v,
 		})
@@ -117,17 +415,16 @@ impl RPC {
 	pub fn header<Block: BlockT + DeserializeOwned>(
 		&self,
 		hash: Option<Block::Hash>,
-	) -> Result<Option<Block::Header>, jsonrpsee::core::ClientError> {
-		let request = &|| {
+	) -> Result<Option<Block::Header>, ClientError> {
+		self.block_on(&|client| async move {
 			substrate_rpc_client::ChainApi::<
 				BlockNumber,
 				Block::Hash,
 				Block::Header,
 				SignedBlock<Block>,
-			>::header(&self.http_client, hash)
-		};
-
-		self.block_on(request)
+			>::header(&client, hash)
+			.await
+		})
 	}
 
 	pub fn storage_hash<
@@ -136,16 +433,11 @@ impl RPC {
 		&self,
 		key: StorageKey,
 		at: Option<Hash>,
-	) -> Result<Option<Hash>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			substrate_rpc_client::StateApi::<Hash>::storage_hash(
-				&self.http_client,
-				key.clone(),
-				at.clone(),
-			)
-		};
-
-		self.block_on(request)
+	) -> Result<Option<Hash>, ClientError> {
+		self.block_on(&|client| {
+			let (key, at) = (key.clone(), at.clone());
+			async move { substrate_rpc_client::StateApi::<Hash>::storage_hash(&client, key, at).await }
+		})
 	}
 
 	pub fn storage<
@@ -154,16 +446,11 @@ impl RPC {
 		&self,
 		key: StorageKey,
 		at: Option<Hash>,
-	) -> Result<Option<StorageData>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			substrate_rpc_client::StateApi::<Hash>::storage(
-				&self.http_client,
-				key.clone(),
-				at.clone(),
-			)
-		};
-
-		self.block_on(request)
+	) -> Result<Option<StorageData>, ClientError> {
+		self.block_on(&|client| {
+			let (key, at) = (key.clone(), at.clone());
+			async move { substrate_rpc_client::StateApi::<Hash>::storage(&client, key, at).await }
+		})
 	}
 
 	pub fn storage_keys_paged<
@@ -174,17 +461,16 @@ impl RPC {
 		count: u32,
 		start_key: Option<StorageKey>,
 		at: Option<Hash>,
-	) -> Result<Vec<sp_state_machine::StorageKey>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			substrate_rpc_client::StateApi::<Hash>::storage_keys_paged(
-				&self.http_client,
-				key.clone(),
-				count.clone(),
-				start_key.clone(),
-				at.clone(),
-			)
-		};
-		let result = self.block_on(request);
+	) -> Result<Vec<sp_state_machine::StorageKey>, ClientError> {
+		let result = self.block_on(&|client| {
+			let (key, start_key, at) = (key.clone(), start_key.clone(), at.clone());
+			async move {
+				substrate_rpc_client::StateApi::<Hash>::storage_keys_paged(
+					&client, key, count, start_key, at,
+				)
+				.await
+			}
+		});
 
 		match result {
 			Ok(result) => Ok(result.iter().map(|item| item.0.clone()).collect()),
@@ -195,52 +481,50 @@ impl RPC {
 	pub fn transaction_by_hash(
 		&self,
 		eth_transaction_hash: &H256,
-	) -> Result<Option<fc_rpc_v2_api::types::Transaction>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			fc_rpc_v2_api::eth::EthTransactionApiClient::transaction_by_hash(
-				&self.http_client,
-				eth_transaction_hash.clone(),
-			)
-		};
-
-		self.block_on(request)
+	) -> Result<Option<fc_rpc_v2_api::types::Transaction>, ClientError> {
+		self.block_on(&|client| {
+			let eth_transaction_hash = eth_transaction_hash.clone();
+			async move {
+				fc_rpc_v2_api::eth::EthTransactionApiClient::transaction_by_hash(
+					&client,
+					eth_transaction_hash,
+				)
+				.await
+			}
+		})
 	}
 
 	pub fn block_by_hash(
 		&self,
 		eth_block_hash: &H256,
 		full: bool,
-	) -> Result<Option<fc_rpc_v2_api::types::Block>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			fc_rpc_v2_api::eth::EthBlockApiClient::block_by_hash(
-				&self.http_client,
-				eth_block_hash.clone(),
-				full,
-			)
-		};
-
-		self.block_on(request)
+	) -> Result<Option<fc_rpc_v2_api::types::Block>, ClientError> {
+		self.block_on(&|client| {
+			let eth_block_hash = eth_block_hash.clone();
+			async move {
+				fc_rpc_v2_api::eth::EthBlockApiClient::block_by_hash(&client, eth_block_hash, full)
+					.await
+			}
+		})
 	}
 
 	pub fn block_by_number(
 		&self,
 		block_number: fc_rpc_v2_api::types::BlockNumberOrTag,
 		full: bool,
-	) -> Result<Option<fc_rpc_v2_api::types::Block>, jsonrpsee::core::ClientError> {
-		let request = &|| {
-			fc_rpc_v2_api::eth::EthBlockApiClient::block_by_number(
-				&self.http_client,
-				block_number.clone(),
-				full,
-			)
-		};
-
-		self.block_on(request)
+	) -> Result<Option<fc_rpc_v2_api::types::Block>, ClientError> {
+		self.block_on(&|client| {
+			let block_number = block_number.clone();
+			async move {
+				fc_rpc_v2_api::eth::EthBlockApiClient::block_by_number(&client, block_number, full)
+					.await
+			}
+		})
 	}
 
-	fn block_on<F, T, E>(&self, f: &dyn Fn() -> F) -> Result<T, E>
+	fn block_on<F, T>(&self, f: &dyn Fn(RpcTransport) -> F) -> Result<T, ClientError>
 	where
-		F: Future<Output = Result<T, E>>,
+		F: Future<Output = Result<T, ClientError>>,
 	{
 		use tokio::runtime::Handle;
 
@@ -262,11 +546,24 @@ impl RPC {
 				// Explicit request delay, to avoid getting 429 errors
 				let _ = tokio::time::sleep(delay_between_requests).await;
 
-				// Retry request in case of failure
+				// Retry request in case of endpoint failure, switching to the next endpoint if any
 				// The maximum number of retries is specified by `self.max_retries_per_request`
 				let retry_strategy = FixedInterval::new(delay_between_requests)
 					.take(self.max_retries_per_request as usize);
-				let result = Retry::spawn(retry_strategy, f).await;
+				let request = || {
+					let request = self.client().map(f);
+					async move {
+						let result = match request {
+							Ok(request) => request.await,
+							Err(err) => Err(err),
+						};
+						if result.as_ref().is_err_and(is_endpoint_error) {
+							self.failover();
+						}
+						result
+					}
+				};
+				let result = RetryIf::spawn(retry_strategy, request, is_endpoint_error).await;
 
 				log::debug!(
 					target: super::LAZY_LOADING_LOG_TARGET,
@@ -282,3 +579,164 @@ impl RPC {
 		})
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use jsonrpsee::server::{RpcModule, Server, ServerHandle};
+	use jsonrpsee::types::{ErrorObject, ErrorObjectOwned};
+
+	/// Start a local node answering `system_chain` with the given result.
+	async fn start_server(chain: Result<&'static str, &'static str>) -> (Url, ServerHandle) {
+		let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+		let url = Url::parse(&format!("http://{}", server.local_addr().unwrap())).unwrap();
+		let mut module = RpcModule::new(());
+		module
+			.register_method("system_chain", move |_, _, _| {
+				chain
+					.map(String::from)
+					.map_err(|message| ErrorObject::owned(-32000, message, None::<()>))
+			})
+			.unwrap();
+		(url, server.start(module))
+	}
+
+	async fn stop_server(handle: ServerHandle) {
+		handle.stop().unwrap();
+		handle.stopped().await;
+	}
+
+	fn endpoint(url: &Url, healthy: bool) -> Endpoint {
+		Endpoint {
+			url: url.clone(),
+			client: RwLock::new(Some(RpcTransport::Http(
+				HttpClientBuilder::default().build(url.as_str()).unwrap(),
+			))),
+			healthy: AtomicBool::new(healthy),
+		}
+	}
+
+	fn rpc(endpoints: Vec<Endpoint>) -> RPC {
+		RPC {
+			endpoints: Arc::new(endpoints),
+			active_endpoint: Default::default(),
+			headers: HeaderMap::new(),
+			delay_between_requests_ms: 0,
+			max_retries_per_request: 3,
+			counter: Default::default(),
+		}
+	}
+
+	#[test]
+	fn parse_headers_keeps_repeated_names() {
+		let headers = parse_headers(&[
+			("X-Api-Key".to_string(), "first".to_string()),
+			("Authorization".to_string(), "Bearer token".to_string()),
+			("x-api-key".to_string(), "second".to_string()),
+		])
+		.unwrap();
+
+		assert_eq!(headers.len(), 3);
+		assert_eq!(
+			headers.get_all("x-api-key").iter().collect::<Vec<_>>(),
+			vec!["first", "second"]
+		);
+		assert_eq!(headers.get("authorization").unwrap(), "Bearer token");
+	}
+
+	#[test]
+	fn parse_headers_rejects_invalid_headers() {
+		assert!(parse_headers(&[("X Api Key".to_string(), "key".to_string())]).is_err());
+		assert!(parse_headers(&[("X-Api-Key".to_string(), "key\n".to_string())]).is_err());
+	}
+
+	#[test]
+	fn only_endpoint_errors_are_retried() {
+		assert!(is_endpoint_error(&ClientError::RequestTimeout));
+		assert!(is_endpoint_error(&ClientError::RestartNeeded(Arc::new(
+			ClientError::Custom("connection closed".to_string())
+		))));
+		assert!(!is_endpoint_error(&ClientError::Call(
+			ErrorObjectOwned::owned(-32000, "execution reverted", None::<()>)
+		)));
+		assert!(!is_endpoint_error(&ClientError::Custom(
+			"unexpected response".to_string()
+		)));
+	}
+
+	#[tokio::test]
+	async fn failover_rotates_to_next_available_endpoint() {
+		let urls: Vec<Url> = (1..=3)
+			.map(|port| Url::parse(&format!("http://127.0.0.1:{}", port)).unwrap())
+			.collect();
+		let rpc = rpc(vec![
+			endpoint(&urls[0], true),
+			endpoint(&urls[1], false),
+			endpoint(&urls[2], true),
+		]);
+
+		// the unhealthy endpoint is skipped
+		rpc.failover();
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 2);
+		rpc.failover();
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 0);
+
+		// without any other available endpoint, the next one is tried
+		rpc.endpoints[2].healthy.store(false, Ordering::SeqCst);
+		rpc.failover();
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 1);
+	}
+
+	#[tokio::test]
+	async fn failover_keeps_single_endpoint() {
+		let rpc = rpc(vec![endpoint(
+			&Url::parse("http://127.0.0.1:1").unwrap(),
+			true,
+		)]);
+
+		rpc.failover();
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 0);
+	}
+
+	#[tokio::test]
+	async fn check_health_tracks_endpoint_availability() {
+		let (url, handle) = start_server(Ok("Moonbase")).await;
+		let endpoint = endpoint(&url, false);
+
+		endpoint.check_health(&HeaderMap::new()).await;
+		assert!(endpoint.is_available());
+
+		stop_server(handle).await;
+		endpoint.check_health(&HeaderMap::new()).await;
+		assert!(!endpoint.is_available());
+	}
+
+	#[tokio::test]
+	async fn check_health_marks_failing_endpoint_unhealthy() {
+		let (url, handle) = start_server(Err("unavailable")).await;
+		let endpoint = endpoint(&url, true);
+
+		endpoint.check_health(&HeaderMap::new()).await;
+		assert!(!endpoint.is_available());
+
+		stop_server(handle).await;
+	}
+
+	#[tokio::test(flavor = "multi_thread")]
+	async fn requests_fail_over_on_connection_errors_only() {
+		let (first_url, first_handle) = start_server(Err("unknown block")).await;
+		let (second_url, second_handle) = start_server(Ok("Moonbase")).await;
+		let rpc = RPC::connect(vec![first_url, second_url], &[], 0, 3).unwrap();
+
+		// an error returned by the remote node is not retried on another endpoint
+		assert!(matches!(rpc.system_chain(), Err(ClientError::Call(_))));
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 0);
+
+		// a connection error switches to the next endpoint
+		stop_server(first_handle).await;
+		assert_eq!(rpc.system_chain().unwrap(), "Moonbase");
+		assert_eq!(rpc.active_endpoint.load(Ordering::SeqCst), 1);
+
+		stop_server(second_handle).await;
+	}
+}
@@ -15,11 +15,13 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::chain_spec::generate_accounts;
+use crate::evm_state::EvmState;
 use moonbeam_core_primitives::{AccountId, Balance};
 use pallet_parachain_staking::{Bond, CandidateMetadata, CollatorSnapshot, Delegations};
-use parity_scale_codec::Encode;
+use parity_scale_codec::{Decode, Encode};
 use serde::Deserialize;
-use sp_core::{blake2_128, twox_64};
+use sp_core::{blake2_128, keccak_256, twox_128, twox_64, H256};
+use sp_runtime::{traits::UniqueSaturatedInto, Perbill};
 use std::io::Read;
 use std::path::PathBuf;
 
@@ -52,6 +54,16 @@ pub enum StateEntry {
 	Raw(StateEntryRaw),
 }
 
+type AccountInfo = frame_system::AccountInfo<u32, pallet_balances::AccountData<Balance>>;
+
+/// State overrides file, either a list of storage entries or an exported EVM state.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+enum StateOverrides {
+	Entries(Vec<StateEntry>),
+	Evm(EvmState),
+}
+
 /// Mandatory state overrides that most exist when starting a node in lazy loading mode.
 pub fn base_state_overrides(runtime_code: Option<PathBuf>) -> Vec<StateEntry> {
 	use hex_literal::hex;
@@ -67,13 +79,6 @@ pub fn base_state_overrides(runtime_code: Option<PathBuf>) -> Vec<StateEntry> {
 			key: None,
 			value: Vec::new(),
 		}),
-		// Reset LastRelayChainBlockNumber
-		StateEntry::Concrete(StateEntryConcrete {
-			pallet: "ParachainSystem".to_string(),
-			storage: "LastRelayChainBlockNumber".to_string(),
-			key: None,
-			value: 0u32.encode(),
-		}),
 		StateEntry::Concrete(StateEntryConcrete {
 			pallet: "AuthorMapping".to_string(),
 			storage: "NimbusLookup".to_string(),
@@ -165,17 +170,11 @@ pub fn base_state_overrides(runtime_code: Option<PathBuf>) -> Vec<StateEntry> {
 					bond: alith_staking_bond.clone(),
 					delegations: Default::default(),
 					total: alith_staking_bond,
+					commission: Perbill::from_percent(20),
 				}
 				.encode()
 			},
 		}),
-		// Reset SlotInfo
-		StateEntry::Concrete(StateEntryConcrete {
-			pallet: "AsyncBacking".to_string(),
-			storage: "SlotInfo".to_string(),
-			key: None,
-			value: (1u64, 1u32).encode(),
-		}),
 	];
 
 	// Default mnemonic if none was provided
@@ -224,9 +223,117 @@ pub fn base_state_overrides(runtime_code: Option<PathBuf>) -> Vec<StateEntry> {
 	overrides
 }
 
-pub fn read(path: PathBuf) -> Result<Vec<StateEntry>, String> {
+/// Storage entries setting the balance, nonce, code and storage of the exported EVM accounts.
+///
+/// `upstream` reads the storage of the forked chain. Only the nonce and free balance of the
+/// accounts existing upstream are overridden, and the total issuance is adjusted accordingly.
+pub fn evm_state_overrides(
+	state: EvmState,
+	upstream: impl Fn(&[u8]) -> Result<Option<Vec<u8>>, String>,
+) -> Result<Vec<StateEntry>, String> {
+	let storage_key = |pallet: &str, storage: &str, key: &[u8]| {
+		[
+			twox_128(pallet.as_bytes()).as_slice(),
+			&twox_128(storage.as_bytes()),
+			key,
+		]
+		.concat()
+	};
+
+	let mut total_issuance: Balance = upstream(&storage_key("Balances", "TotalIssuance", &[]))?
+		.map(|data| Balance::decode(&mut data.as_slice()))
+		.transpose()
+		.map_err(|e| format!("Invalid total issuance: {:?}", e))?
+		.unwrap_or_default();
+
+	let mut overrides = Vec::new();
+	for (address, account) in state.accounts {
+		let account_key = [
+			blake2_128(address.as_bytes()).as_slice(),
+			address.as_bytes(),
+		]
+		.concat();
+
+		let mut info = match upstream(&storage_key("System", "Account", &account_key))? {
+			Some(data) => AccountInfo::decode(&mut data.as_slice())
+				.map_err(|e| format!("Invalid account info for {:?}: {:?}", address, e))?,
+			None => AccountInfo {
+				providers: 1,
+				// Contract accounts are kept alive by their code
+				sufficients: if account.code.is_empty() { 0 } else { 1 },
+				..Default::default()
+			},
+		};
+		let free: Balance = account.balance.unique_saturated_into();
+		total_issuance = total_issuance
+			.saturating_add(free)
+			.saturating_sub(info.data.free);
+		info.nonce = account.nonce.unique_saturated_into();
+		info.data.free = free;
+
+		overrides.push(StateEntry::Concrete(StateEntryConcrete {
+			pallet: "System".to_string(),
+			storage: "Account".to_string(),
+			key: Some(account_key.clone()),
+			value: info.encode(),
+		}));
+
+		if !account.code.is_empty() {
+			overrides.push(StateEntry::Concrete(StateEntryConcrete {
+				pallet: "EVM".to_string(),
+				storage: "AccountCodesMetadata".to_string(),
+				key: Some(account_key.clone()),
+				value: (
+					account.code.len() as u64,
+					H256::from(keccak_256(&account.code)),
+				)
+					.encode(),
+			}));
+			overrides.push(StateEntry::Concrete(StateEntryConcrete {
+				pallet: "EVM".to_string(),
+				storage: "AccountCodes".to_string(),
+				key: Some(account_key.clone()),
+				value: account.code.0.encode(),
+			}));
+		}
+
+		for (slot, value) in account.storage {
+			overrides.push(StateEntry::Concrete(StateEntryConcrete {
+				pallet: "EVM".to_string(),
+				storage: "AccountStorages".to_string(),
+				key: Some(
+					[
+						account_key.as_slice(),
+						&blake2_128(slot.as_bytes()),
+						slot.as_bytes(),
+					]
+					.concat(),
+				),
+				value: value.encode(),
+			}));
+		}
+	}
+
+	overrides.push(StateEntry::Concrete(StateEntryConcrete {
+		pallet: "Balances".to_string(),
+		storage: "TotalIssuance".to_string(),
+		key: None,
+		value: total_issuance.encode(),
+	}));
+
+	Ok(overrides)
+}
+
+/// Read a state overrides file. `upstream` reads the storage of the forked chain.
+pub fn read(
+	path: PathBuf,
+	upstream: impl Fn(&[u8]) -> Result<Option<Vec<u8>>, String>,
+) -> Result<Vec<StateEntry>, String> {
 	let reader = std::fs::File::open(path).expect("Can open file");
-	let state = serde_json::from_reader(reader).expect("Can parse state overrides JSON");
+	let state = match serde_json::from_reader(reader).expect("Can parse state overrides JSON") {
+		StateOverrides::Entries(entries) => entries,
+		StateOverrides::Evm(state) => evm_state_overrides(state, upstream)?,
+	};
 
 	Ok(state)
 }
@@ -27,9 +27,12 @@ use sp_state_machine::{
 use std::marker::PhantomData;
 use std::time::Duration;
 use std::{
-	collections::{HashMap, HashSet},
+	collections::{BTreeMap, HashMap, HashSet},
 	ptr,
-	sync::Arc,
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
 };
 
 use sc_client_api::{
@@ -256,6 +259,22 @@ impl<Block: BlockT + DeserializeOwned> Blockchain<Block> {
 		self.apply_head(&header)
 	}
 
+	/// Remove the given blocks, all imported after `head`, and make `head` the best block again.
+	fn remove_blocks(
+		&self,
+		blocks: &HashSet<Block::Hash>,
+		head: Block::Hash,
+		head_number: NumberFor<Block>,
+	) -> sp_blockchain::Result<()> {
+		{
+			let mut storage = self.storage.write();
+			storage.blocks.retain(|hash, _| !blocks.contains(hash));
+			storage.hashes.retain(|number, _| *number <= head_number);
+			storage.leaves.revert(head, head_number);
+		}
+		self.set_head(head)
+	}
+
 	fn apply_head(&self, header: &<Block as BlockT>::Header) -> sp_blockchain::Result<()> {
 		let mut storage = self.storage.write();
 
@@ -541,6 +560,7 @@ pub struct BlockImportOperation<Block: BlockT> {
 	new_state: Option<BackendTransaction<HashingFor<Block>>>,
 	aux: Vec<(Vec<u8>, Option<Vec<u8>>)>,
 	storage_updates: StorageCollection,
+	child_storage_updates: ChildStorageCollection,
 	finalized_blocks: Vec<(Block::Hash, Option<Justification>)>,
 	set_head: Option<Block::Hash>,
 	pub(crate) before_fork: bool,
@@ -657,9 +677,10 @@ impl<Block: BlockT + DeserializeOwned> backend::BlockImportOperation<Block>
 	fn update_storage(
 		&mut self,
 		update: StorageCollection,
-		_child_update: ChildStorageCollection,
+		child_update: ChildStorageCollection,
 	) -> sp_blockchain::Result<()> {
 		self.storage_updates = update.clone();
+		self.child_storage_updates = child_update;
 		Ok(())
 	}
 
@@ -1043,12 +1064,14 @@ impl<Block: BlockT + DeserializeOwned> sp_state_machine::Backend<HashingFor<Bloc
 		unimplemented!("child_closest_merkle_value: unsupported feature for lazy loading")
 	}
 
+	/// Child tries are not fetched from the remote chain, only the entries written after the fork
+	/// are available.
 	fn child_storage(
 		&self,
-		_child_info: &sp_storage::ChildInfo,
-		_key: &[u8],
+		child_info: &sp_storage::ChildInfo,
+		key: &[u8],
 	) -> Result<Option<sp_state_machine::StorageValue>, Self::Error> {
-		unimplemented!("child_storage: unsupported feature for lazy loading");
+		self.db.read().child_storage(child_info, key)
 	}
 
 	fn child_storage_hash(
@@ -1181,6 +1204,24 @@ impl<B: BlockT> sp_state_machine::backend::AsTrieBackend<HashingFor<B>> for Fork
 	}
 }
 
+/// Head of the local chain when a snapshot was taken.
+struct Snapshot<Block: BlockT> {
+	hash: Block::Hash,
+	number: NumberFor<Block>,
+	/// Number of block overlays recorded before the snapshot.
+	overlays: usize,
+}
+
+/// Changes made to the forked state overlay by a block imported while snapshots are held.
+struct BlockOverlay<Block: BlockT> {
+	hash: Block::Hash,
+	/// Value of each key written by the block before it was imported, and whether the key was
+	/// marked as removed.
+	previous: Vec<(Vec<u8>, Option<Vec<u8>>, bool)>,
+	/// Value of each child trie key written by the block before it was imported.
+	previous_children: Vec<(ChildInfo, Vec<u8>, Option<Vec<u8>>)>,
+}
+
 /// Lazy loading (In-memory) backend. Keeps all states and blocks in memory.
 pub struct Backend<Block: BlockT> {
 	pub(crate) rpc_client: Arc<super::rpc_client::RPC>,
@@ -1189,6 +1230,9 @@ pub struct Backend<Block: BlockT> {
 	import_lock: parking_lot::RwLock<()>,
 	pinned_blocks: ReadWriteLock<HashMap<Block::Hash, i64>>,
 	pub(crate) fork_checkpoint: Block::Header,
+	snapshots: ReadWriteLock<BTreeMap<u64, Snapshot<Block>>>,
+	overlays: ReadWriteLock<Vec<BlockOverlay<Block>>>,
+	next_snapshot_id: AtomicU64,
 }
 
 impl<Block: BlockT + DeserializeOwned> Backend<Block> {
@@ -1200,8 +1244,136 @@ impl<Block: BlockT + DeserializeOwned> Backend<Block> {
 			import_lock: Default::default(),
 			pinned_blocks: Default::default(),
 			fork_checkpoint,
+			snapshots: Default::default(),
+			overlays: Default::default(),
+			next_snapshot_id: AtomicU64::new(1),
 		}
 	}
+
+	/// Take a snapshot of the current head. The changes of the blocks imported from then on are
+	/// recorded, so that they can be undone.
+	pub fn snapshot(&self) -> sp_blockchain::Result<u64> {
+		// Hold the import lock so that no block is committed meanwhile.
+		let _import_lock = self.import_lock.write();
+
+		let info = self.blockchain.info();
+		let snapshot = Snapshot {
+			hash: info.best_hash,
+			number: info.best_number,
+			overlays: self.overlays.read().len(),
+		};
+
+		let snapshot_id = self.next_snapshot_id.fetch_add(1, Ordering::SeqCst);
+		self.snapshots.write().insert(snapshot_id, snapshot);
+
+		log::debug!(
+			target: super::LAZY_LOADING_LOG_TARGET,
+			"Created snapshot {} at block {:?}",
+			snapshot_id,
+			info.best_hash
+		);
+
+		Ok(snapshot_id)
+	}
+
+	/// Rewind the head and the forked state overlay to the given snapshot, undoing the blocks
+	/// imported since then.
+	///
+	/// The snapshot and every snapshot taken after it are discarded.
+	/// Returns false if the snapshot is unknown.
+	pub fn revert_to_snapshot(&self, snapshot_id: u64) -> sp_blockchain::Result<bool> {
+		let _import_lock = self.import_lock.write();
+
+		let mut snapshots = self.snapshots.write();
+		let Some(snapshot) = snapshots.get(&snapshot_id) else {
+			return Ok(false);
+		};
+		let (snapshot_hash, snapshot_number) = (snapshot.hash, snapshot.number);
+
+		let mut overlays = self.overlays.write();
+		let finalized_hash = self.blockchain.info().finalized_hash;
+		if overlays[snapshot.overlays..]
+			.iter()
+			.any(|overlay| overlay.hash == finalized_hash)
+		{
+			return Err(sp_blockchain::Error::Backend(format!(
+				"Block {:?} imported after snapshot {} is finalized and can't be reverted",
+				finalized_hash, snapshot_id
+			)));
+		}
+
+		// Every state after the fork shares the same overlay, rewinding it in place
+		// restores the storage seen by all of them.
+		let best_hash = self.blockchain.info().best_hash;
+		let state = <Self as backend::Backend<Block>>::state_at(
+			self,
+			best_hash,
+			TrieCacheContext::Untrusted,
+		)?;
+
+		// Forget the blocks imported after the snapshot, before touching the state so that a
+		// failure leaves the snapshot usable.
+		let reverted_hashes: HashSet<_> = overlays[snapshot.overlays..]
+			.iter()
+			.map(|overlay| overlay.hash)
+			.collect();
+		self.blockchain
+			.remove_blocks(&reverted_hashes, snapshot_hash, snapshot_number)?;
+		self.states
+			.write()
+			.retain(|hash, _| !reverted_hashes.contains(hash));
+
+		let reverted = overlays.split_off(snapshot.overlays);
+		for overlay in reverted.iter().rev() {
+			for (key, value, removed) in overlay.previous.iter().rev() {
+				state.db.write().insert(
+					vec![(None::<ChildInfo>, vec![(key.clone(), value.clone())])],
+					StateVersion::V1,
+				);
+				if *removed {
+					state.removed_keys.write().insert(key.clone(), ());
+				} else {
+					state.removed_keys.write().remove(key);
+				}
+			}
+			for (child_info, key, value) in overlay.previous_children.iter().rev() {
+				state.db.write().insert(
+					vec![(Some(child_info.clone()), vec![(key.clone(), value.clone())])],
+					StateVersion::V1,
+				);
+			}
+		}
+
+		// Reverting consumes the snapshot and every snapshot taken after it.
+		let _ = snapshots.split_off(&snapshot_id);
+		if snapshots.is_empty() {
+			overlays.clear();
+		}
+
+		log::debug!(
+			target: super::LAZY_LOADING_LOG_TARGET,
+			"Reverted to snapshot {} at block {:?}",
+			snapshot_id,
+			snapshot_hash
+		);
+
+		Ok(true)
+	}
+}
+
+impl<Block: BlockT + DeserializeOwned> moonbeam_dev_rpc::ChainSnapshots for Backend<Block> {
+	fn snapshot(&self) -> Result<u64, String> {
+		Backend::snapshot(self).map_err(|e| e.to_string())
+	}
+
+	fn has_snapshots(&self) -> bool {
+		!self.snapshots.read().is_empty()
+	}
+
+	fn revert(&self, snapshot_id: u64) -> Result<bool, String> {
+		self.revert_to_snapshot(snapshot_id)
+			.map_err(|e| e.to_string())
+	}
 }
 
 impl<Block: BlockT + DeserializeOwned> backend::AuxStore for Backend<Block> {
@@ -1238,6 +1410,7 @@ impl<Block: BlockT + DeserializeOwned> backend::Backend<Block> for Backend<Block
 			new_state: None,
 			aux: Default::default(),
 			storage_updates: Default::default(),
+			child_storage_updates: Default::default(),
 			finalized_blocks: Default::default(),
 			set_head: None,
 			before_fork: false,
@@ -1263,6 +1436,47 @@ impl<Block: BlockT + DeserializeOwned> backend::Backend<Block> for Backend<Block
 			let (header, body, justification) = pending_block.block.into_inner();
 			let hash = header.hash();
 
+			// Keep what the block overwrites while snapshots may revert it.
+			if !self.snapshots.read().is_empty() {
+				use sp_state_machine::Backend;
+				let previous = operation
+					.storage_updates
+					.iter()
+					.map(|(key, _)| {
+						let value = old_state
+							.db
+							.read()
+							.storage(key)
+							.map_err(|e| sp_blockchain::Error::Backend(e.to_string()))?;
+						let removed = old_state.removed_keys.read().contains_key(key);
+						Ok((key.clone(), value, removed))
+					})
+					.collect::<sp_blockchain::Result<_>>()?;
+				let previous_children = operation
+					.child_storage_updates
+					.iter()
+					.flat_map(|(storage_key, updates)| {
+						let child_info = ChildInfo::new_default(storage_key);
+						updates
+							.iter()
+							.map(move |(key, _)| (child_info.clone(), key))
+					})
+					.map(|(child_info, key)| {
+						let value = old_state
+							.db
+							.read()
+							.child_storage(&child_info, key)
+							.map_err(|e| sp_blockchain::Error::Backend(e.to_string()))?;
+						Ok((child_info, key.clone(), value))
+					})
+					.collect::<sp_blockchain::Result<_>>()?;
+				self.overlays.write().push(BlockOverlay {
+					hash,
+					previous,
+					previous_children,
+				});
+			}
+
 			let new_removed_keys = old_state.removed_keys.clone();
 			for (key, value) in operation.storage_updates.clone() {
 				if value.is_some() {
@@ -1274,7 +1488,13 @@ impl<Block: BlockT + DeserializeOwned> backend::Backend<Block> for Backend<Block
 
 			let new_db = old_state.db.clone();
 			new_db.write().insert(
-				vec![(None::<ChildInfo>, operation.storage_updates)],
+				[(None::<ChildInfo>, operation.storage_updates)]
+					.into_iter()
+					.chain(operation.child_storage_updates.into_iter().map(
+						|(storage_key, updates)| {
+							(Some(ChildInfo::new_default(&storage_key)), updates)
+						},
+					)),
 				StateVersion::V1,
 			);
 			let new_state = ForkedLazyBackend {
@@ -1455,8 +1675,9 @@ pub fn check_genesis_storage(storage: &Storage) -> sp_blockchain::Result<()> {
 	Ok(())
 }
 
-/// Create an instance of a lazy loading memory backend.
-pub fn new_backend<Block>(
+/// Create an instance of a lazy loading memory backend forked at the checkpoint block, without
+/// producing any block on top of it.
+pub fn new_fork_backend<Block>(
 	config: &mut Configuration,
 	lazy_loading_config: &LazyLoadingConfig,
 ) -> Result<Arc<Backend<Block>>, Error>
@@ -1464,22 +1685,19 @@ where
 	Block: BlockT + DeserializeOwned,
 	Block::Hash: From<H256>,
 {
-	let http_client = jsonrpsee::http_client::HttpClientBuilder::default()
-		.max_request_size(u32::MAX)
-		.max_response_size(u32::MAX)
-		.request_timeout(Duration::from_secs(10))
-		.build(lazy_loading_config.state_rpc.clone())
-		.map_err(|e| {
-			sp_blockchain::Error::Backend(
-				format!("failed to build http client: {:?}", e).to_string(),
-			)
-		})?;
-
-	let rpc = super::rpc_client::RPC::new(
-		http_client,
+	let rpc = super::rpc_client::RPC::connect(
+		[
+			vec![lazy_loading_config.state_rpc.clone()],
+			lazy_loading_config.fallback_rpcs.clone(),
+		]
+		.concat(),
+		&lazy_loading_config.rpc_headers,
 		lazy_loading_config.delay_between_requests,
 		lazy_loading_config.max_retries_per_request,
-	);
+	)
+	.map_err(sp_blockchain::Error::Backend)?;
+	rpc.spawn_health_checks();
+
 	let block_hash = lazy_loading_config
 		.from_block
 		.map(|block| Into::<Block::Hash>::into(block));
@@ -1506,10 +1724,32 @@ where
 		.with_properties(chain_properties);
 	config.chain_spec = Box::new(spec_builder.build());
 
+	Ok(backend)
+}
+
+/// Create an instance of a lazy loading memory backend.
+pub fn new_backend<Block>(
+	config: &mut Configuration,
+	lazy_loading_config: &LazyLoadingConfig,
+) -> Result<Arc<Backend<Block>>, Error>
+where
+	Block: BlockT + DeserializeOwned,
+	Block::Hash: From<H256>,
+{
+	let backend = new_fork_backend::<Block>(config, lazy_loading_config)?;
+	let checkpoint = backend.fork_checkpoint.clone();
+
 	let base_overrides =
 		state_overrides::base_state_overrides(lazy_loading_config.runtime_override.clone());
 	let custom_overrides = if let Some(path) = lazy_loading_config.state_overrides_path.clone() {
-		state_overrides::read(path)?
+		let fork_block = checkpoint.hash();
+		state_overrides::read(path, |key| {
+			backend
+				.rpc_client
+				.storage(StorageKey(key.to_vec()), Some(fork_block))
+				.map(|data| data.map(|data| data.0))
+				.map_err(|e| format!("Failed to read upstream storage: {:?}", e))
+		})?
 	} else {
 		Default::default()
 	};
@@ -90,6 +90,12 @@ use substrate_prometheus_endpoint::Registry;
 pub use client::*;
 pub mod chain_spec;
 mod client;
+mod dev_hrmp;
+mod dev_outbound_xcm;
+mod dev_relay_state;
+mod dev_snapshots;
+mod dev_xcm_reports;
+pub mod evm_state;
 #[cfg(feature = "lazy-loading")]
 pub mod lazy_loading;
 
@@ -137,6 +143,31 @@ impl MockTimestampInherentDataProvider {
 			TIMESTAMP.fetch_add(slot_duration, Ordering::SeqCst);
 		}
 	}
+
+	/// Jump to the given timestamp, unless it is less than one slot after the current one.
+	/// The dev RPC rejects such timestamps, so this only happens when blocks were sealed
+	/// between the request and this block; the timestamp is then advanced as usual.
+	fn set_timestamp(timestamp: u64, slot_duration: u64) {
+		let current = TIMESTAMP.load(Ordering::SeqCst);
+		if current == 0 || timestamp >= current.saturating_add(slot_duration) {
+			TIMESTAMP.store(timestamp, Ordering::SeqCst);
+		} else {
+			log::warn!(
+				"Ignoring requested timestamp {}, it must be at least {}",
+				timestamp,
+				current.saturating_add(slot_duration)
+			);
+			Self::advance_timestamp(slot_duration);
+		}
+	}
+
+	/// Advance the timestamp for the next block, applying the pending override if any.
+	fn next_timestamp(next_timestamp_override: &AtomicU64, slot_duration: u64) {
+		match next_timestamp_override.swap(0, Ordering::SeqCst) {
+			0 => Self::advance_timestamp(slot_duration),
+			timestamp => Self::set_timestamp(timestamp, slot_duration),
+		}
+	}
 }
 
 #[async_trait::async_trait]
@@ -911,7 +942,7 @@ where
 				fee_history_cache: fee_history_cache.clone(),
 				network: network.clone(),
 				sync: sync.clone(),
-				dev_rpc_data: None,
+				dev_rpc: None,
 				block_data_cache: block_data_cache.clone(),
 				overrides: overrides.clone(),
 				forced_parent_hashes,
@@ -1306,6 +1337,9 @@ where
 	use futures::Stream;
 	use sc_consensus_manual_seal::{run_manual_seal, EngineCommand, ManualSealParams};
 
+	let dev_hrmp_peers = node_extra_args.dev_hrmp_peers.clone();
+	let dev_relay_state = node_extra_args.dev_relay_state.clone();
+
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -1380,12 +1414,20 @@ where
 	let overrides = Arc::new(StorageOverrideHandler::new(client.clone()));
 	let fee_history_limit = rpc_config.fee_history_limit;
 	let mut command_sink = None;
-	let mut dev_rpc_data = None;
+	let mut dev_rpc = None;
 	let collator = config.role.is_authority();
 
 	let parachain_id: ParaId = para_id
 		.expect("para ID should be specified for dev service")
 		.into();
+	let hrmp_peers: Vec<(ParaId, url::Url)> = dev_hrmp_peers
+		.into_iter()
+		.map(|(peer_id, url)| (ParaId::from(peer_id), url))
+		.collect();
+	let relay_storage = Arc::new(Mutex::new(match &dev_relay_state {
+		Some(path) => dev_relay_state::load_relay_storage(path).map_err(ServiceError::Other)?,
+		None => Default::default(),
+	}));
 
 	if collator {
 		let mut env = sc_basic_authorship::ProposerFactory::with_proof_recording(
@@ -1429,6 +1471,19 @@ where
 				)),
 			};
 
+		// Sealing commands issued by the dev RPC, whatever the sealing mode.
+		let (dev_command_sink, dev_commands_stream) = futures::channel::mpsc::channel(1000);
+		let snapshots: Arc<dyn moonbeam_dev_rpc::ChainSnapshots> =
+			Arc::new(crate::dev_snapshots::FrontierSnapshots::new(
+				Arc::new(crate::dev_snapshots::DevSnapshots::new(backend.clone())),
+				&frontier_backend,
+			));
+		let commands_stream: Box<dyn Stream<Item = EngineCommand<H256>> + Send + Sync + Unpin> =
+			Box::new(crate::dev_snapshots::defer_finality(
+				futures::stream::select(commands_stream, dev_commands_stream),
+				snapshots.clone(),
+			));
+
 		let select_chain = maybe_select_chain.expect(
 			"`new_partial` builds a `LongestChainRule` when building dev service.\
 				We specified the dev service when calling `new_partial`.\
@@ -1439,11 +1494,27 @@ where
 		let (downward_xcm_sender, downward_xcm_receiver) = flume::bounded::<Vec<u8>>(100);
 		let (hrmp_xcm_sender, hrmp_xcm_receiver) = flume::bounded::<(ParaId, Vec<u8>)>(100);
 		let additional_relay_offset = Arc::new(std::sync::atomic::AtomicU32::new(0));
-		dev_rpc_data = Some((
-			downward_xcm_sender,
-			hrmp_xcm_sender,
-			additional_relay_offset.clone(),
-		));
+		let additional_relay_slot_offset = Arc::new(AtomicU64::new(0));
+		let next_timestamp = Arc::new(AtomicU64::new(0));
+		dev_rpc = Some(moonbeam_dev_rpc::DevRpc {
+			downward_message_channel: downward_xcm_sender,
+			hrmp_message_channel: hrmp_xcm_sender,
+			additional_relay_offset: additional_relay_offset.clone(),
+			additional_relay_slot_offset: additional_relay_slot_offset.clone(),
+			relay_storage: relay_storage.clone(),
+			next_timestamp: next_timestamp.clone(),
+			current_timestamp: &TIMESTAMP,
+			slot_duration: RELAY_CHAIN_SLOT_DURATION_MILLIS,
+			command_sink: dev_command_sink,
+			snapshots: Some(snapshots),
+			xcm_reports: Some(Arc::new(crate::dev_xcm_reports::DevXcmReports::new(
+				client.clone(),
+			))),
+			outbound_messages: Some(Arc::new(crate::dev_outbound_xcm::DevOutboundXcm::<
+				_,
+				FullBackend,
+			>::new(client.clone()))),
+		});
 
 		// Need to clone it and store here to avoid moving of `client`
 		// variable in closure below.
@@ -1463,6 +1534,13 @@ where
 		// Need to clone it and store here to avoid moving of `client`
 		// variable in closure below.
 		let client_for_cidp = client.clone();
+		let hrmp_egress_key_values = dev_hrmp::egress_channels_key_values(
+			parachain_id,
+			&hrmp_peers
+				.iter()
+				.map(|(peer_id, _)| *peer_id)
+				.collect::<Vec<_>>(),
+		);
 
 		task_manager.spawn_essential_handle().spawn_blocking(
 			"authorship_task",
@@ -1486,14 +1564,19 @@ where
 					let downward_xcm_receiver = downward_xcm_receiver.clone();
 					let hrmp_xcm_receiver = hrmp_xcm_receiver.clone();
 					let additional_relay_offset = additional_relay_offset.clone();
+					let additional_relay_slot_offset = additional_relay_slot_offset.clone();
+					let next_timestamp = next_timestamp.clone();
 					let relay_slot_key = well_known_keys::CURRENT_SLOT.to_vec();
+					let hrmp_egress_key_values = hrmp_egress_key_values.clone();
+					let relay_storage = relay_storage.clone();
 
 					// Need to clone it and store here to avoid moving of `client`
 					// variable in closure below.
 					let client_for_xcm = client_for_cidp.clone();
 
 					async move {
-						MockTimestampInherentDataProvider::advance_timestamp(
+						MockTimestampInherentDataProvider::next_timestamp(
+							&next_timestamp,
 							RELAY_CHAIN_SLOT_DURATION_MILLIS,
 						);
 
@@ -1507,9 +1590,11 @@ where
 						// Get the mocked timestamp
 						let timestamp = TIMESTAMP.load(Ordering::SeqCst);
 						// Calculate mocked slot number
-						let slot = timestamp.saturating_div(RELAY_CHAIN_SLOT_DURATION_MILLIS);
+						let slot = timestamp
+							.saturating_div(RELAY_CHAIN_SLOT_DURATION_MILLIS)
+							.saturating_add(additional_relay_slot_offset.load(Ordering::SeqCst));
 
-						let additional_key_values = vec![
+						let mut additional_key_values = vec![
 							(relay_slot_key, Slot::from(slot).encode()),
 							(
 								relay_chain::well_known_keys::ACTIVE_CONFIG.to_vec(),
@@ -1531,6 +1616,16 @@ where
 								.encode(),
 							),
 						];
+						// Open egress channels to the HRMP peers of this node
+						additional_key_values.extend(hrmp_egress_key_values);
+						// Configured relay storage entries take precedence over the mocked ones
+						additional_key_values.extend(
+							relay_storage
+								.lock()
+								.expect("Relay storage lock poisoned")
+								.iter()
+								.map(|(key, value)| (key.clone(), value.clone())),
+						);
 
 						let current_para_head = client_for_xcm
 							.header(block)
@@ -1589,6 +1684,18 @@ where
 				},
 			}),
 		);
+
+		if !hrmp_peers.is_empty() {
+			task_manager.spawn_handle().spawn(
+				"dev-hrmp-forwarder",
+				None,
+				dev_hrmp::forward_hrmp_messages::<_, FullBackend>(
+					client.clone(),
+					parachain_id,
+					hrmp_peers,
+				),
+			);
+		}
 	}
 
 	// Sinks for pubsub notifications.
@@ -1685,7 +1792,7 @@ where
 				fee_history_cache: fee_history_cache.clone(),
 				network: network.clone(),
 				sync: sync.clone(),
-				dev_rpc_data: dev_rpc_data.clone(),
+				dev_rpc: dev_rpc.clone(),
 				overrides: overrides.clone(),
 				block_data_cache: block_data_cache.clone(),
 				forced_parent_hashes: None,
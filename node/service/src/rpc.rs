@@ -36,7 +36,7 @@ use fc_rpc_core::types::{FeeHistoryCache, FilterPool, TransactionRequest};
 use futures::StreamExt;
 use jsonrpsee::RpcModule;
 use moonbeam_cli_opt::EthApi as EthApiCmd;
-use moonbeam_core_primitives::{Block, Hash};
+use moonbeam_core_primitives::{AccountId, Balance, Block, Hash};
 use parity_scale_codec::Encode;
 use sc_client_api::{
 	backend::{AuxStore, Backend, StateBackend, StorageProvider},
@@ -133,12 +133,8 @@ pub struct FullDeps<C, P, BE> {
 	pub fee_history_limit: u64,
 	/// Fee history cache.
 	pub fee_history_cache: FeeHistoryCache,
-	/// Channels for manual xcm messages (downward, hrmp)
-	pub dev_rpc_data: Option<(
-		flume::Sender<Vec<u8>>,
-		flume::Sender<(ParaId, Vec<u8>)>,
-		Arc<std::sync::atomic::AtomicU32>,
-	)>,
+	/// Dev RPC, sharing channels and mocked relay data with the authorship task (dev only)
+	pub dev_rpc: Option<moonbeam_dev_rpc::DevRpc>,
 	/// Ethereum data access overrides.
 	pub overrides: Arc<dyn StorageOverride<Block>>,
 	/// Cache for Ethereum block data.
@@ -182,10 +178,14 @@ where
 		Eth, EthApiServer, EthFilter, EthFilterApiServer, EthPubSub, EthPubSubApiServer, Net,
 		NetApiServer, TxPool, TxPoolApiServer, Web3, Web3ApiServer,
 	};
-	use moonbeam_dev_rpc::{DevApiServer, DevRpc};
-	use moonbeam_finality_rpc::{MoonbeamFinality, MoonbeamFinalityApiServer};
+	use moonbeam_dev_rpc::DevApiServer;
+	use moonbeam_finality_rpc::{
+		MoonbeamFinality, MoonbeamFinalityApiServer, MoonbeamTransactionEffects,
+		MoonbeamTransactionEffectsApiServer,
+	};
 	use moonbeam_rpc_debug::{Debug, DebugServer};
 	use moonbeam_rpc_trace::{Trace, TraceServer};
+	use moonbeam_staking_rpc::{MoonbeamStaking, MoonbeamStakingApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -206,7 +206,7 @@ where
 		max_block_range,
 		fee_history_limit,
 		fee_history_cache,
-		dev_rpc_data,
+		dev_rpc,
 		overrides,
 		block_data_cache,
 		forced_parent_hashes,
@@ -350,7 +350,7 @@ where
 			Arc::clone(&client),
 			sync.clone(),
 			subscription_task_executor,
-			overrides,
+			overrides.clone(),
 			pubsub_notification_sinks.clone(),
 			logs_journal,
 		)
@@ -361,7 +361,14 @@ where
 		io.merge(TxPool::new(Arc::clone(&client), graph).into_rpc())?;
 	}
 
-	io.merge(MoonbeamFinality::new(client.clone(), frontier_backend.clone()).into_rpc())?;
+	io.merge(
+		MoonbeamFinality::new(client.clone(), frontier_backend.clone(), overrides).into_rpc(),
+	)?;
+	io.merge(
+		MoonbeamTransactionEffects::<_, _, BE>::new(client.clone(), frontier_backend.clone())
+			.into_rpc(),
+	)?;
+	io.merge(MoonbeamStaking::<_, _, AccountId, Balance>::new(client.clone()).into_rpc())?;
 
 	if let Some(command_sink) = command_sink {
 		io.merge(
@@ -371,17 +378,8 @@ where
 		)?;
 	};
 
-	if let Some((downward_message_channel, hrmp_message_channel, additional_relay_offset)) =
-		dev_rpc_data
-	{
-		io.merge(
-			DevRpc {
-				downward_message_channel,
-				hrmp_message_channel,
-				additional_relay_offset,
-			}
-			.into_rpc(),
-		)?;
+	if let Some(dev_rpc) = dev_rpc {
+		io.merge(dev_rpc.into_rpc())?;
 	}
 
 	if let Some(tracing_config) = maybe_tracing_config {
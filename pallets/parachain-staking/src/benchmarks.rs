@@ -18,14 +18,18 @@
 
 //! Benchmarking
 use crate::{
-	AwardedPts, BalanceOf, BottomDelegations, Call, CandidateBondLessRequest, Config,
+	AwardedPts, BalanceOf, BottomDelegations, Call, CandidateBondLessRequest,
+	CandidateCommissionBounds, CandidateProfileInfo, CandidateProfiles, CommissionBounds, Config,
 	DelegationAction, DelegationScheduledRequestsSummaryMap, EnableMarkingOffline,
-	InflationDistributionAccount, InflationDistributionConfig, InflationDistributionInfo, Pallet,
-	Points, Range, RewardPayment, Round, ScheduledRequest, TopDelegations,
+	InflationDistributionAccount, InflationDistributionConfig, InflationDistributionInfo,
+	NextPoolId, Pallet, PendingSlash, PendingSlashes, Points, PoolId, PoolShareApprovals,
+	PoolShares, Pools, Range, RewardPayment, Round, ScheduledRequest, SlashFractions,
+	SlashFractionsConfig, SlashReason, TopDelegations,
 };
 use frame_benchmarking::v2::*;
 use frame_support::traits::tokens::fungible::{Inspect, Mutate};
 use frame_support::traits::{Get, OnFinalize, OnInitialize};
+use frame_support::BoundedVec;
 use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
 use sp_runtime::{Perbill, Percent};
 use sp_std::vec::Vec;
@@ -202,6 +206,53 @@ fn create_funded_collator<T: Config>(
 	Ok(user)
 }
 
+/// Create `count` candidates a delegation pool can delegate to.
+fn create_pool_candidates<T: Config>(
+	string: &'static str,
+	count: u32,
+) -> Result<Vec<T::AccountId>, &'static str> {
+	let mut candidates = Vec::new();
+	for i in 0..count {
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		candidates.push(create_funded_collator::<T>(
+			string,
+			USER_SEED + i,
+			0u32.into(),
+			true,
+			candidate_count,
+		)?);
+	}
+	Ok(candidates)
+}
+
+/// Create a delegation pool delegating to a single new candidate.
+fn create_benchmark_pool<T: Config>() -> Result<PoolId, &'static str> {
+	let candidates = create_pool_candidates::<T>("pool_candidate", 1)?;
+	let pool = <NextPoolId<T>>::get();
+	Pallet::<T>::create_pool(RawOrigin::Root.into(), candidates)?;
+	Ok(pool)
+}
+
+/// Profile of a candidate whose text fields are `len` bytes long in total.
+fn candidate_profile_info<T: Config>(
+	len: u32,
+) -> CandidateProfileInfo<T::MaxCandidateProfileFieldLength> {
+	let max = T::MaxCandidateProfileFieldLength::get();
+	let field = |i: u32| {
+		BoundedVec::truncate_from(sp_std::vec![
+			b'a';
+			len.saturating_sub(i * max).min(max) as usize
+		])
+	};
+	CandidateProfileInfo {
+		display_name: field(0),
+		website: field(1),
+		contact: field(2),
+		region: field(3),
+		commission_intent: Some(Perbill::from_percent(10)),
+	}
+}
+
 // Simulate staking on finalize by manually setting points
 fn parachain_staking_on_finalize<T: Config>(author: T::AccountId) {
 	let now = <Round<T>>::get().current;
@@ -1776,6 +1827,7 @@ mod benchmarks {
 				bond: 1_000u32.into(),
 				delegations: delegations.clone(),
 				total: 1_000_000u32.into(),
+				commission: Perbill::from_rational(1u32, 100u32),
 			},
 		);
 
@@ -1884,6 +1936,7 @@ mod benchmarks {
 				bond: 1_000u32.into(),
 				delegations,
 				total: 1_000_000u32.into(),
+				commission: Perbill::from_rational(1u32, 100u32),
 			},
 		);
 
@@ -2402,6 +2455,404 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn create_pool(x: Linear<1, { T::MaxPoolCandidates::get() }>) -> Result<(), BenchmarkError> {
+		let candidates = create_pool_candidates::<T>("candidate", x)?;
+		let pool = <NextPoolId<T>>::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, candidates.clone());
+
+		assert_eq!(
+			<Pools<T>>::get(pool).map(|info| info.candidates),
+			Some(candidates)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_pool_candidates(
+		x: Linear<1, { T::MaxPoolCandidates::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let pool = create_benchmark_pool::<T>()?;
+		let candidates = create_pool_candidates::<T>("candidate", x)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, pool, candidates.clone());
+
+		assert_eq!(
+			<Pools<T>>::get(pool).map(|info| info.candidates),
+			Some(candidates)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_pool_shares() -> Result<(), BenchmarkError> {
+		let pool = create_benchmark_pool::<T>()?;
+		let owner: T::AccountId = account("owner", 0, USER_SEED);
+		let dest: T::AccountId = account("dest", 0, USER_SEED);
+		let shares = min_delegator_stk::<T>();
+		// Worst case is a partial transfer to an account already owning shares
+		<PoolShares<T>>::insert(pool, &owner, shares * 2u32.into());
+		<PoolShares<T>>::insert(pool, &dest, shares);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(owner.clone()), pool, dest.clone(), shares);
+
+		assert_eq!(<PoolShares<T>>::get(pool, &owner), shares);
+		assert_eq!(<PoolShares<T>>::get(pool, &dest), shares * 2u32.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn approve_pool_shares() -> Result<(), BenchmarkError> {
+		let pool = create_benchmark_pool::<T>()?;
+		let owner: T::AccountId = account("owner", 0, USER_SEED);
+		let spender: T::AccountId = account("spender", 0, USER_SEED);
+		let shares = min_delegator_stk::<T>();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(owner.clone()),
+			pool,
+			spender.clone(),
+			shares,
+		);
+
+		assert_eq!(
+			<PoolShareApprovals<T>>::get((pool, &owner, &spender)),
+			shares
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_pool_shares_from() -> Result<(), BenchmarkError> {
+		let pool = create_benchmark_pool::<T>()?;
+		let owner: T::AccountId = account("owner", 0, USER_SEED);
+		let spender: T::AccountId = account("spender", 0, USER_SEED);
+		let dest: T::AccountId = account("dest", 0, USER_SEED);
+		let shares = min_delegator_stk::<T>();
+		// Worst case is a partial transfer, within a larger allowance, to an account already
+		// owning shares
+		<PoolShares<T>>::insert(pool, &owner, shares * 2u32.into());
+		<PoolShares<T>>::insert(pool, &dest, shares);
+		<PoolShareApprovals<T>>::insert((pool, &owner, &spender), shares * 2u32.into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(spender.clone()),
+			pool,
+			owner.clone(),
+			dest.clone(),
+			shares,
+		);
+
+		assert_eq!(<PoolShares<T>>::get(pool, &owner), shares);
+		assert_eq!(<PoolShares<T>>::get(pool, &dest), shares * 2u32.into());
+		assert_eq!(
+			<PoolShareApprovals<T>>::get((pool, &owner, &spender)),
+			shares
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_slash_fractions() -> Result<(), BenchmarkError> {
+		let new = SlashFractionsConfig {
+			offline: Perbill::from_percent(1),
+			misbehaviour: Perbill::from_percent(10),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, new);
+
+		assert_eq!(<SlashFractions<T>>::get(), new);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn report_misbehaviour() -> Result<(), BenchmarkError> {
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator =
+			create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, candidate_count)?;
+		<SlashFractions<T>>::put(SlashFractionsConfig {
+			offline: Perbill::from_percent(1),
+			misbehaviour: Perbill::from_percent(10),
+		});
+		// Worst case replaces a lower slash already pending
+		Pallet::<T>::report_slash(collator.clone(), SlashReason::Offline)?;
+		let apply_at = Pallet::<T>::round().current + T::SlashDeferDuration::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, collator.clone());
+
+		assert_eq!(
+			<PendingSlashes<T>>::get(apply_at, &collator).map(|slash| slash.reason),
+			Some(SlashReason::Misbehaviour)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_slash() -> Result<(), BenchmarkError> {
+		let collator: T::AccountId = account("collator", 0, USER_SEED);
+		let current = Pallet::<T>::round().current;
+		// Worst case is a slash due in a past round, carried over to the current round
+		<PendingSlashes<T>>::insert(
+			current,
+			&collator,
+			PendingSlash {
+				reason: SlashReason::Misbehaviour,
+				fraction: Perbill::from_percent(10),
+				reported_round: current,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, current.saturating_sub(1), collator.clone());
+
+		assert!(<PendingSlashes<T>>::get(current, &collator).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn apply_slash(
+		x: Linear<0, { T::MaxTopDelegationsPerCandidate::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// x is the number of top delegations slashed
+
+		let mut seed = Seed::new();
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator = create_account::<T>(
+			"collator",
+			seed.take(),
+			AccountBalance::Value(min_candidate_stk::<T>() * 2u32.into()),
+			AccountAction::JoinCandidates {
+				amount: Amount::All,
+				candidate_count,
+			},
+		)?;
+
+		// Worst case is a delegation with a pending decrease request, which is rescaled
+		for i in 0..x {
+			let delegator = create_account::<T>(
+				"delegator",
+				seed.take(),
+				AccountBalance::Value(min_delegator_stk::<T>() * 3u32.into()),
+				AccountAction::Delegate {
+					collator: collator.clone(),
+					amount: Amount::Value(min_delegator_stk::<T>() * 2u32.into()),
+					auto_compound: Percent::zero(),
+					collator_delegation_count: i,
+					collator_auto_compound_delegation_count: 0,
+				},
+			)?;
+			Pallet::<T>::schedule_delegator_bond_less(
+				RawOrigin::Signed(delegator).into(),
+				collator.clone(),
+				min_delegator_stk::<T>(),
+			)?;
+		}
+		let bond = Pallet::<T>::candidate_info(&collator)
+			.expect("candidate was created, qed")
+			.bond;
+
+		#[block]
+		{
+			Pallet::<T>::apply_slash(
+				collator.clone(),
+				PendingSlash {
+					reason: SlashReason::Misbehaviour,
+					fraction: Perbill::from_percent(10),
+					reported_round: 1,
+				},
+			);
+		}
+
+		assert!(
+			Pallet::<T>::candidate_info(&collator)
+				.expect("candidate was created, qed")
+				.bond < bond
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_candidate_commission_bounds() -> Result<(), BenchmarkError> {
+		let new = CommissionBounds {
+			min: Perbill::from_percent(5),
+			max: Perbill::from_percent(50),
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, new);
+
+		assert_eq!(<CandidateCommissionBounds<T>>::get(), new);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule_candidate_commission_change() -> Result<(), BenchmarkError> {
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator =
+			create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, candidate_count)?;
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(collator.clone()),
+			Perbill::from_percent(10),
+		);
+
+		assert!(Pallet::<T>::candidate_info(&collator)
+			.expect("must exist")
+			.commission_request
+			.is_some());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_candidate_commission_change() -> Result<(), BenchmarkError> {
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator =
+			create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, candidate_count)?;
+		let caller: T::AccountId = account("caller", 0, USER_SEED);
+		Pallet::<T>::schedule_candidate_commission_change(
+			RawOrigin::Signed(collator.clone()).into(),
+			Perbill::from_percent(10),
+		)?;
+		roll_to_and_author::<T>(T::CandidateCommissionChangeDelay::get(), collator.clone());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), collator.clone());
+
+		assert_eq!(
+			Pallet::<T>::candidate_info(&collator)
+				.expect("must exist")
+				.commission,
+			Some(Perbill::from_percent(10))
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_candidate_commission_change() -> Result<(), BenchmarkError> {
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator =
+			create_funded_collator::<T>("collator", USER_SEED, 0u32.into(), true, candidate_count)?;
+		Pallet::<T>::schedule_candidate_commission_change(
+			RawOrigin::Signed(collator.clone()).into(),
+			Perbill::from_percent(10),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(collator.clone()));
+
+		assert!(Pallet::<T>::candidate_info(&collator)
+			.expect("must exist")
+			.commission_request
+			.is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_candidate_profile(
+		x: Linear<0, { CandidateProfileInfo::<T::MaxCandidateProfileFieldLength>::max_byte_len() }>,
+	) -> Result<(), BenchmarkError> {
+		// x is the length in bytes of the text fields of the profile
+
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		// Worst case is a new profile, holding the deposit
+		let collator = create_funded_collator::<T>(
+			"collator",
+			USER_SEED,
+			T::CandidateProfileDeposit::get() * 2u32.into(),
+			true,
+			candidate_count,
+		)?;
+		let info = candidate_profile_info::<T>(x);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(collator.clone()), info.clone());
+
+		assert_eq!(
+			<CandidateProfiles<T>>::get(&collator).map(|profile| profile.info),
+			Some(info)
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn clear_candidate_profile(
+		x: Linear<0, { CandidateProfileInfo::<T::MaxCandidateProfileFieldLength>::max_byte_len() }>,
+	) -> Result<(), BenchmarkError> {
+		// x is the length in bytes of the text fields of the profile
+
+		let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+		let collator = create_funded_collator::<T>(
+			"collator",
+			USER_SEED,
+			T::CandidateProfileDeposit::get() * 2u32.into(),
+			true,
+			candidate_count,
+		)?;
+		Pallet::<T>::set_candidate_profile(
+			RawOrigin::Signed(collator.clone()).into(),
+			candidate_profile_info::<T>(x),
+		)?;
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(collator.clone()));
+
+		assert!(<CandidateProfiles<T>>::get(&collator).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn execute_all_matured_requests(
+		x: Linear<1, { T::MaxDelegationsPerDelegator::get() }>,
+	) -> Result<(), BenchmarkError> {
+		// x is the number of matured requests executed, each revoking a delegation
+
+		let mut collators = Vec::new();
+		for i in 0..x {
+			let candidate_count = Pallet::<T>::candidate_pool().0.len() as u32;
+			collators.push(create_funded_collator::<T>(
+				"collator",
+				USER_SEED + i,
+				0u32.into(),
+				true,
+				candidate_count,
+			)?);
+		}
+		let (delegator, _) =
+			create_funded_user::<T>("delegator", USER_SEED, min_delegator_stk::<T>() * x.into());
+		for (i, collator) in collators.iter().enumerate() {
+			Pallet::<T>::delegate_with_auto_compound(
+				RawOrigin::Signed(delegator.clone()).into(),
+				collator.clone(),
+				min_delegator_stk::<T>(),
+				Percent::zero(),
+				0u32,
+				0u32,
+				i as u32,
+			)?;
+			Pallet::<T>::schedule_revoke_delegation(
+				RawOrigin::Signed(delegator.clone()).into(),
+				collator.clone(),
+			)?;
+		}
+		roll_to_and_author::<T>(T::RevokeDelegationDelay::get(), collators[0].clone());
+		let caller: T::AccountId = account("caller", 0, USER_SEED);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), delegator.clone(), x);
+
+		assert!(!Pallet::<T>::is_delegator(&delegator));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Pallet,
 		crate::benchmarks::tests::new_test_ext(),
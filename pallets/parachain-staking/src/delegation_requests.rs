@@ -19,20 +19,23 @@
 use crate::pallet::{
 	BalanceOf, CandidateInfo, Config, DelegationScheduledRequests,
 	DelegationScheduledRequestsPerCollator, DelegationScheduledRequestsSummaryMap, DelegatorState,
-	Error, Event, Pallet, Round, RoundIndex, Total,
+	Error, Event, MaturedRequestsCursor, Pallet, Round, RoundIndex, Total,
 };
 use crate::weights::WeightInfo;
 use crate::{auto_compound::AutoCompoundDelegations, Delegator};
 use frame_support::dispatch::{DispatchErrorWithPostInfo, DispatchResultWithPostInfo};
 use frame_support::ensure;
+use frame_support::pallet_prelude::Weight;
+use frame_support::storage::with_storage_layer;
 use frame_support::traits::Get;
 use frame_support::BoundedVec;
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::{
-	traits::{Saturating, Zero},
-	RuntimeDebug,
+	traits::{One, Saturating, Zero},
+	Perbill, RuntimeDebug,
 };
+use sp_std::vec::Vec;
 
 /// An action that can be performed upon a delegation
 #[derive(
@@ -606,6 +609,168 @@ impl<T: Config> Pallet<T> {
 		});
 	}
 
+	/// Adjusts the delegator's [ScheduledRequest]s towards a given collator after its delegation
+	/// was slashed from `old_bond` to `new_bond`. A revocation unstakes the new bond, and the
+	/// decreases are rescaled by the slash, so they never add up to the new bond.
+	/// The state needs to be persisted by the caller of this function.
+	pub(crate) fn delegation_slash_requests_with_state(
+		collator: &T::AccountId,
+		delegator: &T::AccountId,
+		state: &mut Delegator<T::AccountId, BalanceOf<T>>,
+		old_bond: BalanceOf<T>,
+		new_bond: BalanceOf<T>,
+	) {
+		let mut scheduled_requests = <DelegationScheduledRequests<T>>::get(collator, delegator);
+		if scheduled_requests.is_empty() {
+			return;
+		}
+
+		let ratio = Perbill::from_rational(new_bond, old_bond);
+		let (mut old_total, mut new_total) = (BalanceOf::<T>::zero(), BalanceOf::<T>::zero());
+		let mut summary = None;
+		for request in scheduled_requests.iter_mut() {
+			let amount = request.action.amount();
+			old_total = old_total.saturating_add(amount);
+			request.action = match request.action {
+				DelegationAction::Revoke(_) => {
+					summary = Some(DelegationAction::Revoke(new_bond));
+					DelegationAction::Revoke(new_bond)
+				}
+				DelegationAction::Decrease(_) => {
+					// a decrease must leave a non zero bond to be executed
+					let available = new_bond
+						.saturating_sub(new_total)
+						.saturating_sub(One::one());
+					DelegationAction::Decrease((ratio * amount).min(available))
+				}
+			};
+			new_total = new_total.saturating_add(request.action.amount());
+		}
+
+		state.less_total = state
+			.less_total
+			.saturating_sub(old_total)
+			.saturating_add(new_total);
+		let summary = summary.unwrap_or(DelegationAction::Decrease(new_total));
+		<DelegationScheduledRequestsSummaryMap<T>>::insert(collator, delegator, summary);
+		<DelegationScheduledRequests<T>>::insert(collator, delegator, scheduled_requests);
+	}
+
+	/// Executes, in their FIFO order, up to `max_requests` matured requests of all the
+	/// delegations of `delegator`. A request which fails to execute is left in place, along with
+	/// the requests following it for the same delegation.
+	pub(crate) fn delegation_execute_matured_requests(
+		delegator: T::AccountId,
+		max_requests: u32,
+	) -> DispatchResultWithPostInfo {
+		let state = <DelegatorState<T>>::get(&delegator).ok_or(<Error<T>>::DelegatorDNE)?;
+		let now = <Round<T>>::get().current;
+
+		let mut executed = 0u32;
+		let mut attempted = 0u32;
+		// reads of the requests of delegations without matured request
+		let mut unmatured_reads = 0u64;
+		for collator in state.delegations.0.into_iter().map(|bond| bond.owner) {
+			while executed < max_requests {
+				let is_matured = <DelegationScheduledRequests<T>>::get(&collator, &delegator)
+					.first()
+					.map_or(false, |request| request.when_executable <= now);
+				if !is_matured {
+					unmatured_reads = unmatured_reads.saturating_add(1);
+					break;
+				}
+				attempted = attempted.saturating_add(1);
+				let result = with_storage_layer(|| {
+					Self::delegation_execute_scheduled_request(collator.clone(), delegator.clone())
+						.map_err(|err| err.error)
+				});
+				if let Err(err) = result {
+					log::debug!(
+						"skipped matured request of {:?} towards {:?}: {:?}",
+						delegator,
+						collator,
+						err
+					);
+					break;
+				}
+				executed = executed.saturating_add(1);
+			}
+		}
+		let actual_weight = <T as Config>::WeightInfo::execute_all_matured_requests(attempted)
+			.saturating_add(T::DbWeight::get().reads(unmatured_reads));
+
+		ensure!(
+			executed > 0,
+			DispatchErrorWithPostInfo {
+				post_info: Some(actual_weight).into(),
+				error: <Error<T>>::NoMaturedDelegationRequests.into(),
+			}
+		);
+		Ok(Some(actual_weight).into())
+	}
+
+	/// Benchmarked weight of executing one more matured request
+	fn matured_request_weight() -> Weight {
+		<T as Config>::WeightInfo::execute_all_matured_requests(1)
+			.saturating_sub(<T as Config>::WeightInfo::execute_all_matured_requests(0))
+	}
+
+	/// Executes matured requests of any delegator within `remaining_weight`, resuming the scan of
+	/// [DelegationScheduledRequests] where the previous call stopped. At most the first request of
+	/// each delegation is executed per scan.
+	pub(crate) fn execute_matured_requests_on_idle(remaining_weight: Weight) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let execution_weight = Self::matured_request_weight();
+		// Round and cursor reads, cursor write
+		let mut used_weight = db_weight.reads_writes(2, 1);
+		let step_weight = db_weight.reads(1).saturating_add(execution_weight);
+		if remaining_weight.any_lt(used_weight.saturating_add(step_weight)) {
+			return Weight::zero();
+		}
+
+		let now = <Round<T>>::get().current;
+		let mut iter = match <MaturedRequestsCursor<T>>::get() {
+			Some(cursor) => <DelegationScheduledRequests<T>>::iter_from(cursor),
+			None => <DelegationScheduledRequests<T>>::iter(),
+		};
+		let mut matured = Vec::new();
+		let mut exhausted = false;
+		while remaining_weight.all_gte(used_weight.saturating_add(step_weight)) {
+			let Some((collator, delegator, requests)) = iter.next() else {
+				exhausted = true;
+				break;
+			};
+			used_weight = used_weight.saturating_add(db_weight.reads(1));
+			if requests
+				.first()
+				.map_or(false, |request| request.when_executable <= now)
+			{
+				matured.push((collator, delegator));
+				used_weight = used_weight.saturating_add(execution_weight);
+			}
+		}
+		if exhausted {
+			<MaturedRequestsCursor<T>>::kill();
+		} else {
+			<MaturedRequestsCursor<T>>::put(iter.last_raw_key().to_vec());
+		}
+
+		for (collator, delegator) in matured {
+			if let Err(err) = with_storage_layer(|| {
+				Self::delegation_execute_scheduled_request(collator.clone(), delegator.clone())
+					.map_err(|err| err.error)
+			}) {
+				log::debug!(
+					"skipped matured request of {:?} towards {:?}: {:?}",
+					delegator,
+					collator,
+					err
+				);
+			}
+		}
+		used_weight
+	}
+
 	/// Returns true if a [ScheduledRequest] exists for a given delegation
 	pub fn delegation_request_exists(collator: &T::AccountId, delegator: &T::AccountId) -> bool {
 		!<DelegationScheduledRequests<T>>::get(collator, delegator).is_empty()
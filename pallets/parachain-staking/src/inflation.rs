@@ -28,7 +28,7 @@ use substrate_fixed::types::I64F64;
 // Milliseconds per year
 const MS_PER_YEAR: u64 = 31_557_600_000;
 
-fn rounds_per_year<T: Config>() -> u32 {
+pub(crate) fn rounds_per_year<T: Config>() -> u32 {
 	let blocks_per_round = <Pallet<T>>::round().length as u64;
 	let blocks_per_year = MS_PER_YEAR / T::BlockTime::get();
 	(blocks_per_year / blocks_per_round) as u32
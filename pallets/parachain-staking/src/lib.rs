@@ -52,6 +52,7 @@ mod auto_compound;
 mod delegation_requests;
 pub mod inflation;
 pub mod migrations;
+mod queries;
 pub mod traits;
 pub mod types;
 pub mod weights;
@@ -60,7 +61,10 @@ pub mod weights;
 mod benchmarks;
 #[cfg(test)]
 mod mock;
+mod pools;
+mod profiles;
 mod set;
+mod slashing;
 #[cfg(test)]
 mod tests;
 
@@ -71,6 +75,10 @@ pub use weights::WeightInfo;
 pub use auto_compound::{AutoCompoundConfig, AutoCompoundDelegations};
 pub use delegation_requests::{CancelledScheduledRequest, DelegationAction, ScheduledRequest};
 pub use pallet::*;
+pub use pools::{Pool, PoolId, PoolUnbonding};
+pub use profiles::{CandidateProfile, CandidateProfileInfo};
+pub use queries::*;
+pub use slashing::{PendingSlash, SlashFractionsConfig, SlashReason};
 pub use traits::*;
 pub use types::*;
 pub use RoundIndex;
@@ -82,12 +90,16 @@ pub mod pallet {
 	};
 	use crate::{set::BoundedOrderedSet, traits::*, types::*, InflationInfo, Range, WeightInfo};
 	use crate::{AutoCompoundConfig, AutoCompoundDelegations};
+	use crate::{CandidateProfile, CandidateProfileInfo};
+	use crate::{PendingSlash, SlashFractionsConfig, SlashReason};
+	use crate::{Pool, PoolId, PoolUnbonding};
 	use frame_support::dispatch::DispatchResultWithPostInfo;
 	use frame_support::pallet_prelude::*;
 	use frame_support::traits::{
-		fungible::{Balanced, Inspect, InspectHold, Mutate, MutateFreeze},
+		fungible::{Balanced, Inspect, Mutate, MutateFreeze, MutateHold},
 		Get,
 	};
+	use frame_support::PalletId;
 	use frame_system::pallet_prelude::*;
 	use sp_consensus_slots::Slot;
 	use sp_runtime::{
@@ -96,8 +108,12 @@ pub mod pallet {
 	};
 	use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
+	/// The in-code storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	/// Pallet for parachain staking
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(PhantomData<T>);
 
@@ -115,14 +131,18 @@ pub mod pallet {
 	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
 		/// The fungible type for handling balances
 		type Currency: Inspect<Self::AccountId>
-			+ InspectHold<Self::AccountId>
 			+ Mutate<Self::AccountId>
 			+ MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
 			+ Balanced<Self::AccountId>;
 		/// The overarching freeze identifier type.
 		type RuntimeFreezeReason: From<FreezeReason>;
+		/// The overarching hold identifier type.
+		type RuntimeHoldReason: From<HoldReason>;
 		/// The origin for monetary governance
 		type MonetaryGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The origin allowed to configure slashing, report misbehaviour and cancel slashes
+		type SlashingOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		/// Minimum number of blocks per round
 		#[pallet::constant]
 		type MinBlocksPerRound: Get<u32>;
@@ -136,18 +156,38 @@ pub mod pallet {
 		/// Number of rounds candidate requests to decrease self-bond must wait to be executable
 		#[pallet::constant]
 		type CandidateBondLessDelay: Get<RoundIndex>;
+		/// Number of rounds candidate requests to change their commission rate must wait to be
+		/// executable
+		#[pallet::constant]
+		type CandidateCommissionChangeDelay: Get<RoundIndex>;
 		/// Number of rounds that delegators remain bonded before exit request is executable
 		#[pallet::constant]
 		type LeaveDelegatorsDelay: Get<RoundIndex>;
 		/// Number of rounds that delegations remain bonded before revocation request is executable
 		#[pallet::constant]
 		type RevokeDelegationDelay: Get<RoundIndex>;
+		/// Number of rounds a delegator must wait between two redelegations
+		#[pallet::constant]
+		type RedelegationCooldown: Get<RoundIndex>;
+		/// Number of rounds between the report of a slash and its application, during which
+		/// governance can cancel it. Must be lower than the leave and revoke delays for the
+		/// slash to reach exiting stake.
+		#[pallet::constant]
+		type SlashDeferDuration: Get<RoundIndex>;
 		/// Number of rounds that delegation less requests must wait before executable
 		#[pallet::constant]
 		type DelegationBondLessDelay: Get<RoundIndex>;
 		/// Number of rounds after which block authors are rewarded
 		#[pallet::constant]
 		type RewardPaymentDelay: Get<RoundIndex>;
+		/// Number of paid rounds for which the rewards of each delegator are kept in
+		/// `RewardHistory`. Set to 0 to disable the history.
+		#[pallet::constant]
+		type RewardHistoryRounds: Get<RoundIndex>;
+		/// Number of rounds for which the collators selected for each round are kept in
+		/// `SelectionHistory`. Set to 0 to disable the history.
+		#[pallet::constant]
+		type SelectionHistoryRounds: Get<RoundIndex>;
 		/// Minimum number of selected candidates every round
 		#[pallet::constant]
 		type MinSelectedCandidates: Get<u32>;
@@ -184,6 +224,9 @@ pub mod pallet {
 		/// Handler to notify the runtime when a new round begin.
 		/// If you don't need it, you can specify the type `()`.
 		type OnNewRound: OnNewRound;
+		/// Handler to notify the runtime when a delegation pool is created.
+		/// If you don't need it, you can specify the type `()`.
+		type OnPoolCreated: OnPoolCreated;
 		/// Get the current slot number
 		type SlotProvider: Get<Slot>;
 		/// Get the slot duration in milliseconds
@@ -195,6 +238,19 @@ pub mod pallet {
 		/// Maximum candidates
 		#[pallet::constant]
 		type MaxCandidates: Get<u32>;
+		/// Identifier from which the accounts of delegation pools are derived
+		#[pallet::constant]
+		type PoolsPalletId: Get<PalletId>;
+		/// Maximum number of candidates a delegation pool delegates to. Must not be greater than
+		/// `MaxDelegationsPerDelegator`.
+		#[pallet::constant]
+		type MaxPoolCandidates: Get<u32>;
+		/// Deposit held from a candidate for as long as its profile is stored
+		#[pallet::constant]
+		type CandidateProfileDeposit: Get<BalanceOf<Self>>;
+		/// Maximum length in bytes of each text field of a candidate profile
+		#[pallet::constant]
+		type MaxCandidateProfileFieldLength: Get<u32>;
 		/// Threshold after which inflation become linear
 		/// If you don't want to use it, set it to `()`
 		#[pallet::constant]
@@ -212,6 +268,13 @@ pub mod pallet {
 		StakingDelegator,
 	}
 
+	/// The reason for holding funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds held as deposit for the profile of a candidate
+		CandidateProfile,
+	}
+
 	#[pallet::error]
 	pub enum Error<T> {
 		DelegatorDNE,
@@ -270,6 +333,28 @@ pub mod pallet {
 		MarkingOfflineNotEnabled,
 		CurrentRoundTooLow,
 		EmptyMigrationBatch,
+		CannotRedelegateToSameCandidate,
+		RedelegationCooldownNotElapsed,
+		SlashingDisabled,
+		PendingSlashDNE,
+		NoMaturedDelegationRequests,
+		InvalidCommissionBounds,
+		CommissionOutOfBounds,
+		PendingCommissionRequestAlreadyExists,
+		PendingCommissionRequestDNE,
+		PendingCommissionRequestNotDueYet,
+		PoolDNE,
+		InvalidPoolCandidates,
+		TooManyPoolCandidates,
+		ZeroPoolShares,
+		InsufficientPoolShares,
+		PoolSharesAllowanceExceeded,
+		PoolUnbondingDNE,
+		PoolUnbondingNotDueYet,
+		PoolUnbondingUnavailable,
+		InsufficientPoolLiquidity,
+		CandidateProfileDNE,
+		CandidateExited,
 	}
 
 	#[pallet::event]
@@ -466,6 +551,123 @@ pub mod pallet {
 			delegator: T::AccountId,
 			amount: BalanceOf<T>,
 		},
+		/// Delegation moved from a candidate to another one.
+		Redelegated {
+			delegator: T::AccountId,
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			amount: BalanceOf<T>,
+			delegator_position: DelegatorAdded<BalanceOf<T>>,
+			auto_compound: Percent,
+		},
+		/// Slash fractions were set.
+		SlashFractionsSet {
+			old: SlashFractionsConfig,
+			new: SlashFractionsConfig,
+		},
+		/// A slash of a collator and its top delegations was scheduled.
+		SlashReported {
+			collator: T::AccountId,
+			reason: SlashReason,
+			fraction: Perbill,
+			apply_at: RoundIndex,
+		},
+		/// A pending slash was cancelled.
+		SlashCancelled {
+			collator: T::AccountId,
+			apply_at: RoundIndex,
+		},
+		/// Stake of an account was slashed and burned.
+		Slashed {
+			collator: T::AccountId,
+			account: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Set the bounds of the commission rate of candidates.
+		CandidateCommissionBoundsSet {
+			old: CommissionBounds,
+			new: CommissionBounds,
+		},
+		/// Candidate requested to change its commission rate.
+		CandidateCommissionChangeRequested {
+			candidate: T::AccountId,
+			commission: Perbill,
+			execute_round: RoundIndex,
+		},
+		/// Candidate changed its commission rate.
+		CandidateCommissionChanged {
+			candidate: T::AccountId,
+			old: Perbill,
+			new: Perbill,
+		},
+		/// Cancelled request to change the commission rate of a candidate.
+		CancelledCandidateCommissionChange {
+			candidate: T::AccountId,
+			commission: Perbill,
+			execute_round: RoundIndex,
+		},
+		/// Created a delegation pool.
+		PoolCreated {
+			pool: PoolId,
+			account: T::AccountId,
+			candidates: Vec<T::AccountId>,
+		},
+		/// Set the candidates of a delegation pool.
+		PoolCandidatesSet {
+			pool: PoolId,
+			candidates: Vec<T::AccountId>,
+		},
+		/// Member joined a delegation pool and received shares of it.
+		JoinedPool {
+			pool: PoolId,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+			shares: BalanceOf<T>,
+		},
+		/// Member unbonded shares of a delegation pool.
+		UnbondedFromPool {
+			pool: PoolId,
+			member: T::AccountId,
+			shares: BalanceOf<T>,
+			amount: BalanceOf<T>,
+			when_executable: RoundIndex,
+		},
+		/// Member withdrew its unbonded stake from a delegation pool.
+		WithdrawnFromPool {
+			pool: PoolId,
+			member: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Revoked the ineligible delegations of a pool and delegated its idle stake.
+		PoolRebalanced {
+			pool: PoolId,
+			revoked: u32,
+			delegated: BalanceOf<T>,
+		},
+		/// Transferred shares of a delegation pool.
+		PoolSharesTransferred {
+			pool: PoolId,
+			from: T::AccountId,
+			to: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Approved a spender to transfer shares of a delegation pool on behalf of their owner.
+		PoolSharesApproved {
+			pool: PoolId,
+			owner: T::AccountId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		/// Set the profile of a candidate.
+		CandidateProfileSet {
+			candidate: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
+		/// Cleared the profile of a candidate and released its deposit.
+		CandidateProfileCleared {
+			candidate: T::AccountId,
+			deposit: BalanceOf<T>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -487,6 +689,8 @@ pub mod pallet {
 
 				// mutate round
 				round.update(n, current_slot);
+				// slashes left unapplied in the previous round are applied in this one
+				weight = weight.saturating_add(Self::carry_over_pending_slashes(round.current));
 				// notify that new round begin
 				weight = weight.saturating_add(T::OnNewRound::on_new_round(round.current));
 				// pay all stakers for T::RewardPaymentDelay rounds ago
@@ -510,6 +714,8 @@ pub mod pallet {
 				weight = weight.saturating_add(T::DbWeight::get().reads_writes(0, 1));
 			} else {
 				weight = weight.saturating_add(Self::handle_delayed_payouts(round.current));
+				weight = weight.saturating_add(Self::prune_reward_history(round.current));
+				weight = weight.saturating_add(Self::handle_pending_slashes(round.current));
 			}
 
 			// add on_finalize weight
@@ -522,12 +728,22 @@ pub mod pallet {
 			Self::award_points_to_block_author();
 			Self::cleanup_inactive_collator_info();
 		}
+		fn on_idle(_n: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let used_weight = Self::execute_matured_requests_on_idle(remaining_weight);
+			used_weight.saturating_add(Self::rebalance_next_pool_on_idle(
+				remaining_weight.saturating_sub(used_weight),
+			))
+		}
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn collator_commission)]
 	/// Commission percent taken off of rewards for all collators
-	type CollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+	pub(crate) type CollatorCommission<T: Config> = StorageValue<_, Perbill, ValueQuery>;
+
+	#[pallet::storage]
+	/// Bounds of the commission rate candidates can set, applied to all candidates
+	pub type CandidateCommissionBounds<T: Config> = StorageValue<_, CommissionBounds, ValueQuery>;
 
 	#[pallet::storage]
 	#[pallet::getter(fn total_selected)]
@@ -559,6 +775,27 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// Fractions of the stake slashed for each slash reason
+	pub type SlashFractions<T: Config> = StorageValue<_, SlashFractionsConfig, ValueQuery>;
+
+	#[pallet::storage]
+	/// Slashes waiting to be applied, by round of application and collator
+	pub type PendingSlashes<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		Twox64Concat,
+		T::AccountId,
+		PendingSlash,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Round of the last redelegation of a delegator
+	pub type LastRedelegation<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RoundIndex, OptionQuery>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn candidate_info)]
 	/// Get collator candidate info associated with an account if account is candidate else None
@@ -595,6 +832,11 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Raw key of the last entry of [DelegationScheduledRequests] scanned by `on_idle` for matured
+	/// requests, if the scan is not complete.
+	#[pallet::storage]
+	pub(crate) type MaturedRequestsCursor<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
 	/// Tracks how many delegators have at least one pending delegation request for a given collator.
 	///
 	/// This is used to enforce that the number of delegators with pending requests per collator
@@ -660,7 +902,7 @@ pub mod pallet {
 	#[pallet::storage]
 	#[pallet::getter(fn selected_candidates)]
 	/// The collator candidates selected for the current round
-	type SelectedCandidates<T: Config> =
+	pub(crate) type SelectedCandidates<T: Config> =
 		StorageValue<_, BoundedVec<T::AccountId, T::MaxCandidates>, ValueQuery>;
 
 	#[pallet::storage]
@@ -703,6 +945,28 @@ pub mod pallet {
 	pub type DelayedPayouts<T: Config> =
 		StorageMap<_, Twox64Concat, RoundIndex, DelayedPayout<BalanceOf<T>>, OptionQuery>;
 
+	#[pallet::storage]
+	/// Rewards paid to each delegator for a round, kept for `T::RewardHistoryRounds` paid rounds
+	pub type RewardHistory<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<DelegatorReward<T::AccountId, BalanceOf<T>>, T::MaxDelegationsPerDelegator>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Collators selected for a round, kept for `T::SelectionHistoryRounds` rounds
+	pub type SelectionHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		RoundIndex,
+		BoundedVec<SelectedCollator<T::AccountId, BalanceOf<T>>, T::MaxCandidates>,
+		ValueQuery,
+	>;
+
 	#[pallet::storage]
 	#[pallet::getter(fn inflation_config)]
 	/// Inflation configuration
@@ -731,6 +995,66 @@ pub mod pallet {
 	/// Killswitch to enable/disable marking offline feature.
 	pub type EnableMarkingOffline<T: Config> = StorageValue<_, bool, ValueQuery>;
 
+	#[pallet::storage]
+	/// Identifier of the next delegation pool, which is also the number of pools
+	pub type NextPoolId<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Next delegation pool to be rebalanced by `on_idle`
+	pub(crate) type NextPoolToRebalance<T: Config> = StorageValue<_, PoolId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Delegation pools
+	pub type Pools<T: Config> =
+		StorageMap<_, Twox64Concat, PoolId, Pool<T::AccountId, BalanceOf<T>>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Shares of each delegation pool owned by each member
+	pub type PoolShares<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Shares of a delegation pool a spender is allowed to transfer, by pool, owner and spender
+	pub type PoolShareApprovals<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, PoolId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+			NMapKey<Blake2_128Concat, T::AccountId>,
+		),
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::storage]
+	/// Stake unbonded by each member of a delegation pool and not withdrawn yet
+	pub type PoolUnbondings<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		PoolId,
+		Blake2_128Concat,
+		T::AccountId,
+		PoolUnbonding<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// Profiles of candidates, along with the deposit held for them
+	pub type CandidateProfiles<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		CandidateProfile<BalanceOf<T>, T::MaxCandidateProfileFieldLength>,
+		OptionQuery,
+	>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Initialize balance and register all as collators: `(collator AccountId, balance Amount)`
@@ -1061,6 +1385,7 @@ pub mod pallet {
 		#[pallet::call_index(9)]
 		#[pallet::weight(
 			<T as Config>::WeightInfo::execute_leave_candidates_worst_case(*candidate_delegation_count)
+				.saturating_add(T::DbWeight::get().reads_writes(2, 2))
 		)]
 		pub fn execute_leave_candidates(
 			origin: OriginFor<T>,
@@ -1279,6 +1604,22 @@ pub mod pallet {
 			Self::delegation_execute_scheduled_request(candidate, delegator)
 		}
 
+		/// Execute up to `max_requests` matured requests of all the delegations of a delegator.
+		#[pallet::call_index(37)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::execute_all_matured_requests(*max_requests).saturating_add(
+				T::DbWeight::get().reads(T::MaxDelegationsPerDelegator::get().into())
+			)
+		)]
+		pub fn execute_all_matured_requests(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			max_requests: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::delegation_execute_matured_requests(delegator, max_requests)
+		}
+
 		/// Cancel request to change an existing delegation.
 		#[pallet::call_index(26)]
 		#[pallet::weight(<T as Config>::WeightInfo::cancel_delegation_request(350))]
@@ -1314,6 +1655,10 @@ pub mod pallet {
 		}
 
 		/// Notify a collator is inactive during MaxOfflineRounds
+		///
+		/// An offline slash is reported for the collator if enabled. The slash is deferred like
+		/// the ones reported by governance, which can cancel it until it is applied. Failing to
+		/// report it does not prevent the collator from being marked offline.
 		#[pallet::call_index(29)]
 		#[pallet::weight(<T as Config>::WeightInfo::notify_inactive_collator())]
 		pub fn notify_inactive_collator(
@@ -1371,6 +1716,15 @@ pub mod pallet {
 					collator.clone(),
 					round_info.current.saturating_sub(1),
 				);
+				if !<SlashFractions<T>>::get().offline.is_zero() {
+					if let Err(err) = Self::report_slash(collator.clone(), SlashReason::Offline) {
+						log::warn!(
+							"failed to report offline slash of {:?}: {:?}",
+							collator,
+							err
+						);
+					}
+				}
 			} else {
 				return Err(<Error<T>>::CannotBeNotifiedAsInactive.into());
 			}
@@ -1429,6 +1783,326 @@ pub mod pallet {
 			});
 			Ok(().into())
 		}
+
+		/// Move a delegation to another candidate without unbonding it.
+		/// The amount and auto-compound config of the delegation are kept, and it starts being
+		/// counted for the new candidate from the next round.
+		/// A delegation may not be moved if a request is pending for it, nor within
+		/// `RedelegationCooldown` rounds of the last redelegation of the delegator.
+		#[pallet::call_index(33)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::execute_delegator_revoke_delegation_worst().saturating_add(
+				<T as Config>::WeightInfo::delegate_with_auto_compound(
+					*candidate_delegation_count,
+					*candidate_auto_compounding_delegation_count,
+					T::MaxDelegationsPerDelegator::get(),
+				)
+			)
+		)]
+		pub fn redelegate(
+			origin: OriginFor<T>,
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			candidate_delegation_count: u32,
+			candidate_auto_compounding_delegation_count: u32,
+		) -> DispatchResultWithPostInfo {
+			let delegator = ensure_signed(origin)?;
+			Self::redelegate_inner(
+				delegator,
+				from_candidate,
+				to_candidate,
+				candidate_delegation_count,
+				candidate_auto_compounding_delegation_count,
+			)
+		}
+
+		/// Set the fractions of the stake slashed for each slash reason.
+		/// A zero fraction disables the corresponding slash.
+		#[pallet::call_index(34)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_slash_fractions())]
+		pub fn set_slash_fractions(
+			origin: OriginFor<T>,
+			new: SlashFractionsConfig,
+		) -> DispatchResultWithPostInfo {
+			T::SlashingOrigin::ensure_origin(origin)?;
+			let old = <SlashFractions<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<SlashFractions<T>>::put(new);
+			Self::deposit_event(Event::SlashFractionsSet { old, new });
+			Ok(().into())
+		}
+
+		/// Report a misbehaviour of a collator candidate, such as an equivocation.
+		/// The candidate and its top delegations are slashed after `SlashDeferDuration` rounds,
+		/// unless the slash is cancelled.
+		#[pallet::call_index(35)]
+		#[pallet::weight(<T as Config>::WeightInfo::report_misbehaviour())]
+		pub fn report_misbehaviour(
+			origin: OriginFor<T>,
+			collator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::SlashingOrigin::ensure_origin(origin)?;
+			Self::report_slash(collator, SlashReason::Misbehaviour)?;
+			Ok(().into())
+		}
+
+		/// Cancel a slash which has not been applied yet.
+		/// A slash due in a past round which is still pending was carried over to the current
+		/// round, where it is cancelled.
+		#[pallet::call_index(36)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_slash())]
+		pub fn cancel_slash(
+			origin: OriginFor<T>,
+			apply_at: RoundIndex,
+			collator: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			T::SlashingOrigin::ensure_origin(origin)?;
+			let current = <Round<T>>::get().current;
+			let cancelled = <PendingSlashes<T>>::take(apply_at, &collator).is_some()
+				|| (apply_at < current && <PendingSlashes<T>>::take(current, &collator).is_some());
+			ensure!(cancelled, Error::<T>::PendingSlashDNE);
+			Self::deposit_event(Event::SlashCancelled { collator, apply_at });
+			Ok(().into())
+		}
+
+		/// Set the bounds of the commission rate of candidates.
+		/// Commission rates outside the new bounds are clamped to them.
+		#[pallet::call_index(38)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_candidate_commission_bounds())]
+		pub fn set_candidate_commission_bounds(
+			origin: OriginFor<T>,
+			new: CommissionBounds,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(new.min <= new.max, Error::<T>::InvalidCommissionBounds);
+			let old = <CandidateCommissionBounds<T>>::get();
+			ensure!(old != new, Error::<T>::NoWritingSameValue);
+			<CandidateCommissionBounds<T>>::put(new);
+			Self::deposit_event(Event::CandidateCommissionBoundsSet { old, new });
+			Ok(().into())
+		}
+
+		/// Request by collator candidate to change its commission rate
+		#[pallet::call_index(39)]
+		#[pallet::weight(<T as Config>::WeightInfo::schedule_candidate_commission_change())]
+		pub fn schedule_candidate_commission_change(
+			origin: OriginFor<T>,
+			commission: Perbill,
+		) -> DispatchResultWithPostInfo {
+			let collator = ensure_signed(origin)?;
+			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+			let when = state.schedule_commission_change::<T>(commission)?;
+			<CandidateInfo<T>>::insert(&collator, state);
+			Self::deposit_event(Event::CandidateCommissionChangeRequested {
+				candidate: collator,
+				commission,
+				execute_round: when,
+			});
+			Ok(().into())
+		}
+
+		/// Execute pending request to change the commission rate of a collator candidate
+		#[pallet::call_index(40)]
+		#[pallet::weight(<T as Config>::WeightInfo::execute_candidate_commission_change())]
+		pub fn execute_candidate_commission_change(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			let mut state = <CandidateInfo<T>>::get(&candidate).ok_or(Error::<T>::CandidateDNE)?;
+			state.execute_commission_change::<T>(candidate.clone())?;
+			<CandidateInfo<T>>::insert(&candidate, state);
+			Ok(().into())
+		}
+
+		/// Cancel pending request to change the commission rate of a collator candidate
+		#[pallet::call_index(41)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_candidate_commission_change())]
+		pub fn cancel_candidate_commission_change(
+			origin: OriginFor<T>,
+		) -> DispatchResultWithPostInfo {
+			let collator = ensure_signed(origin)?;
+			let mut state = <CandidateInfo<T>>::get(&collator).ok_or(Error::<T>::CandidateDNE)?;
+			state.cancel_commission_change::<T>(collator.clone())?;
+			<CandidateInfo<T>>::insert(&collator, state);
+			Ok(().into())
+		}
+
+		/// Create a delegation pool delegating to `candidates`.
+		#[pallet::call_index(42)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool(candidates.len() as u32))]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			candidates: Vec<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_pool_candidates(&candidates)?;
+			let pool = <NextPoolId<T>>::get();
+			<Pools<T>>::insert(
+				pool,
+				Pool {
+					candidates: candidates.clone(),
+					total_shares: Zero::zero(),
+					unbonding: Zero::zero(),
+					free: Zero::zero(),
+					undelegating: Zero::zero(),
+				},
+			);
+			<NextPoolId<T>>::put(pool.saturating_add(1));
+			T::OnPoolCreated::on_pool_created(pool);
+			Self::deposit_event(Event::PoolCreated {
+				pool,
+				account: Self::pool_account(pool),
+				candidates,
+			});
+			Ok(().into())
+		}
+
+		/// Set the candidates of a delegation pool. Delegations to removed candidates are revoked
+		/// when the pool is rebalanced.
+		#[pallet::call_index(43)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_candidates(candidates.len() as u32))]
+		pub fn set_pool_candidates(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			candidates: Vec<T::AccountId>,
+		) -> DispatchResultWithPostInfo {
+			T::MonetaryGovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_pool_candidates(&candidates)?;
+			<Pools<T>>::try_mutate(pool, |info| {
+				let info = info.as_mut().ok_or(Error::<T>::PoolDNE)?;
+				info.candidates = candidates.clone();
+				Ok::<(), Error<T>>(())
+			})?;
+			Self::deposit_event(Event::PoolCandidatesSet { pool, candidates });
+			Ok(().into())
+		}
+
+		/// Join a delegation pool with `amount`, receiving shares of the pool in proportion of the
+		/// pool stake.
+		#[pallet::call_index(44)]
+		#[pallet::weight(Pallet::<T>::join_pool_weight())]
+		pub fn join_pool(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let member = ensure_signed(origin)?;
+			Self::join_pool_inner(member, pool, amount)?;
+			Ok(().into())
+		}
+
+		/// Unbond `shares` of a delegation pool. The stake they are worth can be withdrawn once
+		/// the undelegation is executable.
+		#[pallet::call_index(45)]
+		#[pallet::weight(Pallet::<T>::unbond_from_pool_weight())]
+		pub fn unbond_from_pool(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			shares: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let member = ensure_signed(origin)?;
+			Self::unbond_from_pool_inner(member, pool, shares)?;
+			Ok(().into())
+		}
+
+		/// Withdraw the stake unbonded from a delegation pool.
+		#[pallet::call_index(46)]
+		#[pallet::weight(Pallet::<T>::withdraw_unbonded_from_pool_weight())]
+		pub fn withdraw_unbonded_from_pool(
+			origin: OriginFor<T>,
+			pool: PoolId,
+		) -> DispatchResultWithPostInfo {
+			let member = ensure_signed(origin)?;
+			Self::withdraw_unbonded_from_pool_inner(member, pool)?;
+			Ok(().into())
+		}
+
+		/// Revoke the delegations of a pool to candidates which are offline, not selected or not
+		/// in the pool anymore, and delegate its idle stake. Permissionless.
+		#[pallet::call_index(47)]
+		#[pallet::weight(Pallet::<T>::rebalance_pool_weight())]
+		pub fn rebalance_pool(origin: OriginFor<T>, pool: PoolId) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::rebalance_pool_inner(pool)?;
+			Ok(().into())
+		}
+
+		/// Transfer shares of a delegation pool.
+		#[pallet::call_index(48)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_pool_shares())]
+		pub fn transfer_pool_shares(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let from = ensure_signed(origin)?;
+			Self::transfer_pool_shares_inner(pool, from, dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Allow `spender` to transfer up to `amount` shares of a delegation pool on behalf of
+		/// the caller.
+		#[pallet::call_index(49)]
+		#[pallet::weight(<T as Config>::WeightInfo::approve_pool_shares())]
+		pub fn approve_pool_shares(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			spender: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let owner = ensure_signed(origin)?;
+			ensure!(<Pools<T>>::contains_key(pool), Error::<T>::PoolDNE);
+			<PoolShareApprovals<T>>::insert((pool, &owner, &spender), amount);
+			Self::deposit_event(Event::PoolSharesApproved {
+				pool,
+				owner,
+				spender,
+				amount,
+			});
+			Ok(().into())
+		}
+
+		/// Transfer shares of a delegation pool on behalf of `owner`, within the allowance
+		/// approved to the caller.
+		#[pallet::call_index(50)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer_pool_shares_from())]
+		pub fn transfer_pool_shares_from(
+			origin: OriginFor<T>,
+			pool: PoolId,
+			owner: T::AccountId,
+			dest: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResultWithPostInfo {
+			let spender = ensure_signed(origin)?;
+			Self::transfer_pool_shares_from_inner(spender, pool, owner, dest, amount)?;
+			Ok(().into())
+		}
+
+		/// Set the profile of a collator candidate, holding `T::CandidateProfileDeposit` for as
+		/// long as it is stored
+		#[pallet::call_index(51)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_candidate_profile(info.byte_len()))]
+		pub fn set_candidate_profile(
+			origin: OriginFor<T>,
+			info: CandidateProfileInfo<T::MaxCandidateProfileFieldLength>,
+		) -> DispatchResultWithPostInfo {
+			let candidate = ensure_signed(origin)?;
+			Self::set_candidate_profile_inner(candidate, info)?;
+			Ok(().into())
+		}
+
+		/// Clear the profile of a collator candidate and release its deposit
+		#[pallet::call_index(52)]
+		#[pallet::weight(<T as Config>::WeightInfo::clear_candidate_profile(
+			CandidateProfileInfo::<T::MaxCandidateProfileFieldLength>::max_byte_len()
+		))]
+		pub fn clear_candidate_profile(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let candidate = ensure_signed(origin)?;
+			let actual_weight = Self::clear_candidate_profile_inner(candidate)?;
+			Ok(Some(actual_weight).into())
+		}
 	}
 
 	/// Represents a payout made via `pay_one_collator_reward`.
@@ -1739,6 +2413,7 @@ pub mod pallet {
 			<AutoCompoundingDelegations<T>>::remove(&candidate);
 			<TopDelegations<T>>::remove(&candidate);
 			<BottomDelegations<T>>::remove(&candidate);
+			let profile_weight = Self::remove_candidate_profile(candidate.clone());
 			let new_total_staked = <Total<T>>::get().saturating_sub(total_backing);
 			<Total<T>>::put(new_total_staked);
 			Self::deposit_event(Event::CandidateLeft {
@@ -1746,7 +2421,7 @@ pub mod pallet {
 				unlocked_amount: total_backing,
 				new_total_amt_locked: new_total_staked,
 			});
-			Ok(Some(actual_weight).into())
+			Ok(Some(actual_weight.saturating_add(profile_weight)).into())
 		}
 
 		pub fn max_delegators_per_candidate() -> u32 {
@@ -1796,7 +2471,7 @@ pub mod pallet {
 		}
 
 		/// Compute round issuance based on duration of the given round
-		fn compute_issuance(round_duration: u64, round_length: u32) -> BalanceOf<T> {
+		pub(crate) fn compute_issuance(round_duration: u64, round_length: u32) -> BalanceOf<T> {
 			let ideal_duration: BalanceOf<T> = round_length
 				.saturating_mul(T::BlockTime::get() as u32)
 				.into();
@@ -1831,6 +2506,109 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Move the delegation of `delegator` from `from_candidate` to `to_candidate`, keeping
+		/// the frozen balance of the delegator untouched.
+		pub(crate) fn redelegate_inner(
+			delegator: T::AccountId,
+			from_candidate: T::AccountId,
+			to_candidate: T::AccountId,
+			candidate_delegation_count_hint: u32,
+			candidate_auto_compounding_delegation_count_hint: u32,
+		) -> DispatchResultWithPostInfo {
+			ensure!(
+				from_candidate != to_candidate,
+				Error::<T>::CannotRedelegateToSameCandidate
+			);
+			let mut state = <DelegatorState<T>>::get(&delegator).ok_or(Error::<T>::DelegatorDNE)?;
+			let amount = state
+				.get_bond_amount(&from_candidate)
+				.ok_or(Error::<T>::DelegationDNE)?;
+			ensure!(
+				amount >= T::MinDelegation::get(),
+				Error::<T>::DelegationBelowMin
+			);
+			ensure!(
+				state.get_bond_amount(&to_candidate).is_none(),
+				Error::<T>::AlreadyDelegatedCandidate
+			);
+			ensure!(
+				!Self::delegation_request_exists(&from_candidate, &delegator),
+				Error::<T>::PendingDelegationRequestAlreadyExists
+			);
+			let now = <Round<T>>::get().current;
+			if let Some(last) = <LastRedelegation<T>>::get(&delegator) {
+				ensure!(
+					last.saturating_add(T::RedelegationCooldown::get()) <= now,
+					Error::<T>::RedelegationCooldownNotElapsed
+				);
+			}
+
+			let mut to_state =
+				<CandidateInfo<T>>::get(&to_candidate).ok_or(Error::<T>::CandidateDNE)?;
+			ensure!(!to_state.is_leaving(), Error::<T>::CandidateExited);
+			ensure!(
+				candidate_delegation_count_hint >= to_state.delegation_count,
+				Error::<T>::TooLowCandidateDelegationCountToDelegate
+			);
+			let auto_compound =
+				<AutoCompoundDelegations<T>>::auto_compound(&from_candidate, &delegator);
+			if !auto_compound.is_zero() {
+				ensure!(
+					<AutoCompoundDelegations<T>>::get_auto_compounding_delegation_count(
+						&to_candidate
+					) as u32 <= candidate_auto_compounding_delegation_count_hint,
+					Error::<T>::TooLowCandidateAutoCompoundingDelegationCountToDelegate,
+				);
+			}
+
+			// remove the delegation from the previous candidate
+			let mut from_state =
+				<CandidateInfo<T>>::get(&from_candidate).ok_or(Error::<T>::CandidateDNE)?;
+			from_state.rm_delegation_if_exists::<T>(&from_candidate, delegator.clone(), amount)?;
+			<CandidateInfo<T>>::insert(&from_candidate, from_state);
+			<AutoCompoundDelegations<T>>::remove_auto_compound(&from_candidate, &delegator);
+
+			// add it to the new candidate
+			let (delegator_position, less_total_staked) = to_state.add_delegation::<T>(
+				&to_candidate,
+				Bond {
+					owner: delegator.clone(),
+					amount,
+				},
+			)?;
+			<CandidateInfo<T>>::insert(&to_candidate, to_state);
+			if !auto_compound.is_zero() {
+				let mut auto_compounding_state =
+					<AutoCompoundDelegations<T>>::get_storage(&to_candidate);
+				auto_compounding_state.set_for_delegator(delegator.clone(), auto_compound)?;
+				auto_compounding_state.set_storage(&to_candidate);
+			}
+
+			// the delegated amount stays frozen, only a kicked bottom delegation leaves the total
+			if let Some(less) = less_total_staked {
+				<Total<T>>::mutate(|total| *total = total.saturating_sub(less));
+			}
+			state
+				.delegations
+				.remove(&Bond::from_owner(from_candidate.clone()));
+			state.delegations.insert(Bond {
+				owner: to_candidate.clone(),
+				amount,
+			});
+			<DelegatorState<T>>::insert(&delegator, state);
+			<LastRedelegation<T>>::insert(&delegator, now);
+
+			Self::deposit_event(Event::Redelegated {
+				delegator,
+				from_candidate,
+				to_candidate,
+				amount,
+				delegator_position,
+				auto_compound,
+			});
+			Ok(().into())
+		}
+
 		pub(crate) fn prepare_staking_payouts(
 			round_info: RoundInfo<BlockNumberFor<T>>,
 			round_duration: u64,
@@ -1941,8 +2719,6 @@ pub mod pallet {
 				return (RewardPayment::Finished, early_weight);
 			}
 
-			let collator_fee = payout_info.collator_commission;
-			let collator_issuance = collator_fee * payout_info.round_issuance;
 			if let Some((collator, state)) =
 				<AtStake<T>>::iter_prefix(paid_for_round).drain().next()
 			{
@@ -1961,14 +2737,13 @@ pub mod pallet {
 				// known ahead of time.
 				let mut extra_weight = Weight::zero();
 				let pct_due = Perbill::from_rational(pts, total_points);
-				let total_paid = pct_due * payout_info.total_staking_reward;
-				let mut amt_due = total_paid;
 
 				let num_delegators = state.delegations.len();
 				let mut num_paid_delegations = 0u32;
 				let mut num_auto_compounding = 0u32;
 				if state.delegations.is_empty() {
 					// solo collator with no delegators
+					let amt_due = pct_due * payout_info.total_staking_reward;
 					extra_weight = extra_weight
 						.saturating_add(T::PayoutCollatorReward::payout_collator_reward(
 							paid_for_round,
@@ -1982,10 +2757,13 @@ pub mod pallet {
 						));
 				} else {
 					// pay collator first; commission + due_portion
-					let collator_pct = Perbill::from_rational(state.bond, state.total);
-					let commission = pct_due * collator_issuance;
-					amt_due = amt_due.saturating_sub(commission);
-					let collator_reward = (collator_pct * amt_due).saturating_add(commission);
+					let (collator_reward, amt_due) = Self::split_collator_reward(
+						pct_due,
+						&payout_info,
+						state.commission,
+						state.bond,
+						state.total,
+					);
 					extra_weight = extra_weight
 						.saturating_add(T::PayoutCollatorReward::payout_collator_reward(
 							paid_for_round,
@@ -2010,12 +2788,20 @@ pub mod pallet {
 						if !due.is_zero() {
 							num_auto_compounding += if auto_compound.is_zero() { 0 } else { 1 };
 							num_paid_delegations += 1u32;
-							Self::mint_and_compound(
+							let (minted, compounded) = Self::mint_and_compound(
 								due,
 								auto_compound.clone(),
 								collator.clone(),
 								owner.clone(),
 							);
+							extra_weight =
+								extra_weight.saturating_add(Self::record_delegator_reward(
+									paid_for_round,
+									&owner,
+									&collator,
+									minted,
+									compounded,
+								));
 						}
 					}
 				}
@@ -2039,6 +2825,77 @@ pub mod pallet {
 			}
 		}
 
+		/// Record the reward paid to a delegator in `RewardHistory`, if the history is enabled.
+		fn record_delegator_reward(
+			paid_for_round: RoundIndex,
+			delegator: &T::AccountId,
+			collator: &T::AccountId,
+			amount: BalanceOf<T>,
+			compounded: BalanceOf<T>,
+		) -> Weight {
+			if T::RewardHistoryRounds::get().is_zero() || amount.is_zero() {
+				return Weight::zero();
+			}
+
+			<RewardHistory<T>>::mutate(paid_for_round, delegator, |rewards| {
+				// a delegator is paid at most once per delegation and round
+				if rewards
+					.try_push(DelegatorReward {
+						collator: collator.clone(),
+						amount,
+						compounded,
+					})
+					.is_err()
+				{
+					log::warn!(
+						"reward history of delegator {:?} is full for round {}",
+						delegator,
+						paid_for_round
+					);
+				}
+			});
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		/// Remove the reward history of the round which left the retention window, in chunks of
+		/// at most `T::MaxTopDelegationsPerCandidate` delegators per block.
+		fn prune_reward_history(now: RoundIndex) -> Weight {
+			let retained = T::RewardHistoryRounds::get();
+			let oldest_kept = now
+				.saturating_sub(T::RewardPaymentDelay::get())
+				.saturating_sub(retained.saturating_sub(1));
+			if retained.is_zero() || oldest_kept.is_zero() {
+				return Weight::zero();
+			}
+
+			let result = <RewardHistory<T>>::clear_prefix(
+				oldest_kept - 1,
+				T::MaxTopDelegationsPerCandidate::get(),
+				None,
+			);
+			T::DbWeight::get().reads_writes(result.loops.into(), result.unique.into())
+		}
+
+		/// Split the `pct_due` share of the round rewards between a collator, paid its
+		/// `commission_rate`, and its delegations.
+		///
+		/// Returns the reward of the collator, commission included, and the amount left to the
+		/// delegations, to be shared pro rata of their stake in `total`.
+		pub(crate) fn split_collator_reward(
+			pct_due: Perbill,
+			payout_info: &DelayedPayout<BalanceOf<T>>,
+			commission_rate: Perbill,
+			bond: BalanceOf<T>,
+			total: BalanceOf<T>,
+		) -> (BalanceOf<T>, BalanceOf<T>) {
+			let collator_issuance = commission_rate * payout_info.round_issuance;
+			let commission = pct_due * collator_issuance;
+			let amt_due = (pct_due * payout_info.total_staking_reward).saturating_sub(commission);
+			let collator_pct = Perbill::from_rational(bond, total);
+			let collator_reward = (collator_pct * amt_due).saturating_add(commission);
+			(collator_reward, amt_due)
+		}
+
 		/// Compute the top `TotalSelected` candidates in the CandidatePool and return
 		/// a vec of their AccountIds (sorted by AccountId).
 		///
@@ -2096,6 +2953,7 @@ pub mod pallet {
 				// SELECTION FAILED TO SELECT >=1 COLLATOR => select collators from previous round
 				let last_round = now.saturating_sub(1u32);
 				let mut total_per_candidate: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+				let mut selection = Vec::new();
 				// set this round AtStake to last round AtStake
 				for (account, snapshot) in <AtStake<T>>::iter_prefix(last_round) {
 					collator_count = collator_count.saturating_add(1u32);
@@ -2103,6 +2961,11 @@ pub mod pallet {
 						delegation_count.saturating_add(snapshot.delegations.len() as u32);
 					total = total.saturating_add(snapshot.total);
 					total_per_candidate.insert(account.clone(), snapshot.total);
+					selection.push(SelectedCollator {
+						collator: account.clone(),
+						total_counted: snapshot.total,
+						delegator_count: snapshot.delegations.len() as u32,
+					});
 					<AtStake<T>>::insert(now, account, snapshot);
 				}
 				// `SelectedCandidates` remains unchanged from last round
@@ -2117,11 +2980,14 @@ pub mod pallet {
 						total_exposed_amount: *snapshot_total,
 					})
 				}
-				let weight = <T as Config>::WeightInfo::select_top_candidates(0, 0);
+				selection.sort_by(|a, b| a.collator.cmp(&b.collator));
+				let weight = <T as Config>::WeightInfo::select_top_candidates(0, 0)
+					.saturating_add(Self::record_selection(now, selection));
 				return (weight, collator_count, delegation_count, total);
 			}
 
 			// snapshot exposure for round for weighting reward distribution
+			let mut selection = Vec::with_capacity(collators.len());
 			for account in collators.iter() {
 				let state = <CandidateInfo<T>>::get(account)
 					.expect("all members of CandidateQ must be candidates");
@@ -2134,6 +3000,7 @@ pub mod pallet {
 					rewardable_delegations,
 				} = Self::get_rewardable_delegators(&account);
 				let total_counted = state.total_counted.saturating_sub(uncounted_stake);
+				let delegator_count = rewardable_delegations.len() as u32;
 
 				let auto_compounding_delegations = <AutoCompoundingDelegations<T>>::get(&account)
 					.into_iter()
@@ -2155,8 +3022,14 @@ pub mod pallet {
 					bond: state.bond,
 					delegations: rewardable_delegations,
 					total: total_counted,
+					commission: state.effective_commission::<T>(<CollatorCommission<T>>::get()),
 				};
 				<AtStake<T>>::insert(now, account, snapshot);
+				selection.push(SelectedCollator {
+					collator: account.clone(),
+					total_counted,
+					delegator_count,
+				});
 				Self::deposit_event(Event::CollatorChosen {
 					round: now,
 					collator_account: account.clone(),
@@ -2173,10 +3046,29 @@ pub mod pallet {
 			let weight = <T as Config>::WeightInfo::select_top_candidates(
 				collator_count,
 				avg_delegator_count,
-			);
+			)
+			.saturating_add(Self::record_selection(now, selection));
 			(weight, collator_count, delegation_count, total)
 		}
 
+		/// Record the collators selected for round `now` in the selection history, and remove
+		/// the round which left the retention window.
+		fn record_selection(
+			now: RoundIndex,
+			selection: Vec<SelectedCollator<T::AccountId, BalanceOf<T>>>,
+		) -> Weight {
+			let retained = T::SelectionHistoryRounds::get();
+			if retained.is_zero() {
+				return Weight::zero();
+			}
+
+			<SelectionHistory<T>>::insert(now, BoundedVec::truncate_from(selection));
+			if now > retained {
+				<SelectionHistory<T>>::remove(now - retained);
+			}
+			T::DbWeight::get().writes(2)
+		}
+
 		/// Build the effective list of delegators with their intended bond amount
 		/// for reward calculation.
 		///
@@ -2277,18 +3169,20 @@ pub mod pallet {
 		/// delegator and tries to compound a specified percent of it back towards the delegation.
 		/// If a scheduled delegation revoke exists, then the amount is only minted, and nothing is
 		/// compounded. Emits the [Compounded] event.
+		///
+		/// Returns the minted and compounded amounts.
 		pub fn mint_and_compound(
 			amt: BalanceOf<T>,
 			compound_percent: Percent,
 			candidate: T::AccountId,
 			delegator: T::AccountId,
-		) {
+		) -> (BalanceOf<T>, BalanceOf<T>) {
 			// Mint rewards to the delegator
 			if frame_system::Pallet::<T>::account_exists(&delegator) {
 				if let Ok(minted) = Self::mint(amt.clone(), &delegator) {
 					let compound_amount = compound_percent.mul_ceil(minted);
 					if compound_amount.is_zero() {
-						return;
+						return (minted, Zero::zero());
 					}
 
 					if let Err(err) = Self::delegation_bond_more_without_event(
@@ -2302,7 +3196,7 @@ pub mod pallet {
 							delegator,
 							err
 						);
-						return;
+						return (minted, Zero::zero());
 					};
 
 					Pallet::<T>::deposit_event(Event::Compounded {
@@ -2310,8 +3204,10 @@ pub mod pallet {
 						candidate,
 						amount: compound_amount.clone(),
 					});
+					return (minted, compound_amount);
 				};
 			}
+			(Zero::zero(), Zero::zero())
 		}
 
 		/// Add reward points to block authors:
@@ -14,4 +14,151 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
+//! Storage migrations for the parachain-staking pallet.
+
 extern crate alloc;
+
+use crate::{
+	types::{
+		BondWithAutoCompound, CandidateBondLessRequest, CandidateMetadata, CapacityStatus,
+		CollatorSnapshot, CollatorStatus,
+	},
+	AtStake, BalanceOf, CandidateInfo, CollatorCommission, Config, DelayedPayouts, Pallet,
+};
+use alloc::collections::BTreeMap;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_runtime::Saturating;
+
+/// Candidate metadata before the commission rate of candidates
+#[derive(Encode, Decode)]
+struct OldCandidateMetadata<Balance> {
+	bond: Balance,
+	delegation_count: u32,
+	total_counted: Balance,
+	lowest_top_delegation_amount: Balance,
+	highest_bottom_delegation_amount: Balance,
+	lowest_bottom_delegation_amount: Balance,
+	top_capacity: CapacityStatus,
+	bottom_capacity: CapacityStatus,
+	request: Option<CandidateBondLessRequest<Balance>>,
+	status: CollatorStatus,
+}
+
+/// Collator snapshot before the commission rate of candidates
+#[derive(Encode, Decode)]
+struct OldCollatorSnapshot<AccountId, Balance> {
+	bond: Balance,
+	delegations: alloc::vec::Vec<BondWithAutoCompound<AccountId, Balance>>,
+	total: Balance,
+}
+
+/// Adds the commission rate fields to the [CandidateInfo] of all candidates, which keep the
+/// global commission rate, and the commission rate to the [AtStake] snapshots, which is the
+/// global commission rate of the round.
+pub struct UncheckedMigrateCandidateCommission<T>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateCandidateCommission<T> {
+	fn on_runtime_upgrade() -> Weight {
+		// CollatorCommission
+		let mut reads = 1u64;
+		let mut writes = 0u64;
+		// every entry read is part of the proof
+		let mut proof_size = 0u64;
+		<CandidateInfo<T>>::translate::<OldCandidateMetadata<BalanceOf<T>>, _>(|candidate, old| {
+			reads.saturating_inc();
+			writes.saturating_inc();
+			proof_size.saturating_accrue(
+				(<CandidateInfo<T>>::hashed_key_for(&candidate).len() + old.encoded_size()) as u64,
+			);
+			Some(CandidateMetadata {
+				bond: old.bond,
+				delegation_count: old.delegation_count,
+				total_counted: old.total_counted,
+				lowest_top_delegation_amount: old.lowest_top_delegation_amount,
+				highest_bottom_delegation_amount: old.highest_bottom_delegation_amount,
+				lowest_bottom_delegation_amount: old.lowest_bottom_delegation_amount,
+				top_capacity: old.top_capacity,
+				bottom_capacity: old.bottom_capacity,
+				request: old.request,
+				status: old.status,
+				commission: None,
+				commission_request: None,
+			})
+		});
+		let global = <CollatorCommission<T>>::get();
+		// commission rate of the payout of each round, read once per round
+		let mut round_commissions = BTreeMap::new();
+		<AtStake<T>>::translate::<OldCollatorSnapshot<T::AccountId, BalanceOf<T>>, _>(
+			|round, collator, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				proof_size.saturating_accrue(
+					(<AtStake<T>>::hashed_key_for(round, &collator).len() + old.encoded_size())
+						as u64,
+				);
+				let commission = match round_commissions.get(&round) {
+					Some(commission) => *commission,
+					None => {
+						let payout = <DelayedPayouts<T>>::get(round);
+						reads.saturating_inc();
+						proof_size.saturating_accrue(
+							(<DelayedPayouts<T>>::hashed_key_for(round).len()
+								+ payout.encoded_size()) as u64,
+						);
+						let commission = payout.map_or(global, |payout| payout.collator_commission);
+						round_commissions.insert(round, commission);
+						commission
+					}
+				};
+				Some(CollatorSnapshot {
+					bond: old.bond,
+					delegations: old.delegations,
+					total: old.total,
+					commission,
+				})
+			},
+		);
+		T::DbWeight::get()
+			.reads_writes(reads, writes)
+			.saturating_add(Weight::from_parts(0, proof_size))
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		Ok((
+			<CandidateInfo<T>>::iter_keys().count() as u32,
+			<AtStake<T>>::iter_keys().count() as u32,
+		)
+			.encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		let (candidates_before, snapshots_before) = <(u32, u32)>::decode(&mut state.as_ref())
+			.map_err(|_| "MigrateCandidateCommission: invalid pre-upgrade state")?;
+		// fails if any candidate or snapshot could not be decoded
+		let candidates_after = <CandidateInfo<T>>::iter_values().count() as u32;
+		ensure!(
+			candidates_before == candidates_after,
+			"MigrateCandidateCommission: candidates lost"
+		);
+		let snapshots_after = <AtStake<T>>::iter_values().count() as u32;
+		ensure!(
+			snapshots_before == snapshots_after,
+			"MigrateCandidateCommission: snapshots lost"
+		);
+		Ok(())
+	}
+}
+
+/// Migrates [CandidateInfo] from storage version 0 to 1, see
+/// [UncheckedMigrateCandidateCommission].
+pub type MigrateCandidateCommission<T> = VersionedMigration<
+	0,
+	1,
+	UncheckedMigrateCandidateCommission<T>,
+	Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -26,6 +26,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{fungible::InspectFreeze, Everything, Get, OnFinalize, OnInitialize, VariantCountOf},
 	weights::{constants::RocksDbWeight, Weight},
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_consensus_slots::Slot;
@@ -49,7 +50,7 @@ construct_runtime!(
 	{
 		System: frame_system,
 		Balances: pallet_balances,
-		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, FreezeReason},
+		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, FreezeReason, HoldReason},
 		BlockAuthor: block_author,
 	}
 );
@@ -106,7 +107,7 @@ impl pallet_balances::Config for Test {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<Self::RuntimeFreezeReason>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -126,6 +127,7 @@ parameter_types! {
 	pub const MaxOfflineRounds: u32 = 2;
 	pub const LeaveCandidatesDelay: u32 = 2;
 	pub const CandidateBondLessDelay: u32 = 2;
+	pub const CandidateCommissionChangeDelay: u32 = 2;
 	pub const LeaveDelegatorsDelay: u32 = 2;
 	pub const RevokeDelegationDelay: u32 = 2;
 	pub const DelegationBondLessDelay: u32 = 2;
@@ -150,20 +152,35 @@ impl Get<Slot> for StakingRoundSlotProvider {
 parameter_types! {
 	pub const LinearInflationThreshold: Option<Balance> = Some(1_200_000_000);
 	pub const MaxScheduledRequestsPerDelegator: u32 = 50;
+	pub const RewardHistoryRounds: u32 = 2;
+	pub const SelectionHistoryRounds: u32 = 2;
+	pub const RedelegationCooldown: u32 = 2;
+	pub const SlashDeferDuration: u32 = 1;
+	pub const PoolsPalletId: PalletId = PalletId(*b"pc/stkpl");
+	pub const MaxPoolCandidates: u32 = 3;
+	pub const CandidateProfileDeposit: u128 = 10;
+	pub const MaxCandidateProfileFieldLength: u32 = 32;
 }
 
 impl Config for Test {
 	type Currency = Balances;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashingOrigin = frame_system::EnsureRoot<AccountId>;
 	type MinBlocksPerRound = MinBlocksPerRound;
 	type MaxOfflineRounds = MaxOfflineRounds;
 	type LeaveCandidatesDelay = LeaveCandidatesDelay;
 	type CandidateBondLessDelay = CandidateBondLessDelay;
+	type CandidateCommissionChangeDelay = CandidateCommissionChangeDelay;
 	type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
 	type RevokeDelegationDelay = RevokeDelegationDelay;
+	type RedelegationCooldown = RedelegationCooldown;
+	type SlashDeferDuration = SlashDeferDuration;
 	type DelegationBondLessDelay = DelegationBondLessDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
+	type RewardHistoryRounds = RewardHistoryRounds;
+	type SelectionHistoryRounds = SelectionHistoryRounds;
 	type MinSelectedCandidates = MinSelectedCandidates;
 	type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
 	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
@@ -176,9 +193,14 @@ impl Config for Test {
 	type PayoutCollatorReward = ();
 	type OnInactiveCollator = ();
 	type OnNewRound = ();
+	type OnPoolCreated = ();
 	type SlotProvider = StakingRoundSlotProvider;
 	type WeightInfo = ();
 	type MaxCandidates = MaxCandidates;
+	type PoolsPalletId = PoolsPalletId;
+	type MaxPoolCandidates = MaxPoolCandidates;
+	type CandidateProfileDeposit = CandidateProfileDeposit;
+	type MaxCandidateProfileFieldLength = MaxCandidateProfileFieldLength;
 	type SlotDuration = frame_support::traits::ConstU64<6_000>;
 	type BlockTime = frame_support::traits::ConstU64<6_000>;
 	type LinearInflationThreshold = LinearInflationThreshold;
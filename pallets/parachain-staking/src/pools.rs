@@ -0,0 +1,712 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Delegation pools.
+//!
+//! A pool is an account derived from `T::PoolsPalletId` which delegates the stake of its members
+//! across the candidates of the pool, with rewards fully auto-compounded. Members own shares of
+//! the pool stake, which can be transferred like a token.
+//!
+//! Idle stake of a pool is delegated to its eligible candidate with the lowest pool delegation,
+//! and delegations to candidates which are offline, not selected or removed from the pool are
+//! revoked, when members join and when the pool is rebalanced.
+//!
+//! The stake of a pool which is not delegated is tracked in storage rather than read from the
+//! balance of the pool account, so that funds transferred to the pool account are not accounted
+//! as stake of the members. Shares are minted and burnt against one virtual share worth one
+//! virtual unit of stake, which keeps the rounding of the first shares from being exploited.
+
+use crate::auto_compound::AutoCompoundDelegations;
+use crate::delegation_requests::DelegationAction;
+use crate::pallet::{
+	BalanceOf, CandidateInfo, Config, DelegationScheduledRequests, DelegatorState, Error, Event,
+	NextPoolId, NextPoolToRebalance, Pallet, PoolShareApprovals, PoolShares, PoolUnbondings, Pools,
+	Round, RoundIndex,
+};
+use crate::weights::WeightInfo;
+use frame_support::dispatch::DispatchResult;
+use frame_support::ensure;
+use frame_support::pallet_prelude::Weight;
+use frame_support::storage::with_storage_layer;
+use frame_support::traits::{fungible::Mutate, tokens::Preservation, Get};
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational_with_rounding,
+	traits::{
+		AccountIdConversion, One, Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero,
+	},
+	DispatchError, Percent, Rounding, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Identifier of a delegation pool
+pub type PoolId = u32;
+
+/// Delegation pool
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Pool<AccountId, Balance> {
+	/// Candidates the stake of the pool is delegated to
+	pub candidates: Vec<AccountId>,
+	/// Total amount of shares of the pool
+	pub total_shares: Balance,
+	/// Stake unbonded by members and not withdrawn yet
+	pub unbonding: Balance,
+	/// Stake of the pool which is not delegated, stake unbonded and not withdrawn yet included
+	pub free: Balance,
+	/// Stake scheduled to be undelegated which has not been added to `free` yet
+	pub undelegating: Balance,
+}
+
+/// Stake unbonded by a member from a pool
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct PoolUnbonding<Balance> {
+	pub amount: Balance,
+	/// Round from which the stake can be withdrawn
+	pub when_executable: RoundIndex,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account delegating the stake of `pool`
+	pub fn pool_account(pool: PoolId) -> T::AccountId {
+		T::PoolsPalletId::get().into_sub_account_truncating(pool)
+	}
+
+	/// Stake of the pool members: delegated stake, rewards included, and idle stake
+	pub fn pool_value(pool: PoolId) -> BalanceOf<T> {
+		<Pools<T>>::get(pool)
+			.map(|mut info| {
+				let account = Self::pool_account(pool);
+				Self::pool_sync_undelegated(&account, &mut info);
+				Self::pool_value_of(&account, &info)
+			})
+			.unwrap_or_default()
+	}
+
+	/// Amount of stake `shares` of `pool` are worth
+	pub fn pool_shares_value(pool: PoolId, shares: BalanceOf<T>) -> BalanceOf<T> {
+		<Pools<T>>::get(pool)
+			.map(|mut info| {
+				let account = Self::pool_account(pool);
+				Self::pool_sync_undelegated(&account, &mut info);
+				Self::pool_shares_to_stake(shares, Self::pool_value_of(&account, &info), &info)
+			})
+			.unwrap_or_default()
+	}
+
+	fn pool_value_of(
+		account: &T::AccountId,
+		info: &Pool<T::AccountId, BalanceOf<T>>,
+	) -> BalanceOf<T> {
+		let delegated = <DelegatorState<T>>::get(account)
+			.map(|state| state.total())
+			.unwrap_or_default();
+		// stake being unbonded is still delegated or not withdrawn yet
+		delegated
+			.saturating_add(info.free)
+			.saturating_sub(info.unbonding)
+	}
+
+	/// Stake of the pool which is neither delegated nor unbonded
+	fn pool_idle(info: &Pool<T::AccountId, BalanceOf<T>>) -> BalanceOf<T> {
+		info.free.saturating_sub(info.unbonding)
+	}
+
+	/// Adds the stake of the executed undelegations of a pool to its free stake. Requests may be
+	/// executed by anyone, so the stake still scheduled to be undelegated is read from the
+	/// scheduled requests. The free stake never exceeds the stakable balance of the pool account,
+	/// which accounts for the delegations slashed while being undelegated.
+	fn pool_sync_undelegated(account: &T::AccountId, info: &mut Pool<T::AccountId, BalanceOf<T>>) {
+		let pending = <DelegatorState<T>>::get(account)
+			.map(|state| {
+				state
+					.delegations
+					.0
+					.iter()
+					.flat_map(|bond| <DelegationScheduledRequests<T>>::get(&bond.owner, account))
+					.fold(BalanceOf::<T>::zero(), |acc, request| {
+						let amount = match request.action {
+							DelegationAction::Decrease(less) => less,
+							DelegationAction::Revoke(amount) => amount,
+						};
+						acc.saturating_add(amount)
+					})
+			})
+			.unwrap_or_default();
+		let returned = info.undelegating.saturating_sub(pending);
+		info.free = info
+			.free
+			.saturating_add(returned)
+			.min(Self::get_delegator_stakable_balance(account));
+		info.undelegating = pending;
+	}
+
+	/// Shares minted for `amount` of stake joining a pool worth `value`
+	fn pool_stake_to_shares(
+		amount: BalanceOf<T>,
+		value: BalanceOf<T>,
+		info: &Pool<T::AccountId, BalanceOf<T>>,
+	) -> BalanceOf<T> {
+		Self::pool_convert(
+			amount,
+			info.total_shares.saturating_add(One::one()),
+			value.saturating_add(One::one()),
+		)
+	}
+
+	/// Stake `shares` of a pool worth `value` are worth
+	fn pool_shares_to_stake(
+		shares: BalanceOf<T>,
+		value: BalanceOf<T>,
+		info: &Pool<T::AccountId, BalanceOf<T>>,
+	) -> BalanceOf<T> {
+		Self::pool_convert(
+			shares,
+			value.saturating_add(One::one()),
+			info.total_shares.saturating_add(One::one()),
+		)
+	}
+
+	/// Returns `amount * numerator / denominator`, rounded down, zero if `denominator` is zero
+	fn pool_convert(
+		amount: BalanceOf<T>,
+		numerator: BalanceOf<T>,
+		denominator: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		multiply_by_rational_with_rounding(
+			amount.unique_saturated_into(),
+			numerator.unique_saturated_into(),
+			denominator.unique_saturated_into(),
+			Rounding::Down,
+		)
+		.map(BalanceOf::<T>::unique_saturated_from)
+		.unwrap_or_default()
+	}
+
+	/// Ensures `candidates` is a valid set of candidates for a pool
+	pub(crate) fn ensure_valid_pool_candidates(candidates: &[T::AccountId]) -> DispatchResult {
+		ensure!(!candidates.is_empty(), Error::<T>::InvalidPoolCandidates);
+		ensure!(
+			candidates.len() as u32 <= T::MaxPoolCandidates::get(),
+			Error::<T>::TooManyPoolCandidates
+		);
+		for (i, candidate) in candidates.iter().enumerate() {
+			ensure!(
+				!candidates[..i].contains(candidate),
+				Error::<T>::InvalidPoolCandidates
+			);
+			ensure!(Self::is_candidate(candidate), Error::<T>::CandidateDNE);
+		}
+		Ok(())
+	}
+
+	/// Whether the pool stake can be delegated to `candidate`
+	fn is_pool_candidate_eligible(candidate: &T::AccountId) -> bool {
+		<CandidateInfo<T>>::get(candidate).map_or(false, |info| info.is_active())
+			&& Self::is_selected_candidate(candidate)
+	}
+
+	pub(crate) fn join_pool_inner(
+		member: T::AccountId,
+		pool: PoolId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		let mut info = <Pools<T>>::get(pool).ok_or(Error::<T>::PoolDNE)?;
+		let account = Self::pool_account(pool);
+		Self::pool_sync_undelegated(&account, &mut info);
+		let shares =
+			Self::pool_stake_to_shares(amount, Self::pool_value_of(&account, &info), &info);
+		ensure!(!shares.is_zero(), Error::<T>::ZeroPoolShares);
+
+		T::Currency::transfer(&member, &account, amount, Preservation::Preserve)?;
+		info.free = info.free.saturating_add(amount);
+		info.total_shares = info.total_shares.saturating_add(shares);
+		<PoolShares<T>>::mutate(pool, &member, |owned| *owned = owned.saturating_add(shares));
+		Self::deposit_event(Event::JoinedPool {
+			pool,
+			member,
+			amount,
+			shares,
+		});
+
+		Self::pool_delegate_idle(pool, &account, &mut info);
+		<Pools<T>>::insert(pool, info);
+		Ok(())
+	}
+
+	pub(crate) fn unbond_from_pool_inner(
+		member: T::AccountId,
+		pool: PoolId,
+		shares: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(!shares.is_zero(), Error::<T>::ZeroPoolShares);
+		let mut info = <Pools<T>>::get(pool).ok_or(Error::<T>::PoolDNE)?;
+		let owned = <PoolShares<T>>::get(pool, &member);
+		ensure!(owned >= shares, Error::<T>::InsufficientPoolShares);
+
+		let account = Self::pool_account(pool);
+		Self::pool_sync_undelegated(&account, &mut info);
+		let amount =
+			Self::pool_shares_to_stake(shares, Self::pool_value_of(&account, &info), &info);
+		// idle stake is unbonded right away, the rest is undelegated
+		let to_undelegate = amount.saturating_sub(Self::pool_idle(&info));
+		let now = <Round<T>>::get().current;
+		let when_executable = if to_undelegate.is_zero() {
+			now
+		} else {
+			let scheduled = Self::pool_undelegate(&account, to_undelegate)?;
+			info.undelegating = info.undelegating.saturating_add(scheduled);
+			now.saturating_add(
+				T::RevokeDelegationDelay::get().max(T::DelegationBondLessDelay::get()),
+			)
+		};
+
+		info.total_shares = info.total_shares.saturating_sub(shares);
+		info.unbonding = info.unbonding.saturating_add(amount);
+		<Pools<T>>::insert(pool, info);
+		if owned == shares {
+			<PoolShares<T>>::remove(pool, &member);
+		} else {
+			<PoolShares<T>>::insert(pool, &member, owned.saturating_sub(shares));
+		}
+		<PoolUnbondings<T>>::mutate(pool, &member, |unbonding| {
+			*unbonding = Some(match unbonding.take() {
+				Some(pending) => PoolUnbonding {
+					amount: pending.amount.saturating_add(amount),
+					when_executable: pending.when_executable.max(when_executable),
+				},
+				None => PoolUnbonding {
+					amount,
+					when_executable,
+				},
+			})
+		});
+
+		Self::deposit_event(Event::UnbondedFromPool {
+			pool,
+			member,
+			shares,
+			amount,
+			when_executable,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn withdraw_unbonded_from_pool_inner(
+		member: T::AccountId,
+		pool: PoolId,
+	) -> DispatchResult {
+		let mut info = <Pools<T>>::get(pool).ok_or(Error::<T>::PoolDNE)?;
+		let unbonding =
+			<PoolUnbondings<T>>::get(pool, &member).ok_or(Error::<T>::PoolUnbondingDNE)?;
+		ensure!(
+			unbonding.when_executable <= <Round<T>>::get().current,
+			Error::<T>::PoolUnbondingNotDueYet
+		);
+
+		let account = Self::pool_account(pool);
+		Self::pool_execute_matured_requests(&account);
+		Self::pool_sync_undelegated(&account, &mut info);
+		ensure!(
+			info.free >= unbonding.amount,
+			Error::<T>::InsufficientPoolLiquidity
+		);
+		T::Currency::transfer(
+			&account,
+			&member,
+			unbonding.amount,
+			Preservation::Expendable,
+		)?;
+
+		info.free = info.free.saturating_sub(unbonding.amount);
+		info.unbonding = info.unbonding.saturating_sub(unbonding.amount);
+		<Pools<T>>::insert(pool, info);
+		<PoolUnbondings<T>>::remove(pool, &member);
+		Self::deposit_event(Event::WithdrawnFromPool {
+			pool,
+			member,
+			amount: unbonding.amount,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn transfer_pool_shares_inner(
+		pool: PoolId,
+		from: T::AccountId,
+		to: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		ensure!(<Pools<T>>::contains_key(pool), Error::<T>::PoolDNE);
+		let owned = <PoolShares<T>>::get(pool, &from);
+		ensure!(owned >= amount, Error::<T>::InsufficientPoolShares);
+		if from != to && !amount.is_zero() {
+			if owned == amount {
+				<PoolShares<T>>::remove(pool, &from);
+			} else {
+				<PoolShares<T>>::insert(pool, &from, owned.saturating_sub(amount));
+			}
+			<PoolShares<T>>::mutate(pool, &to, |owned| *owned = owned.saturating_add(amount));
+		}
+		Self::deposit_event(Event::PoolSharesTransferred {
+			pool,
+			from,
+			to,
+			amount,
+		});
+		Ok(())
+	}
+
+	pub(crate) fn transfer_pool_shares_from_inner(
+		spender: T::AccountId,
+		pool: PoolId,
+		owner: T::AccountId,
+		to: T::AccountId,
+		amount: BalanceOf<T>,
+	) -> DispatchResult {
+		if spender != owner {
+			<PoolShareApprovals<T>>::try_mutate((pool, &owner, &spender), |allowance| {
+				ensure!(
+					*allowance >= amount,
+					Error::<T>::PoolSharesAllowanceExceeded
+				);
+				*allowance = allowance.saturating_sub(amount);
+				Ok::<(), Error<T>>(())
+			})?;
+		}
+		Self::transfer_pool_shares_inner(pool, owner, to, amount)
+	}
+
+	/// Executes the matured requests of the delegations of a pool, so that the unbonded stake can
+	/// be withdrawn and the stake of revoked delegations delegated again.
+	fn pool_execute_matured_requests(account: &T::AccountId) -> Weight {
+		let max_requests =
+			T::MaxPoolCandidates::get().saturating_mul(T::MaxScheduledRequestsPerDelegator::get());
+		match Self::delegation_execute_matured_requests(account.clone(), max_requests) {
+			Ok(post_info) => post_info.actual_weight.unwrap_or_default(),
+			Err(err) => err.post_info.actual_weight.unwrap_or_default(),
+		}
+	}
+
+	/// Schedules the undelegation of `amount` from the delegations of a pool. The smallest
+	/// delegations are revoked while decreasing the others down to `T::MinDelegation` is not
+	/// enough; the stake revoked beyond `amount` is delegated again once the revocation is
+	/// executed. Returns the amount scheduled to be undelegated.
+	fn pool_undelegate(
+		account: &T::AccountId,
+		amount: BalanceOf<T>,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		let state =
+			<DelegatorState<T>>::get(account).ok_or(Error::<T>::PoolUnbondingUnavailable)?;
+		// (candidate, bonded amount, amount it can be decreased by, whether it can be revoked)
+		let mut bonds: Vec<(T::AccountId, BalanceOf<T>, BalanceOf<T>, bool)> = state
+			.delegations
+			.0
+			.into_iter()
+			.map(|bond| {
+				let requests = <DelegationScheduledRequests<T>>::get(&bond.owner, account);
+				let mut pending_decrease = BalanceOf::<T>::zero();
+				for request in requests.iter() {
+					match request.action {
+						DelegationAction::Decrease(less) => {
+							pending_decrease = pending_decrease.saturating_add(less)
+						}
+						DelegationAction::Revoke(_) => pending_decrease = bond.amount,
+					}
+				}
+				let decreasable = bond
+					.amount
+					.saturating_sub(pending_decrease)
+					.saturating_sub(T::MinDelegation::get());
+				(bond.owner, bond.amount, decreasable, requests.is_empty())
+			})
+			.collect();
+		bonds.sort_by(|a, b| a.1.cmp(&b.1));
+
+		let mut revoked = BalanceOf::<T>::zero();
+		let mut decreasable = bonds.iter().fold(BalanceOf::<T>::zero(), |acc, bond| {
+			acc.saturating_add(bond.2)
+		});
+		for (candidate, bonded, can_decrease, can_revoke) in bonds.iter_mut() {
+			if revoked.saturating_add(decreasable) >= amount {
+				break;
+			}
+			if !*can_revoke {
+				continue;
+			}
+			Self::delegation_schedule_revoke(candidate.clone(), account.clone())
+				.map_err(|err| err.error)?;
+			revoked = revoked.saturating_add(*bonded);
+			decreasable = decreasable.saturating_sub(*can_decrease);
+			*can_decrease = Zero::zero();
+		}
+
+		let mut remaining = amount.saturating_sub(revoked);
+		for (candidate, _, can_decrease, _) in bonds.iter().rev() {
+			if remaining.is_zero() {
+				break;
+			}
+			let less = (*can_decrease).min(remaining);
+			if less.is_zero() {
+				continue;
+			}
+			Self::delegation_schedule_bond_decrease(candidate.clone(), account.clone(), less)
+				.map_err(|err| err.error)?;
+			remaining = remaining.saturating_sub(less);
+		}
+
+		ensure!(remaining.is_zero(), Error::<T>::PoolUnbondingUnavailable);
+		Ok(revoked.max(amount))
+	}
+
+	/// Delegates the idle stake of a pool to its eligible candidate with the lowest pool
+	/// delegation. Returns the amount delegated.
+	fn pool_delegate_idle(
+		pool: PoolId,
+		account: &T::AccountId,
+		info: &mut Pool<T::AccountId, BalanceOf<T>>,
+	) -> BalanceOf<T> {
+		let idle = Self::pool_idle(info);
+		if idle.is_zero() {
+			return idle;
+		}
+		let state = <DelegatorState<T>>::get(account);
+		let delegation_count = state
+			.as_ref()
+			.map(|state| state.delegations.0.len() as u32)
+			.unwrap_or_default();
+
+		let mut target: Option<(T::AccountId, Option<BalanceOf<T>>)> = None;
+		for candidate in info.candidates.iter() {
+			if !Self::is_pool_candidate_eligible(candidate) {
+				continue;
+			}
+			let bonded = state
+				.as_ref()
+				.and_then(|state| state.get_bond_amount(candidate));
+			let can_delegate = match bonded {
+				Some(_) => !Self::delegation_request_revoke_exists(candidate, account),
+				None => {
+					idle >= T::MinDelegation::get()
+						&& delegation_count < T::MaxDelegationsPerDelegator::get()
+				}
+			};
+			let is_lower = match (&target, bonded) {
+				(None, _) => true,
+				(Some((_, lowest)), bonded) => {
+					bonded.unwrap_or_default() < lowest.unwrap_or_default()
+				}
+			};
+			if can_delegate && is_lower {
+				target = Some((candidate.clone(), bonded));
+			}
+		}
+		let Some((candidate, bonded)) = target else {
+			return Zero::zero();
+		};
+
+		let result = with_storage_layer(|| match bonded {
+			Some(_) => {
+				let (in_top, _) = Self::delegation_bond_more_without_event(
+					account.clone(),
+					candidate.clone(),
+					idle,
+				)
+				.map_err(|err| err.error)?;
+				Self::deposit_event(Event::DelegationIncreased {
+					delegator: account.clone(),
+					candidate: candidate.clone(),
+					amount: idle,
+					in_top,
+				});
+				Ok(())
+			}
+			None => {
+				let candidate_delegation_count = <CandidateInfo<T>>::get(&candidate)
+					.map(|info| info.delegation_count)
+					.unwrap_or_default();
+				<AutoCompoundDelegations<T>>::delegate_with_auto_compound(
+					candidate.clone(),
+					account.clone(),
+					idle,
+					Percent::one(),
+					candidate_delegation_count,
+					<AutoCompoundDelegations<T>>::get_auto_compounding_delegation_count(&candidate)
+						as u32,
+					delegation_count,
+				)
+				.map(|_| ())
+				.map_err(|err| err.error)
+			}
+		});
+		match result {
+			Ok(()) => {
+				info.free = info.free.saturating_sub(idle);
+				idle
+			}
+			Err(err) => {
+				log::warn!(
+					"failed to delegate idle stake of pool {:?} to {:?}: {:?}",
+					pool,
+					candidate,
+					err
+				);
+				Zero::zero()
+			}
+		}
+	}
+
+	/// Revokes the delegations of a pool to candidates which are not eligible or not in the pool
+	/// anymore, and delegates its idle stake. Returns the number of revoked delegations and the
+	/// amount delegated.
+	pub(crate) fn rebalance_pool_inner(pool: PoolId) -> Result<(u32, BalanceOf<T>), Error<T>> {
+		let mut info = <Pools<T>>::get(pool).ok_or(Error::<T>::PoolDNE)?;
+		let account = Self::pool_account(pool);
+		Self::pool_execute_matured_requests(&account);
+		Self::pool_sync_undelegated(&account, &mut info);
+
+		let mut revoked = 0u32;
+		let delegations = <DelegatorState<T>>::get(&account)
+			.map(|state| state.delegations.0)
+			.unwrap_or_default();
+		for bond in delegations {
+			let is_ineligible = !info.candidates.contains(&bond.owner)
+				|| !Self::is_pool_candidate_eligible(&bond.owner);
+			if !is_ineligible
+				|| !<DelegationScheduledRequests<T>>::get(&bond.owner, &account).is_empty()
+			{
+				continue;
+			}
+			match with_storage_layer(|| {
+				Self::delegation_schedule_revoke(bond.owner.clone(), account.clone())
+					.map_err(|err| err.error)
+			}) {
+				Ok(_) => {
+					revoked = revoked.saturating_add(1);
+					info.undelegating = info.undelegating.saturating_add(bond.amount);
+				}
+				Err(err) => log::warn!(
+					"failed to revoke delegation of pool {:?} to {:?}: {:?}",
+					pool,
+					bond.owner,
+					err
+				),
+			}
+		}
+
+		let delegated = Self::pool_delegate_idle(pool, &account, &mut info);
+		<Pools<T>>::insert(pool, info);
+		Self::deposit_event(Event::PoolRebalanced {
+			pool,
+			revoked,
+			delegated,
+		});
+		Ok((revoked, delegated))
+	}
+
+	/// Upper bound of the weight of [Self::join_pool_inner]
+	pub(crate) fn join_pool_weight() -> Weight {
+		Self::pool_delegate_weight()
+			.saturating_add(Self::pool_sync_undelegated_weight())
+			.saturating_add(T::DbWeight::get().reads_writes(6, 5))
+	}
+
+	/// Upper bound of the weight of [Self::unbond_from_pool_inner]
+	pub(crate) fn unbond_from_pool_weight() -> Weight {
+		let max_candidates = T::MaxPoolCandidates::get();
+		let max_requests = T::MaxScheduledRequestsPerDelegator::get();
+		<T as Config>::WeightInfo::schedule_delegator_bond_less(max_requests)
+			.saturating_add(<T as Config>::WeightInfo::schedule_revoke_delegation(
+				max_requests,
+			))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_mul(max_candidates.into())
+			.saturating_add(Self::pool_sync_undelegated_weight())
+			.saturating_add(T::DbWeight::get().reads_writes(6, 4))
+	}
+
+	/// Upper bound of the weight of [Self::withdraw_unbonded_from_pool_inner]
+	pub(crate) fn withdraw_unbonded_from_pool_weight() -> Weight {
+		Self::pool_execute_matured_requests_weight()
+			.saturating_add(Self::pool_sync_undelegated_weight())
+			.saturating_add(T::DbWeight::get().reads_writes(6, 5))
+	}
+
+	fn pool_sync_undelegated_weight() -> Weight {
+		// DelegatorState, DelegationScheduledRequests of each delegation and stakable balance
+		T::DbWeight::get().reads(
+			T::MaxDelegationsPerDelegator::get()
+				.saturating_add(2)
+				.into(),
+		)
+	}
+
+	fn pool_execute_matured_requests_weight() -> Weight {
+		<T as Config>::WeightInfo::execute_delegator_revoke_delegation_worst().saturating_mul(
+			T::MaxPoolCandidates::get()
+				.saturating_mul(T::MaxScheduledRequestsPerDelegator::get())
+				.into(),
+		)
+	}
+
+	fn pool_delegate_weight() -> Weight {
+		let max_delegations = T::MaxTopDelegationsPerCandidate::get()
+			.saturating_add(T::MaxBottomDelegationsPerCandidate::get());
+		<T as Config>::WeightInfo::delegate_with_auto_compound(
+			max_delegations,
+			max_delegations,
+			T::MaxDelegationsPerDelegator::get(),
+		)
+		.saturating_add(
+			T::DbWeight::get().reads(T::MaxPoolCandidates::get().saturating_mul(3).into()),
+		)
+	}
+
+	/// Upper bound of the weight of [Self::rebalance_pool_inner]
+	pub(crate) fn rebalance_pool_weight() -> Weight {
+		let max_candidates = T::MaxPoolCandidates::get();
+		Self::pool_execute_matured_requests_weight()
+			.saturating_add(
+				<T as Config>::WeightInfo::schedule_revoke_delegation(
+					T::MaxScheduledRequestsPerDelegator::get(),
+				)
+				.saturating_add(T::DbWeight::get().reads(1))
+				.saturating_mul(max_candidates.into()),
+			)
+			.saturating_add(Self::pool_delegate_weight())
+			.saturating_add(Self::pool_sync_undelegated_weight())
+			.saturating_add(T::DbWeight::get().reads_writes(3, 1))
+	}
+
+	/// Rebalances the next pool, in a round-robin over all pools, if `remaining_weight` allows.
+	pub(crate) fn rebalance_next_pool_on_idle(remaining_weight: Weight) -> Weight {
+		// NextPoolId and NextPoolToRebalance reads and write
+		let mut used_weight = T::DbWeight::get().reads_writes(2, 1);
+		let rebalance_weight = Self::rebalance_pool_weight();
+		if remaining_weight.any_lt(used_weight.saturating_add(rebalance_weight)) {
+			return Weight::zero();
+		}
+		let pool_count = <NextPoolId<T>>::get();
+		if pool_count.is_zero() {
+			return T::DbWeight::get().reads(1);
+		}
+		let pool = <NextPoolToRebalance<T>>::get() % pool_count;
+		<NextPoolToRebalance<T>>::put(pool.saturating_add(1) % pool_count);
+		if Self::rebalance_pool_inner(pool).is_ok() {
+			used_weight = used_weight.saturating_add(rebalance_weight);
+		}
+		used_weight
+	}
+}
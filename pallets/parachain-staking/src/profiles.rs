@@ -0,0 +1,176 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Profiles of candidates.
+//!
+//! Candidates can publish a profile for wallets to render collator selection screens from chain
+//! data. `T::CandidateProfileDeposit` is held from the candidate for as long as its profile is
+//! stored, and released when the profile is cleared or the candidate leaves.
+
+use crate::pallet::{
+	BalanceOf, CandidateCommissionBounds, CandidateInfo, CandidateProfiles, Config, Error, Event,
+	HoldReason, Pallet,
+};
+use crate::weights::WeightInfo;
+use frame_support::dispatch::DispatchResult;
+use frame_support::pallet_prelude::Weight;
+use frame_support::traits::{fungible::MutateHold, tokens::Precision, Get};
+use frame_support::{
+	ensure, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	DispatchError, Perbill,
+};
+
+/// Self-declared information of a candidate
+#[derive(
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+	DecodeWithMemTracking,
+)]
+#[scale_info(skip_type_params(MaxFieldLength))]
+pub struct CandidateProfileInfo<MaxFieldLength: Get<u32>> {
+	pub display_name: BoundedVec<u8, MaxFieldLength>,
+	pub website: BoundedVec<u8, MaxFieldLength>,
+	pub contact: BoundedVec<u8, MaxFieldLength>,
+	/// Region the collator node is located in
+	pub region: BoundedVec<u8, MaxFieldLength>,
+	/// Commission the candidate intends to charge, informational only
+	pub commission_intent: Option<Perbill>,
+}
+
+impl<MaxFieldLength: Get<u32>> CandidateProfileInfo<MaxFieldLength> {
+	/// Length in bytes of the text fields
+	pub fn byte_len(&self) -> u32 {
+		(self.display_name.len() + self.website.len() + self.contact.len() + self.region.len())
+			as u32
+	}
+
+	/// Maximum length in bytes of the text fields
+	pub fn max_byte_len() -> u32 {
+		MaxFieldLength::get().saturating_mul(4)
+	}
+}
+
+/// Profile of a candidate along with the deposit held for it
+#[derive(
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxFieldLength))]
+pub struct CandidateProfile<Balance: Clone + Eq + sp_std::fmt::Debug, MaxFieldLength: Get<u32>> {
+	pub deposit: Balance,
+	pub info: CandidateProfileInfo<MaxFieldLength>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Sets the profile of `candidate`, holding `T::CandidateProfileDeposit` from it. When the
+	/// profile is updated, only the difference with the deposit already held is held or released.
+	pub(crate) fn set_candidate_profile_inner(
+		candidate: T::AccountId,
+		info: CandidateProfileInfo<T::MaxCandidateProfileFieldLength>,
+	) -> DispatchResult {
+		ensure!(
+			<CandidateInfo<T>>::contains_key(&candidate),
+			Error::<T>::CandidateDNE
+		);
+		if let Some(commission) = info.commission_intent {
+			ensure!(
+				<CandidateCommissionBounds<T>>::get().contains(commission),
+				Error::<T>::CommissionOutOfBounds
+			);
+		}
+
+		let deposit = T::CandidateProfileDeposit::get();
+		let held = <CandidateProfiles<T>>::get(&candidate)
+			.map(|profile| profile.deposit)
+			.unwrap_or_else(Zero::zero);
+		if deposit > held {
+			T::Currency::hold(
+				&HoldReason::CandidateProfile.into(),
+				&candidate,
+				deposit.saturating_sub(held),
+			)?;
+		} else if held > deposit {
+			T::Currency::release(
+				&HoldReason::CandidateProfile.into(),
+				&candidate,
+				held.saturating_sub(deposit),
+				Precision::BestEffort,
+			)?;
+		}
+
+		<CandidateProfiles<T>>::insert(&candidate, CandidateProfile { deposit, info });
+		Self::deposit_event(Event::CandidateProfileSet { candidate, deposit });
+		Ok(())
+	}
+
+	/// Removes the profile of `candidate` and releases its deposit.
+	pub(crate) fn clear_candidate_profile_inner(
+		candidate: T::AccountId,
+	) -> Result<Weight, DispatchError> {
+		ensure!(
+			<CandidateProfiles<T>>::contains_key(&candidate),
+			Error::<T>::CandidateProfileDNE
+		);
+		Ok(Self::remove_candidate_profile(candidate))
+	}
+
+	/// Removes the profile of `candidate`, if any, and releases its deposit.
+	pub(crate) fn remove_candidate_profile(candidate: T::AccountId) -> Weight {
+		let Some(profile) = <CandidateProfiles<T>>::take(&candidate) else {
+			return T::DbWeight::get().reads(1);
+		};
+		let released = Self::release_profile_deposit(&candidate, profile.deposit);
+		Self::deposit_event(Event::CandidateProfileCleared {
+			candidate,
+			deposit: released,
+		});
+		<T as Config>::WeightInfo::clear_candidate_profile(profile.info.byte_len())
+	}
+
+	fn release_profile_deposit(candidate: &T::AccountId, deposit: BalanceOf<T>) -> BalanceOf<T> {
+		T::Currency::release(
+			&HoldReason::CandidateProfile.into(),
+			candidate,
+			deposit,
+			Precision::BestEffort,
+		)
+		.unwrap_or_else(|err| {
+			log::warn!(
+				"failed to release profile deposit of {:?}: {:?}",
+				candidate,
+				err
+			);
+			Zero::zero()
+		})
+	}
+}
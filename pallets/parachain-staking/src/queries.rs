@@ -0,0 +1,299 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Read-only views of the staking state, served by the `ParachainStakingApi` runtime API
+
+use crate::pallet::{
+	AwardedPts, BalanceOf, BottomDelegations, CandidateInfo, CollatorCommission, Config,
+	DelegationScheduledRequests, DelegationScheduledRequestsPerCollator, DelegatorState,
+	InflationDistributionInfo, Pallet, Points, RewardHistory, Round, SelectedCandidates,
+	SelectionHistory, TopDelegations,
+};
+use crate::{
+	auto_compound::AutoCompoundDelegations,
+	inflation::rounds_per_year,
+	types::{
+		CandidateBondLessRequest, CollatorStatus, CountedDelegations, DelayedPayout,
+		DelegatorReward, SelectedCollator,
+	},
+	RoundIndex, ScheduledRequest,
+};
+use frame_support::traits::Get;
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{SaturatedConversion, Saturating, Zero},
+	Perbill, Percent, RuntimeDebug,
+};
+use sp_std::prelude::*;
+
+/// Placement of a delegation among the delegations of its candidate
+#[derive(Clone, Copy, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum DelegationPlacement {
+	/// Counted in the backing of the candidate and rewarded
+	Top,
+	/// Neither counted nor rewarded until it moves to the top delegations
+	Bottom,
+}
+
+/// A delegation of a delegator, as seen from the delegator
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegationPosition<AccountId, Balance> {
+	pub candidate: AccountId,
+	pub amount: Balance,
+	pub auto_compound: Percent,
+	pub placement: DelegationPlacement,
+	/// Pending requests, in execution order
+	pub scheduled_requests: Vec<ScheduledRequest<Balance>>,
+}
+
+/// All the delegations of a delegator
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DelegatorPosition<AccountId, Balance> {
+	/// Total frozen balance of the delegator
+	pub total: Balance,
+	/// Sum of the pending revocation and decrease amounts
+	pub less_total: Balance,
+	pub delegations: Vec<DelegationPosition<AccountId, Balance>>,
+}
+
+/// Summary of a collator candidate
+#[derive(Clone, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct CollatorSummary<Balance> {
+	pub bond: Balance,
+	pub status: CollatorStatus,
+	/// Whether the candidate is selected to author blocks in the current round
+	pub is_selected: bool,
+	pub delegation_count: u32,
+	pub top_delegation_count: u32,
+	pub bottom_delegation_count: u32,
+	/// Self bond + sum of top delegations
+	pub total_counted: Balance,
+	/// Sum of bottom delegations
+	pub total_bottom: Balance,
+	pub lowest_top_delegation_amount: Balance,
+	pub highest_bottom_delegation_amount: Balance,
+	pub lowest_bottom_delegation_amount: Balance,
+	pub auto_compounding_delegation_count: u32,
+	/// Number of delegators with pending requests
+	pub scheduled_request_delegator_count: u32,
+	pub bond_less_request: Option<CandidateBondLessRequest<Balance>>,
+}
+
+/// Rewards projected for a stake delegated to a collator
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProjectedRewards<Balance> {
+	/// Whether the stake would enter the top delegations of the collator, and thus be rewarded
+	pub counted: bool,
+	/// Expected share of the points of a round awarded to the collator
+	pub points_share: Perbill,
+	/// Rewards of the collator for one round, commission included
+	pub collator_round_reward: Balance,
+	/// Rewards of the delegated stake for one round
+	pub delegation_round_reward: Balance,
+	/// Rewards of the delegated stake for one year
+	pub delegation_annual_reward: Balance,
+	/// Annual rewards of the delegated stake relative to the stake, in basis points
+	pub delegation_apr: u32,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Projects the rewards of a new delegation of `stake` to `collator`, or `None` if the account
+	/// is not a candidate.
+	///
+	/// Rounds are assumed to last their ideal duration, with the current inflation, inflation
+	/// distribution and commission. The collator is expected to get its share of the points of
+	/// the last round, or an equal share among the selected candidates if it got none.
+	/// Rewards are then split with the arithmetic of the payouts.
+	pub fn projected_rewards(
+		collator: &T::AccountId,
+		stake: BalanceOf<T>,
+	) -> Option<ProjectedRewards<BalanceOf<T>>> {
+		let info = <CandidateInfo<T>>::get(collator)?;
+		let round = <Round<T>>::get();
+
+		// Same as `prepare_staking_payouts` for a round of ideal duration
+		let round_issuance = Self::compute_issuance(
+			T::BlockTime::get().saturating_mul(round.length.into()),
+			round.length,
+		);
+		let mut total_staking_reward = round_issuance;
+		for config in <InflationDistributionInfo<T>>::get().0.iter() {
+			if frame_system::Pallet::<T>::account_exists(&config.account) {
+				total_staking_reward =
+					total_staking_reward.saturating_sub(config.percent * round_issuance);
+			}
+		}
+		let payout_info = DelayedPayout {
+			round_issuance,
+			total_staking_reward,
+			collator_commission: <CollatorCommission<T>>::get(),
+		};
+
+		let last_round = round.current.saturating_sub(1);
+		let last_round_points = <AwardedPts<T>>::get(last_round, collator);
+		let points_share = if last_round_points.is_zero() {
+			let selected = <SelectedCandidates<T>>::decode_len()
+				.unwrap_or_default()
+				.max(1);
+			Perbill::from_rational(1u32, selected as u32)
+		} else {
+			Perbill::from_rational(last_round_points, <Points<T>>::get(last_round))
+		};
+
+		// Same as the snapshot of `select_top_candidates`, with the new delegation
+		let CountedDelegations {
+			uncounted_stake,
+			rewardable_delegations,
+		} = Self::get_rewardable_delegators(collator);
+		let top_is_full =
+			rewardable_delegations.len() as u32 >= T::MaxTopDelegationsPerCandidate::get();
+		let counted = !top_is_full || stake > info.lowest_top_delegation_amount;
+		let mut total = info.total_counted.saturating_sub(uncounted_stake);
+		if counted {
+			total = total.saturating_add(stake);
+			if top_is_full {
+				total = total.saturating_sub(info.lowest_top_delegation_amount);
+			}
+		}
+
+		let commission = info.effective_commission::<T>(payout_info.collator_commission);
+		let (collator_round_reward, delegations_due) =
+			Self::split_collator_reward(points_share, &payout_info, commission, info.bond, total);
+		let delegation_round_reward = if counted {
+			Perbill::from_rational(stake, total) * delegations_due
+		} else {
+			Zero::zero()
+		};
+		let delegation_annual_reward =
+			delegation_round_reward.saturating_mul(rounds_per_year::<T>().into());
+
+		// Not a `Perbill`, which would cap the rate at 100%
+		let delegation_apr = if stake.is_zero() {
+			0
+		} else {
+			(delegation_annual_reward.saturating_mul(10_000u32.into()) / stake).saturated_into()
+		};
+
+		Some(ProjectedRewards {
+			counted,
+			points_share,
+			collator_round_reward,
+			delegation_round_reward,
+			delegation_annual_reward,
+			delegation_apr,
+		})
+	}
+
+	/// Returns the rewards paid to a delegator in the rounds kept in `RewardHistory`, from the
+	/// oldest to the most recent round.
+	pub fn delegator_reward_history(
+		delegator: &T::AccountId,
+	) -> Vec<(RoundIndex, DelegatorReward<T::AccountId, BalanceOf<T>>)> {
+		let retained = T::RewardHistoryRounds::get();
+		let last_paid = <Round<T>>::get()
+			.current
+			.saturating_sub(T::RewardPaymentDelay::get());
+		let first_kept = last_paid.saturating_sub(retained.saturating_sub(1)).max(1);
+		if retained.is_zero() || last_paid.is_zero() {
+			return Vec::new();
+		}
+
+		(first_kept..=last_paid)
+			.flat_map(|round| {
+				<RewardHistory<T>>::get(round, delegator)
+					.into_iter()
+					.map(move |reward| (round, reward))
+			})
+			.collect()
+	}
+
+	/// Returns the collators selected for `round`, ordered by account, empty if the round is not
+	/// kept in the selection history.
+	pub fn round_selection(round: RoundIndex) -> Vec<SelectedCollator<T::AccountId, BalanceOf<T>>> {
+		<SelectionHistory<T>>::get(round).into_inner()
+	}
+
+	/// Returns the delegations of a delegator, or `None` if the account is not delegating.
+	pub fn delegator_position(
+		delegator: &T::AccountId,
+	) -> Option<DelegatorPosition<T::AccountId, BalanceOf<T>>> {
+		let state = <DelegatorState<T>>::get(delegator)?;
+		let delegations = state
+			.delegations
+			.0
+			.into_iter()
+			.map(|bond| {
+				let is_top = <TopDelegations<T>>::get(&bond.owner)
+					.map(|top| top.delegations.iter().any(|d| &d.owner == delegator))
+					.unwrap_or_default();
+				DelegationPosition {
+					auto_compound: Self::delegation_auto_compound(&bond.owner, delegator),
+					placement: if is_top {
+						DelegationPlacement::Top
+					} else {
+						DelegationPlacement::Bottom
+					},
+					scheduled_requests: <DelegationScheduledRequests<T>>::get(
+						&bond.owner,
+						delegator,
+					)
+					.into_inner(),
+					candidate: bond.owner,
+					amount: bond.amount,
+				}
+			})
+			.collect();
+
+		Some(DelegatorPosition {
+			total: state.total,
+			less_total: state.less_total,
+			delegations,
+		})
+	}
+
+	/// Returns the summary of a collator candidate, or `None` if the account is not a candidate.
+	pub fn collator_summary(candidate: &T::AccountId) -> Option<CollatorSummary<BalanceOf<T>>> {
+		let info = <CandidateInfo<T>>::get(candidate)?;
+		let top_delegation_count = <TopDelegations<T>>::get(candidate)
+			.map(|top| top.delegations.len() as u32)
+			.unwrap_or_default();
+		let (bottom_delegation_count, total_bottom) = <BottomDelegations<T>>::get(candidate)
+			.map(|bottom| (bottom.delegations.len() as u32, bottom.total))
+			.unwrap_or((0, Zero::zero()));
+
+		Some(CollatorSummary {
+			bond: info.bond,
+			status: info.status,
+			is_selected: Self::is_selected_candidate(candidate),
+			delegation_count: info.delegation_count,
+			top_delegation_count,
+			bottom_delegation_count,
+			total_counted: info.total_counted,
+			total_bottom,
+			lowest_top_delegation_amount: info.lowest_top_delegation_amount,
+			highest_bottom_delegation_amount: info.highest_bottom_delegation_amount,
+			lowest_bottom_delegation_amount: info.lowest_bottom_delegation_amount,
+			auto_compounding_delegation_count:
+				<AutoCompoundDelegations<T>>::get_auto_compounding_delegation_count(candidate)
+					as u32,
+			scheduled_request_delegator_count: <DelegationScheduledRequestsPerCollator<T>>::get(
+				candidate,
+			),
+			bond_less_request: info.request,
+		})
+	}
+}
@@ -0,0 +1,278 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Slashing of collators and of their top delegations.
+//!
+//! Slashes are reported for a collator, either when it is notified as inactive or by governance
+//! for misbehaviour, and applied `T::SlashDeferDuration` rounds later, one collator per block.
+//! Slashes left unapplied at the end of a round are carried over to the next round. Governance
+//! can cancel a slash until it is applied.
+
+use crate::pallet::{
+	BalanceOf, CandidateInfo, CandidatePool, Config, DelegatorState, Error, Event, Pallet,
+	PendingSlashes, Round, RoundIndex, SlashFractions, TopDelegations, Total,
+};
+use crate::types::Bond;
+use crate::weights::WeightInfo;
+use frame_support::dispatch::DispatchResult;
+use frame_support::ensure;
+use frame_support::pallet_prelude::Weight;
+use frame_support::traits::{
+	fungible::Mutate,
+	tokens::{Fortitude, Precision, Preservation},
+	Get,
+};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Reason of a slash
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	DecodeWithMemTracking,
+)]
+pub enum SlashReason {
+	/// The collator was notified as inactive for `T::MaxOfflineRounds` rounds
+	Offline,
+	/// Misbehaviour reported by governance, e.g. equivocation
+	Misbehaviour,
+}
+
+/// Fractions of the stake slashed for each reason. A zero fraction disables the slash.
+#[derive(
+	Clone,
+	Copy,
+	Default,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	DecodeWithMemTracking,
+)]
+pub struct SlashFractionsConfig {
+	pub offline: Perbill,
+	pub misbehaviour: Perbill,
+}
+
+impl SlashFractionsConfig {
+	pub fn fraction(&self, reason: SlashReason) -> Perbill {
+		match reason {
+			SlashReason::Offline => self.offline,
+			SlashReason::Misbehaviour => self.misbehaviour,
+		}
+	}
+}
+
+/// Slash waiting to be applied
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingSlash {
+	pub reason: SlashReason,
+	/// Fraction of the collator bond and of each top delegation to slash
+	pub fraction: Perbill,
+	/// Round the slash was reported at
+	pub reported_round: RoundIndex,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Schedules a slash of `collator` with the configured fraction for `reason`, to be applied
+	/// `T::SlashDeferDuration` rounds from now. Slashes of the same collator applied in the same
+	/// round are merged, keeping the highest fraction.
+	pub(crate) fn report_slash(collator: T::AccountId, reason: SlashReason) -> DispatchResult {
+		ensure!(
+			<CandidateInfo<T>>::contains_key(&collator),
+			Error::<T>::CandidateDNE
+		);
+		let fraction = <SlashFractions<T>>::get().fraction(reason);
+		ensure!(!fraction.is_zero(), Error::<T>::SlashingDisabled);
+
+		let now = <Round<T>>::get().current;
+		let apply_at = now.saturating_add(T::SlashDeferDuration::get());
+		Self::insert_pending_slash(
+			apply_at,
+			&collator,
+			PendingSlash {
+				reason,
+				fraction,
+				reported_round: now,
+			},
+		);
+
+		Self::deposit_event(Event::SlashReported {
+			collator,
+			reason,
+			fraction,
+			apply_at,
+		});
+		Ok(())
+	}
+
+	/// Schedules `slash` of `collator` in round `apply_at`, unless a slash with a higher fraction
+	/// is already scheduled then.
+	fn insert_pending_slash(apply_at: RoundIndex, collator: &T::AccountId, slash: PendingSlash) {
+		<PendingSlashes<T>>::mutate(apply_at, collator, |pending| match pending {
+			Some(current) if current.fraction >= slash.fraction => (),
+			_ => *pending = Some(slash),
+		});
+	}
+
+	/// Moves the slashes left unapplied at the end of the previous round to round `now`, so they
+	/// are applied during the blocks of `now`.
+	pub(crate) fn carry_over_pending_slashes(now: RoundIndex) -> Weight {
+		let db_weight = T::DbWeight::get();
+		let Some(previous) = now.checked_sub(1) else {
+			return Weight::zero();
+		};
+		// read of the first leftover slash
+		let mut weight = db_weight.reads(1);
+		for (collator, slash) in <PendingSlashes<T>>::drain_prefix(previous) {
+			Self::insert_pending_slash(now, &collator, slash);
+			weight = weight.saturating_add(db_weight.reads_writes(2, 2));
+		}
+		weight
+	}
+
+	/// Applies one of the slashes due in round `now`, if any.
+	pub(crate) fn handle_pending_slashes(now: RoundIndex) -> Weight {
+		// read of the next pending slash
+		let mut weight = T::DbWeight::get().reads(1);
+		if let Some((collator, slash)) = <PendingSlashes<T>>::iter_prefix(now).drain().next() {
+			weight = weight
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(Self::apply_slash(collator, slash));
+		}
+		weight
+	}
+
+	/// Slashes `slash.fraction` of the bond of `collator` and of each of its top delegations.
+	/// Slashed funds are burned.
+	pub(crate) fn apply_slash(collator: T::AccountId, slash: PendingSlash) -> Weight {
+		let Some(mut info) = <CandidateInfo<T>>::get(&collator) else {
+			log::warn!("skipped slash of {:?}, not a candidate anymore", collator);
+			return T::DbWeight::get().reads(1);
+		};
+
+		let bond_slash = slash.fraction * info.bond;
+		if !bond_slash.is_zero() {
+			info.bond = info.bond.saturating_sub(bond_slash);
+			info.total_counted = info.total_counted.saturating_sub(bond_slash);
+			<Total<T>>::mutate(|total| *total = total.saturating_sub(bond_slash));
+			let _ = if info.bond.is_zero() {
+				Self::thaw_extended(&collator, true)
+			} else {
+				Self::freeze_extended(&collator, info.bond, true)
+			};
+			Self::burn_slashed(&collator, &collator, bond_slash);
+		}
+
+		// The delegations slashed are the top ones when the slash is applied. Decreasing one of
+		// them can swap it with the highest bottom delegation, so the slashed amounts are
+		// computed upfront, and each delegation is then decreased from its current bond.
+		let slashed_delegations: Vec<_> = <TopDelegations<T>>::get(&collator)
+			.map(|top| top.delegations)
+			.unwrap_or_default()
+			.into_iter()
+			.map(|Bond { owner, amount }| (owner, slash.fraction * amount))
+			.filter(|(_, less)| !less.is_zero())
+			.collect();
+		let weight = <T as Config>::WeightInfo::apply_slash(slashed_delegations.len() as u32);
+		for (owner, less) in slashed_delegations {
+			let Some(mut state) = <DelegatorState<T>>::get(&owner) else {
+				continue;
+			};
+			let Some(amount) = state.get_bond_amount(&collator) else {
+				continue;
+			};
+			let less = less.min(amount);
+			if let Err(err) = info.decrease_delegation::<T>(&collator, owner.clone(), amount, less)
+			{
+				log::warn!(
+					"skipped slash of delegation of {:?} to {:?}: {:?}",
+					owner,
+					collator,
+					err
+				);
+				continue;
+			}
+			for bond in state.delegations.0.iter_mut() {
+				if bond.owner == collator {
+					bond.amount = bond.amount.saturating_sub(less);
+				}
+			}
+			let _ = state.total_sub::<T>(less);
+			Self::delegation_slash_requests_with_state(
+				&collator,
+				&owner,
+				&mut state,
+				amount,
+				amount.saturating_sub(less),
+			);
+			<DelegatorState<T>>::insert(&owner, state);
+			<Total<T>>::mutate(|total| *total = total.saturating_sub(less));
+			Self::burn_slashed(&collator, &owner, less);
+		}
+
+		if info.is_active() {
+			if info.bond < T::MinCandidateStk::get() {
+				// the candidate cannot be selected anymore until it bonds more and goes online
+				info.go_offline();
+				let mut candidates = <CandidatePool<T>>::get();
+				if candidates.remove(&Bond::from_owner(collator.clone())) {
+					<CandidatePool<T>>::put(candidates);
+				}
+				Self::deposit_event(Event::CandidateWentOffline {
+					candidate: collator.clone(),
+				});
+			} else {
+				Self::update_active(collator.clone(), info.total_counted);
+			}
+		}
+
+		<CandidateInfo<T>>::insert(&collator, info);
+		weight
+	}
+
+	fn burn_slashed(collator: &T::AccountId, account: &T::AccountId, amount: BalanceOf<T>) {
+		match T::Currency::burn_from(
+			account,
+			amount,
+			Preservation::Preserve,
+			Precision::BestEffort,
+			Fortitude::Force,
+		) {
+			Ok(burned) => Self::deposit_event(Event::Slashed {
+				collator: collator.clone(),
+				account: account.clone(),
+				amount: burned,
+			}),
+			Err(err) => log::warn!("failed to burn slash of {:?}: {:?}", account, err),
+		}
+	}
+}
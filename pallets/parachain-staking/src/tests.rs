@@ -31,12 +31,12 @@ use crate::mock::{
 };
 use crate::{
 	assert_events_emitted, assert_events_emitted_match, assert_events_eq, assert_no_events,
-	AtStake, AwardedPts, Bond, CollatorStatus, DelegationScheduledRequests,
-	DelegationScheduledRequestsPerCollator, DelegationScheduledRequestsSummaryMap, DelegatorAdded,
-	EnableMarkingOffline, Error, Event, FreezeReason, InflationDistributionInfo, Points, Range,
-	WasInactive,
+	AtStake, AwardedPts, Bond, CandidateProfile, CandidateProfileInfo, CandidateProfiles,
+	CollatorStatus, DelegationScheduledRequests, DelegationScheduledRequestsPerCollator,
+	DelegationScheduledRequestsSummaryMap, DelegatorAdded, EnableMarkingOffline, Error, Event,
+	FreezeReason, InflationDistributionInfo, Points, Range, SelectedCollator, WasInactive,
 };
-use frame_support::traits::fungible::MutateFreeze;
+use frame_support::traits::fungible::{InspectHold, MutateFreeze};
 use frame_support::traits::{Currency, ExistenceRequirement, WithdrawReasons};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
 use pallet_balances::{Event as BalancesEvent, PositiveImbalance};
@@ -8603,3 +8603,1722 @@ fn test_linear_inflation_threshold() {
 			assert_eq!(round_above.ideal, threshold.unwrap() / 20); // 5% of threshold
 		});
 }
+
+#[test]
+fn delegator_position_reports_placement_auto_compound_and_requests() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 30), (4, 30), (5, 30), (6, 30)])
+		.with_candidates(vec![(1, 30)])
+		.with_auto_compounding_delegations(vec![
+			(2, 1, 10, Percent::from_percent(50)),
+			(3, 1, 11, Percent::zero()),
+			(4, 1, 12, Percent::zero()),
+			(5, 1, 13, Percent::zero()),
+			(6, 1, 9, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				2
+			));
+
+			assert_eq!(
+				ParachainStaking::delegator_position(&2),
+				Some(crate::DelegatorPosition {
+					total: 10,
+					less_total: 2,
+					delegations: vec![crate::DelegationPosition {
+						candidate: 1,
+						amount: 10,
+						auto_compound: Percent::from_percent(50),
+						placement: crate::DelegationPlacement::Top,
+						scheduled_requests: vec![ScheduledRequest {
+							when_executable: 3,
+							action: DelegationAction::Decrease(2),
+						}],
+					}],
+				})
+			);
+			assert_eq!(
+				ParachainStaking::delegator_position(&6).map(|p| p.delegations[0].placement),
+				Some(crate::DelegationPlacement::Bottom)
+			);
+			assert_eq!(ParachainStaking::delegator_position(&1), None);
+		});
+}
+
+#[test]
+fn collator_summary_reports_candidate_state() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 30), (3, 30), (4, 30), (5, 30), (6, 30)])
+		.with_candidates(vec![(1, 30)])
+		.with_auto_compounding_delegations(vec![
+			(2, 1, 10, Percent::from_percent(50)),
+			(3, 1, 11, Percent::zero()),
+			(4, 1, 12, Percent::zero()),
+			(5, 1, 13, Percent::zero()),
+			(6, 1, 9, Percent::zero()),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(3),
+				1
+			));
+
+			assert_eq!(
+				ParachainStaking::collator_summary(&1),
+				Some(crate::CollatorSummary {
+					bond: 30,
+					status: CollatorStatus::Active,
+					is_selected: true,
+					delegation_count: 5,
+					top_delegation_count: 4,
+					bottom_delegation_count: 1,
+					total_counted: 76,
+					total_bottom: 9,
+					lowest_top_delegation_amount: 10,
+					highest_bottom_delegation_amount: 9,
+					lowest_bottom_delegation_amount: 9,
+					auto_compounding_delegation_count: 1,
+					scheduled_request_delegator_count: 1,
+					bond_less_request: None,
+				})
+			);
+			assert_eq!(ParachainStaking::collator_summary(&2), None);
+		});
+}
+
+#[test]
+fn projected_rewards_match_paid_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			let projected = ParachainStaking::projected_rewards(&1, 10).expect("1 is a candidate");
+			assert!(projected.counted);
+			assert_eq!(projected.points_share, Perbill::one());
+			assert!(!projected.delegation_round_reward.is_zero());
+			assert_eq!(
+				projected.delegation_annual_reward,
+				projected.delegation_round_reward
+					* crate::inflation::rounds_per_year::<Test>() as u128
+			);
+			// the APR is in basis points of the stake
+			assert_eq!(
+				projected.delegation_apr as u128,
+				projected.delegation_annual_reward * 10_000 / 10
+			);
+
+			assert_ok!(ParachainStaking::delegate_with_auto_compound(
+				RuntimeOrigin::signed(3),
+				1,
+				10,
+				Percent::zero(),
+				1,
+				0,
+				0
+			));
+			set_block_author(1);
+			roll_to_round_begin(4);
+			roll_blocks(1);
+			assert_events_emitted!(
+				Event::Rewarded {
+					account: 1,
+					rewards: projected.collator_round_reward,
+				},
+				Event::Rewarded {
+					account: 3,
+					rewards: projected.delegation_round_reward,
+				},
+			);
+			assert_eq!(ParachainStaking::projected_rewards(&2, 10), None);
+		});
+}
+
+#[test]
+fn projected_rewards_are_zero_outside_top_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100), (5, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 10), (4, 1, 10), (5, 1, 10)])
+		.build()
+		.execute_with(|| {
+			let projected = ParachainStaking::projected_rewards(&1, 10).expect("1 is a candidate");
+			assert!(!projected.counted);
+			assert!(projected.delegation_round_reward.is_zero());
+			assert_eq!(projected.delegation_apr, 0);
+
+			let projected = ParachainStaking::projected_rewards(&1, 11).expect("1 is a candidate");
+			assert!(projected.counted);
+			assert!(projected.delegation_apr > 0);
+		});
+}
+
+#[test]
+fn reward_history_records_minted_and_compounded_rewards() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.with_auto_compounding_delegations(vec![(3, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			set_block_author(1);
+			roll_to_round_begin(3);
+			roll_blocks(1);
+
+			let rewards = crate::RewardHistory::<Test>::get(1, 3);
+			assert_eq!(rewards.len(), 1);
+			let reward = rewards[0].clone();
+			assert_eq!(reward.collator, 1);
+			assert!(!reward.amount.is_zero());
+			assert_eq!(
+				reward.compounded,
+				Percent::from_percent(50).mul_ceil(reward.amount)
+			);
+			assert_events_emitted!(
+				Event::Rewarded {
+					account: 3,
+					rewards: reward.amount,
+				},
+				Event::Compounded {
+					candidate: 1,
+					delegator: 3,
+					amount: reward.compounded,
+				},
+			);
+
+			let reward_2 = crate::RewardHistory::<Test>::get(1, 2)[0].clone();
+			assert!(reward_2.compounded.is_zero());
+			assert_eq!(
+				ParachainStaking::delegator_reward_history(&2),
+				vec![(1, reward_2)]
+			);
+			assert!(ParachainStaking::delegator_reward_history(&1).is_empty());
+		});
+}
+
+#[test]
+fn reward_history_is_pruned_after_retention_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			set_block_author(1);
+			roll_to_round_begin(4);
+			roll_blocks(1);
+			assert!(crate::RewardHistory::<Test>::contains_key(1, 2));
+			assert!(crate::RewardHistory::<Test>::contains_key(2, 2));
+
+			// Rounds 2 and 3 are kept once round 3 is paid
+			roll_to_round_begin(5);
+			roll_blocks(1);
+			assert!(!crate::RewardHistory::<Test>::contains_key(1, 2));
+			let rounds: Vec<_> = ParachainStaking::delegator_reward_history(&2)
+				.into_iter()
+				.map(|(round, _)| round)
+				.collect();
+			assert_eq!(rounds, vec![2, 3]);
+		});
+}
+
+#[test]
+fn selection_history_records_selected_collators() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20), (2, 10)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_eq!(
+				ParachainStaking::round_selection(1),
+				vec![
+					SelectedCollator {
+						collator: 1,
+						total_counted: 30,
+						delegator_count: 1,
+					},
+					SelectedCollator {
+						collator: 2,
+						total_counted: 10,
+						delegator_count: 0,
+					},
+				]
+			);
+
+			// a candidate leaving the selected set is not in the selection of the next rounds
+			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(2)));
+			roll_to_round_begin(2);
+			assert_eq!(
+				ParachainStaking::round_selection(2),
+				vec![SelectedCollator {
+					collator: 1,
+					total_counted: 30,
+					delegator_count: 1,
+				}]
+			);
+			assert_eq!(ParachainStaking::round_selection(1).len(), 2);
+		});
+}
+
+#[test]
+fn selection_history_records_rewardable_stake() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+			(7, 100),
+		])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![
+			(3, 1, 10),
+			(4, 1, 10),
+			(5, 1, 10),
+			(6, 1, 10),
+			(7, 1, 5),
+		])
+		.build()
+		.execute_with(|| {
+			// the bottom delegation is not counted
+			assert_eq!(
+				ParachainStaking::round_selection(1),
+				vec![SelectedCollator {
+					collator: 1,
+					total_counted: 60,
+					delegator_count: 4,
+				}]
+			);
+
+			// neither is the stake of a pending revocation
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(3),
+				1
+			));
+			roll_to_round_begin(2);
+			assert_eq!(
+				ParachainStaking::round_selection(2),
+				vec![SelectedCollator {
+					collator: 1,
+					total_counted: 50,
+					delegator_count: 4,
+				}]
+			);
+			assert_eq!(ParachainStaking::at_stake(2, 1).total, 50);
+		});
+}
+
+#[test]
+fn selection_history_is_pruned_after_retention_rounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100)])
+		.with_candidates(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			roll_to_round_begin(2);
+			assert!(crate::SelectionHistory::<Test>::contains_key(1));
+
+			// Rounds 2 and 3 are kept once round 3 starts
+			roll_to_round_begin(3);
+			assert!(!crate::SelectionHistory::<Test>::contains_key(1));
+			assert!(crate::SelectionHistory::<Test>::contains_key(2));
+			assert_eq!(ParachainStaking::round_selection(3).len(), 1);
+		});
+}
+
+#[test]
+fn redelegate_moves_delegation_without_unfreezing() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_auto_compounding_delegations(vec![(3, 1, 10, Percent::from_percent(50))])
+		.build()
+		.execute_with(|| {
+			let total = ParachainStaking::total();
+			assert_ok!(ParachainStaking::redelegate(
+				RuntimeOrigin::signed(3),
+				1,
+				2,
+				0,
+				0
+			));
+			assert_events_eq!(Event::Redelegated {
+				delegator: 3,
+				from_candidate: 1,
+				to_candidate: 2,
+				amount: 10,
+				delegator_position: DelegatorAdded::AddedToTop { new_total: 30 },
+				auto_compound: Percent::from_percent(50),
+			});
+
+			assert_eq!(ParachainStaking::total(), total);
+			assert_eq!(
+				ParachainStaking::candidate_info(1)
+					.expect("1 is a candidate")
+					.total_counted,
+				20
+			);
+			assert_eq!(
+				ParachainStaking::candidate_info(2)
+					.expect("2 is a candidate")
+					.total_counted,
+				30
+			);
+			let state = ParachainStaking::delegator_state(3).expect("3 is a delegator");
+			assert_eq!(state.total, 10);
+			assert_eq!(state.get_bond_amount(&1), None);
+			assert_eq!(state.get_bond_amount(&2), Some(10));
+			assert_eq!(
+				query_freeze_amount(3, &FreezeReason::StakingDelegator.into()),
+				10
+			);
+			assert_eq!(
+				ParachainStaking::delegation_auto_compound(&1, &3),
+				Percent::zero()
+			);
+			assert_eq!(
+				ParachainStaking::delegation_auto_compound(&2, &3),
+				Percent::from_percent(50)
+			);
+		});
+}
+
+#[test]
+fn redelegate_is_rate_limited_by_cooldown() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 10)])
+		.with_candidates(vec![(1, 20), (2, 20), (3, 20)])
+		.with_delegations(vec![(4, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::redelegate(
+				RuntimeOrigin::signed(4),
+				1,
+				2,
+				0,
+				0
+			));
+			roll_to_round_begin(2);
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(4), 2, 3, 0, 0),
+				Error::<Test>::RedelegationCooldownNotElapsed
+			);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::redelegate(
+				RuntimeOrigin::signed(4),
+				2,
+				3,
+				0,
+				0
+			));
+		});
+}
+
+#[test]
+fn redelegate_fails_for_invalid_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 20)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_delegations(vec![(3, 1, 5), (3, 2, 5)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 1, 0, 0),
+				Error::<Test>::CannotRedelegateToSameCandidate
+			);
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 1, 0),
+				Error::<Test>::AlreadyDelegatedCandidate
+			);
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 4, 1, 1, 0),
+				Error::<Test>::DelegationDNE
+			);
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(1), 2, 3, 1, 0),
+				Error::<Test>::DelegatorDNE
+			);
+
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(3),
+				1,
+				1
+			));
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 4, 1, 0),
+				Error::<Test>::PendingDelegationRequestAlreadyExists
+			);
+		});
+}
+
+#[test]
+fn redelegate_fails_towards_leaving_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 10)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_delegations(vec![(3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_leave_candidates(
+				RuntimeOrigin::signed(2),
+				2
+			));
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 0, 0),
+				Error::<Test>::CandidateExited
+			);
+		});
+}
+
+#[test]
+fn redelegate_fails_for_delegation_below_min() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 3)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.with_delegations(vec![(3, 1, 3)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(50),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			roll_to_round_begin(2);
+			roll_blocks(1);
+			assert!(
+				ParachainStaking::delegator_state(3)
+					.expect("3 is a delegator")
+					.get_bond_amount(&1)
+					.expect("3 delegates 1")
+					< 3
+			);
+
+			assert_noop!(
+				ParachainStaking::redelegate(RuntimeOrigin::signed(3), 1, 2, 0, 0),
+				Error::<Test>::DelegationBelowMin
+			);
+		});
+}
+
+#[test]
+fn report_misbehaviour_fails_when_slashing_is_disabled() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20)])
+		.with_candidates(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::report_misbehaviour(RuntimeOrigin::signed(1), 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				ParachainStaking::report_misbehaviour(RuntimeOrigin::root(), 1),
+				Error::<Test>::SlashingDisabled
+			);
+		});
+}
+
+#[test]
+fn misbehaviour_slash_is_applied_after_defer_duration() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 50)])
+		.with_delegations(vec![(2, 1, 40), (3, 1, 20)])
+		.build()
+		.execute_with(|| {
+			let fractions = crate::SlashFractionsConfig {
+				offline: Perbill::zero(),
+				misbehaviour: Perbill::from_percent(10),
+			};
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				fractions
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			assert_events_emitted!(Event::SlashReported {
+				collator: 1,
+				reason: crate::SlashReason::Misbehaviour,
+				fraction: Perbill::from_percent(10),
+				apply_at: 2,
+			});
+
+			roll_to_round_begin(2);
+			assert!(crate::PendingSlashes::<Test>::contains_key(2, 1));
+			roll_blocks(1);
+			assert!(!crate::PendingSlashes::<Test>::contains_key(2, 1));
+			assert_events_emitted!(
+				Event::Slashed {
+					collator: 1,
+					account: 1,
+					amount: 5,
+				},
+				Event::Slashed {
+					collator: 1,
+					account: 2,
+					amount: 4,
+				},
+				Event::Slashed {
+					collator: 1,
+					account: 3,
+					amount: 2,
+				},
+			);
+
+			let info = ParachainStaking::candidate_info(1).expect("1 is a candidate");
+			assert_eq!(info.bond, 45);
+			assert_eq!(info.total_counted, 99);
+			assert_eq!(ParachainStaking::total(), 99);
+			assert_eq!(
+				ParachainStaking::delegator_state(2)
+					.expect("2 is a delegator")
+					.get_bond_amount(&1),
+				Some(36)
+			);
+			assert_eq!(Balances::free_balance(2), 96);
+			assert_eq!(
+				query_freeze_amount(2, &FreezeReason::StakingDelegator.into()),
+				36
+			);
+			assert_eq!(
+				query_freeze_amount(1, &FreezeReason::StakingCollator.into()),
+				45
+			);
+		});
+}
+
+#[test]
+fn cancelled_slash_is_not_applied() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 50)])
+		.with_delegations(vec![(2, 1, 40)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(10),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			assert_noop!(
+				ParachainStaking::cancel_slash(RuntimeOrigin::signed(2), 2, 1),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_ok!(ParachainStaking::cancel_slash(RuntimeOrigin::root(), 2, 1));
+			assert_events_emitted!(Event::SlashCancelled {
+				collator: 1,
+				apply_at: 2,
+			});
+			assert_noop!(
+				ParachainStaking::cancel_slash(RuntimeOrigin::root(), 2, 1),
+				Error::<Test>::PendingSlashDNE
+			);
+
+			roll_to_round_begin(2);
+			roll_blocks(1);
+			let info = ParachainStaking::candidate_info(1).expect("1 is a candidate");
+			assert_eq!(info.bond, 50);
+			assert_eq!(info.total_counted, 90);
+			assert_eq!(Balances::free_balance(2), 100);
+		});
+}
+
+#[test]
+fn pending_slashes_left_at_round_end_are_carried_over() {
+	ExtBuilder::default()
+		.with_balances((1..=8).map(|c| (c, 100)).collect())
+		.with_candidates((1..=8).map(|c| (c, 20)).collect())
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(10),
+				}
+			));
+			for collator in 1..=8 {
+				assert_ok!(ParachainStaking::report_misbehaviour(
+					RuntimeOrigin::root(),
+					collator
+				));
+			}
+
+			// one slash is applied per block, so round 2 is too short to apply all of them
+			roll_to_round_begin(3);
+			assert_eq!(crate::PendingSlashes::<Test>::iter_prefix(2).count(), 0);
+			assert!(crate::PendingSlashes::<Test>::iter_prefix(3).count() > 0);
+
+			roll_to_round_begin(4);
+			assert_eq!(crate::PendingSlashes::<Test>::iter().count(), 0);
+			for collator in 1..=8 {
+				let info = ParachainStaking::candidate_info(collator).expect("is a candidate");
+				assert_eq!(info.bond, 18);
+			}
+		});
+}
+
+#[test]
+fn carried_over_slash_can_be_cancelled_with_its_original_round() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100)])
+		.with_candidates(vec![(1, 50)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(10),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			// simulate a slash left unapplied at the end of round 2
+			crate::PendingSlashes::<Test>::insert(
+				1,
+				1,
+				crate::PendingSlashes::<Test>::take(2, 1).expect("just reported"),
+			);
+			roll_to_round_begin(2);
+			assert!(crate::PendingSlashes::<Test>::contains_key(2, 1));
+
+			assert_ok!(ParachainStaking::cancel_slash(RuntimeOrigin::root(), 1, 1));
+			assert!(!crate::PendingSlashes::<Test>::contains_key(2, 1));
+		});
+}
+
+#[test]
+fn slash_updates_candidate_pool_and_takes_under_bonded_candidate_offline() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100), (4, 100)])
+		.with_candidates(vec![(1, 10), (3, 50)])
+		.with_delegations(vec![(2, 1, 40), (4, 3, 40)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(10),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				3
+			));
+			roll_to_round_begin(2);
+			roll_blocks(2);
+
+			// the bond of 1 fell below MinCandidateStk
+			let info = ParachainStaking::candidate_info(1).expect("1 is a candidate");
+			assert_eq!(info.bond, 9);
+			assert!(!info.is_active());
+			assert_events_emitted!(Event::CandidateWentOffline { candidate: 1 });
+
+			// the slashed delegation is reflected in the candidate pool
+			let pool = ParachainStaking::candidate_pool();
+			assert!(pool.0.iter().all(|bond| bond.owner != 1));
+			let bond = pool
+				.0
+				.iter()
+				.find(|bond| bond.owner == 3)
+				.expect("3 is in the pool");
+			assert_eq!(bond.amount, 81);
+		});
+}
+
+#[test]
+fn slash_rescales_pending_decrease() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 50)])
+		.with_delegations(vec![(2, 1, 40)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				30
+			));
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(50),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			roll_to_round_begin(2);
+			roll_blocks(1);
+
+			// the decrease is rescaled along with the slashed delegation
+			assert_eq!(
+				ParachainStaking::delegator_state(2)
+					.expect("2 is a delegator")
+					.get_bond_amount(&1),
+				Some(20)
+			);
+			assert_eq!(
+				ParachainStaking::delegation_scheduled_requests(&1, &2),
+				vec![ScheduledRequest {
+					when_executable: 3,
+					action: DelegationAction::Decrease(15),
+				}],
+			);
+			assert_eq!(
+				<DelegationScheduledRequestsSummaryMap<Test>>::get(1, 2),
+				Some(DelegationAction::Decrease(15))
+			);
+			assert_eq!(
+				ParachainStaking::delegator_state(2)
+					.expect("2 is a delegator")
+					.less_total,
+				15
+			);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert_eq!(
+				ParachainStaking::delegator_state(2)
+					.expect("2 is a delegator")
+					.get_bond_amount(&1),
+				Some(5)
+			);
+			assert_eq!(ParachainStaking::total(), 30);
+			assert_eq!(
+				query_freeze_amount(2, &FreezeReason::StakingDelegator.into()),
+				5
+			);
+		});
+}
+
+#[test]
+fn slash_swapping_top_and_bottom_delegations_slashes_the_top_ones_once() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(1, 100),
+			(2, 100),
+			(3, 100),
+			(4, 100),
+			(5, 100),
+			(6, 100),
+		])
+		.with_candidates(vec![(1, 50)])
+		.with_delegations(vec![
+			(2, 1, 40),
+			(3, 1, 30),
+			(4, 1, 30),
+			(5, 1, 30),
+			(6, 1, 29),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::zero(),
+					misbehaviour: Perbill::from_percent(10),
+				}
+			));
+			assert_ok!(ParachainStaking::report_misbehaviour(
+				RuntimeOrigin::root(),
+				1
+			));
+			roll_to_round_begin(2);
+			roll_blocks(1);
+
+			let bond = |delegator| {
+				ParachainStaking::delegator_state(delegator)
+					.expect("is a delegator")
+					.get_bond_amount(&1)
+			};
+			assert_eq!(bond(2), Some(36));
+			assert_eq!(bond(3), Some(27));
+			assert_eq!(bond(4), Some(27));
+			assert_eq!(bond(5), Some(27));
+			// the bottom delegation promoted by the slash of 3 is not slashed
+			assert_eq!(bond(6), Some(29));
+
+			let top = ParachainStaking::top_delegations(1).expect("1 is a candidate");
+			assert_eq!(top.total, 119);
+			let mut top_owners: Vec<_> = top.delegations.iter().map(|d| d.owner).collect();
+			top_owners.sort();
+			assert_eq!(top_owners, vec![2, 4, 5, 6]);
+			assert_eq!(
+				ParachainStaking::bottom_delegations(1)
+					.expect("1 is a candidate")
+					.delegations,
+				vec![Bond {
+					owner: 3,
+					amount: 27
+				}]
+			);
+			assert_eq!(
+				ParachainStaking::candidate_info(1)
+					.expect("1 is a candidate")
+					.total_counted,
+				45 + 119
+			);
+			assert_eq!(ParachainStaking::total(), 191);
+		});
+}
+
+#[test]
+fn notify_inactive_collator_reports_offline_slash() {
+	const INACTIVE_COLLATOR: AccountId = 1;
+	const ACTIVE_COLLATOR: AccountId = 2;
+
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+		.with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20), (5, 20)])
+		.build()
+		.execute_with(|| {
+			<EnableMarkingOffline<Test>>::set(true);
+			assert_ok!(ParachainStaking::set_slash_fractions(
+				RuntimeOrigin::root(),
+				crate::SlashFractionsConfig {
+					offline: Perbill::from_percent(5),
+					misbehaviour: Perbill::zero(),
+				}
+			));
+
+			set_block_author(INACTIVE_COLLATOR);
+			roll_to_round_begin(2);
+			set_block_author(ACTIVE_COLLATOR);
+			roll_to_round_begin(4);
+			roll_blocks(1);
+
+			assert_ok!(ParachainStaking::notify_inactive_collator(
+				RuntimeOrigin::signed(1),
+				INACTIVE_COLLATOR
+			));
+			assert_events_emitted!(Event::SlashReported {
+				collator: INACTIVE_COLLATOR,
+				reason: crate::SlashReason::Offline,
+				fraction: Perbill::from_percent(5),
+				apply_at: 5,
+			});
+
+			roll_to_round_begin(5);
+			roll_blocks(1);
+			assert_eq!(
+				ParachainStaking::candidate_info(INACTIVE_COLLATOR)
+					.expect("1 is a candidate")
+					.bond,
+				19
+			);
+		});
+}
+
+#[test]
+fn execute_all_matured_requests_executes_requests_of_all_delegations() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30), (4, 10)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 20), (2, 3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(2),
+				3
+			));
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_all_matured_requests(
+				RuntimeOrigin::signed(4),
+				2,
+				10
+			));
+			assert_events_emitted!(Event::DelegationDecreased {
+				delegator: 2,
+				candidate: 1,
+				amount: 5,
+				in_top: true
+			});
+			assert_events_emitted!(Event::DelegatorLeftCandidate {
+				delegator: 2,
+				candidate: 3,
+				unstaked_amount: 20,
+				total_candidate_staked: 30
+			});
+			assert!(DelegationScheduledRequests::<Test>::get(1, 2).is_empty());
+			assert!(DelegationScheduledRequests::<Test>::get(3, 2).is_empty());
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total(), 15);
+			assert_eq!(
+				ParachainStaking::execute_all_matured_requests(RuntimeOrigin::signed(4), 2, 10)
+					.map_err(|err| err.error),
+				Err(Error::<Test>::NoMaturedDelegationRequests.into())
+			);
+		});
+}
+
+#[test]
+fn execute_all_matured_requests_respects_max_requests_and_maturity() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 40), (3, 30)])
+		.with_candidates(vec![(1, 30), (3, 30)])
+		.with_delegations(vec![(2, 1, 20), (2, 3, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				1,
+				5
+			));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(2),
+				3,
+				5
+			));
+			assert_eq!(
+				ParachainStaking::execute_all_matured_requests(RuntimeOrigin::signed(2), 2, 10)
+					.map_err(|err| err.error),
+				Err(Error::<Test>::NoMaturedDelegationRequests.into())
+			);
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_all_matured_requests(
+				RuntimeOrigin::signed(2),
+				2,
+				1
+			));
+			assert!(DelegationScheduledRequests::<Test>::get(1, 2).is_empty());
+			assert_eq!(DelegationScheduledRequests::<Test>::get(3, 2).len(), 1);
+		});
+}
+
+#[test]
+fn on_idle_executes_matured_requests_within_remaining_weight() {
+	use crate::mock::System;
+	use crate::WeightInfo;
+	use frame_support::traits::{Get, OnIdle};
+	use frame_support::weights::Weight;
+
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30), (2, 20), (3, 20), (4, 20)])
+		.with_candidates(vec![(1, 30)])
+		.with_delegations(vec![(2, 1, 20), (3, 1, 20), (4, 1, 20)])
+		.build()
+		.execute_with(|| {
+			for delegator in [2, 3] {
+				assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+					RuntimeOrigin::signed(delegator),
+					1,
+					5
+				));
+			}
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(4),
+				1
+			));
+			// nothing is matured yet
+			ParachainStaking::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(
+				DelegationScheduledRequests::<Test>::iter().count(),
+				3,
+				"no request should be executed before it is matured"
+			);
+
+			roll_to(10);
+			let db_weight = <Test as frame_system::Config>::DbWeight::get();
+			let one_request = db_weight.reads_writes(3, 1).saturating_add(
+				<() as WeightInfo>::execute_all_matured_requests(1)
+					.saturating_sub(<() as WeightInfo>::execute_all_matured_requests(0)),
+			);
+			assert_eq!(
+				ParachainStaking::on_idle(
+					System::block_number(),
+					one_request.saturating_sub(Weight::from_parts(1, 0))
+				),
+				Weight::zero()
+			);
+			assert_eq!(DelegationScheduledRequests::<Test>::iter().count(), 3);
+
+			ParachainStaking::on_idle(System::block_number(), one_request);
+			assert_eq!(DelegationScheduledRequests::<Test>::iter().count(), 2);
+			assert!(crate::MaturedRequestsCursor::<Test>::get().is_some());
+
+			ParachainStaking::on_idle(System::block_number(), Weight::MAX);
+			assert_eq!(DelegationScheduledRequests::<Test>::iter().count(), 0);
+			assert!(crate::MaturedRequestsCursor::<Test>::get().is_none());
+			assert!(ParachainStaking::delegator_state(4).is_none());
+			assert_eq!(ParachainStaking::delegator_state(2).unwrap().total(), 15);
+			assert_eq!(ParachainStaking::delegator_state(3).unwrap().total(), 15);
+		});
+}
+
+#[test]
+fn candidate_commission_change_executes_after_delay_within_bounds() {
+	use crate::CommissionBounds;
+
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_candidate_commission_bounds(
+					RuntimeOrigin::root(),
+					CommissionBounds {
+						min: Perbill::from_percent(20),
+						max: Perbill::from_percent(10),
+					}
+				),
+				Error::<Test>::InvalidCommissionBounds
+			);
+			let bounds = CommissionBounds {
+				min: Perbill::from_percent(5),
+				max: Perbill::from_percent(30),
+			};
+			assert_ok!(ParachainStaking::set_candidate_commission_bounds(
+				RuntimeOrigin::root(),
+				bounds
+			));
+			assert_events_emitted!(Event::CandidateCommissionBoundsSet {
+				old: CommissionBounds::default(),
+				new: bounds,
+			});
+
+			assert_noop!(
+				ParachainStaking::schedule_candidate_commission_change(
+					RuntimeOrigin::signed(1),
+					Perbill::from_percent(40)
+				),
+				Error::<Test>::CommissionOutOfBounds
+			);
+			assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			assert_events_emitted!(Event::CandidateCommissionChangeRequested {
+				candidate: 1,
+				commission: Perbill::from_percent(10),
+				execute_round: 3,
+			});
+			assert_noop!(
+				ParachainStaking::schedule_candidate_commission_change(
+					RuntimeOrigin::signed(1),
+					Perbill::from_percent(15)
+				),
+				Error::<Test>::PendingCommissionRequestAlreadyExists
+			);
+			assert_noop!(
+				ParachainStaking::execute_candidate_commission_change(RuntimeOrigin::signed(2), 1),
+				Error::<Test>::PendingCommissionRequestNotDueYet
+			);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_candidate_commission_change(
+				RuntimeOrigin::signed(2),
+				1
+			));
+			assert_events_emitted!(Event::CandidateCommissionChanged {
+				candidate: 1,
+				old: Perbill::from_percent(20),
+				new: Perbill::from_percent(10),
+			});
+			let info = ParachainStaking::candidate_info(1).unwrap();
+			assert_eq!(info.commission, Some(Perbill::from_percent(10)));
+			assert!(info.commission_request.is_none());
+
+			// the commission is clamped to the bounds set afterwards
+			assert_ok!(ParachainStaking::set_candidate_commission_bounds(
+				RuntimeOrigin::root(),
+				CommissionBounds {
+					min: Perbill::from_percent(15),
+					max: Perbill::from_percent(30),
+				}
+			));
+			assert_eq!(
+				info.effective_commission::<Test>(ParachainStaking::collator_commission()),
+				Perbill::from_percent(15)
+			);
+		});
+}
+
+#[test]
+fn cancel_candidate_commission_change_removes_request() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 30)])
+		.with_candidates(vec![(1, 30)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::cancel_candidate_commission_change(RuntimeOrigin::signed(1)),
+				Error::<Test>::PendingCommissionRequestDNE
+			);
+			assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+				RuntimeOrigin::signed(1),
+				Perbill::from_percent(10)
+			));
+			assert_ok!(ParachainStaking::cancel_candidate_commission_change(
+				RuntimeOrigin::signed(1)
+			));
+			assert_events_emitted!(Event::CancelledCandidateCommissionChange {
+				candidate: 1,
+				commission: Perbill::from_percent(10),
+				execute_round: 3,
+			});
+			let info = ParachainStaking::candidate_info(1).unwrap();
+			assert!(info.commission.is_none());
+			assert!(info.commission_request.is_none());
+		});
+}
+
+#[test]
+fn paid_collator_commission_matches_candidate_commission() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100), (3, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10), (3, 1, 10)])
+		.build()
+		.execute_with(|| {
+			// the collator takes all the rewards with a commission of 100%
+			assert_ok!(ParachainStaking::schedule_candidate_commission_change(
+				RuntimeOrigin::signed(1),
+				Perbill::one()
+			));
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_candidate_commission_change(
+				RuntimeOrigin::signed(1),
+				1
+			));
+
+			// round 3 was selected with the previous commission
+			set_author(3, 1, POINTS_PER_ROUND);
+			roll_to_round_begin(5);
+			roll_blocks(1);
+			assert_events_emitted_match!(Event::Rewarded { account: 2, .. });
+			assert_events_emitted_match!(Event::Rewarded { account: 3, .. });
+
+			set_author(4, 1, POINTS_PER_ROUND);
+			roll_to_round_begin(6);
+			roll_blocks(1);
+			assert_events_emitted_match!(Event::Rewarded { account: 1, .. });
+			assert!(!crate::mock::events()
+				.into_iter()
+				.any(|event| matches!(event, Event::Rewarded { account: 2 | 3, .. })));
+		});
+}
+
+#[test]
+fn paid_collator_commission_is_snapshotted_at_selection() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.with_delegations(vec![(2, 1, 10)])
+		.build()
+		.execute_with(|| {
+			let snapshot_commission = |round| {
+				ParachainStaking::at_stake(round, 1)
+					.unwrap_or_default()
+					.commission
+			};
+			assert_eq!(snapshot_commission(1), Perbill::from_percent(20));
+			assert_ok!(ParachainStaking::set_candidate_commission_bounds(
+				RuntimeOrigin::root(),
+				CommissionBounds {
+					min: Perbill::from_percent(50),
+					max: Perbill::one(),
+				}
+			));
+			// the bounds apply to the rounds selected afterwards
+			assert_eq!(snapshot_commission(1), Perbill::from_percent(20));
+			roll_to_round_begin(2);
+			assert_eq!(snapshot_commission(2), Perbill::from_percent(50));
+		});
+}
+
+#[test]
+fn create_pool_validates_candidates() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
+		.with_candidates(vec![(1, 20), (2, 20), (3, 20), (4, 20)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::create_pool(RuntimeOrigin::signed(1), vec![1]),
+				DispatchError::BadOrigin
+			);
+			assert_noop!(
+				ParachainStaking::create_pool(RuntimeOrigin::root(), vec![]),
+				Error::<Test>::InvalidPoolCandidates
+			);
+			assert_noop!(
+				ParachainStaking::create_pool(RuntimeOrigin::root(), vec![1, 1]),
+				Error::<Test>::InvalidPoolCandidates
+			);
+			assert_noop!(
+				ParachainStaking::create_pool(RuntimeOrigin::root(), vec![1, 2, 3, 4]),
+				Error::<Test>::TooManyPoolCandidates
+			);
+			assert_noop!(
+				ParachainStaking::create_pool(RuntimeOrigin::root(), vec![1, 5]),
+				Error::<Test>::CandidateDNE
+			);
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			assert_events_emitted!(Event::PoolCreated {
+				pool: 0,
+				account: ParachainStaking::pool_account(0),
+				candidates: vec![1, 2],
+			});
+			assert_eq!(crate::NextPoolId::<Test>::get(), 1);
+		});
+}
+
+#[test]
+fn pool_members_join_unbond_and_withdraw() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (10, 100), (11, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			let account = ParachainStaking::pool_account(0);
+
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				10
+			));
+			assert_events_emitted!(Event::JoinedPool {
+				pool: 0,
+				member: 10,
+				amount: 10,
+				shares: 10,
+			});
+			// the idle stake goes to the candidate with the lowest pool delegation
+			assert_ok!(ParachainStaking::join_pool(RuntimeOrigin::signed(11), 0, 5));
+			let state = ParachainStaking::delegator_state(account).unwrap();
+			assert_eq!(state.get_bond_amount(&1), Some(10));
+			assert_eq!(state.get_bond_amount(&2), Some(5));
+			assert_eq!(crate::PoolShares::<Test>::get(0, 10), 10);
+			assert_eq!(crate::PoolShares::<Test>::get(0, 11), 5);
+			assert_eq!(ParachainStaking::pool_value(0), 15);
+
+			// decreasing the delegations is not enough, the smallest one is revoked
+			assert_ok!(ParachainStaking::unbond_from_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				10
+			));
+			assert_events_emitted!(Event::UnbondedFromPool {
+				pool: 0,
+				member: 10,
+				shares: 10,
+				amount: 10,
+				when_executable: 3,
+			});
+			assert!(ParachainStaking::delegation_request_revoke_exists(
+				&2, &account
+			));
+			assert_eq!(crate::PoolShares::<Test>::get(0, 10), 0);
+			assert_eq!(ParachainStaking::pool_shares_value(0, 5), 5);
+
+			assert_noop!(
+				ParachainStaking::withdraw_unbonded_from_pool(RuntimeOrigin::signed(10), 0),
+				Error::<Test>::PoolUnbondingNotDueYet
+			);
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(
+				RuntimeOrigin::signed(10),
+				0
+			));
+			assert_events_emitted!(Event::WithdrawnFromPool {
+				pool: 0,
+				member: 10,
+				amount: 10,
+			});
+			assert_eq!(Balances::free_balance(&10), 100);
+			let state = ParachainStaking::delegator_state(account).unwrap();
+			assert_eq!(state.get_bond_amount(&1), Some(5));
+			assert_eq!(state.get_bond_amount(&2), None);
+			assert_eq!(ParachainStaking::pool_value(0), 5);
+			assert!(crate::PoolUnbondings::<Test>::get(0, 10).is_none());
+		});
+}
+
+#[test]
+fn pool_shares_can_be_transferred_and_approved() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (10, 100), (11, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				10
+			));
+			// stake below MinDelegation increases an existing pool delegation
+			assert_ok!(ParachainStaking::join_pool(RuntimeOrigin::signed(11), 0, 2));
+			let state = ParachainStaking::delegator_state(ParachainStaking::pool_account(0));
+			assert_eq!(state.unwrap().get_bond_amount(&1), Some(12));
+
+			assert_noop!(
+				ParachainStaking::transfer_pool_shares(RuntimeOrigin::signed(11), 0, 12, 3),
+				Error::<Test>::InsufficientPoolShares
+			);
+			assert_ok!(ParachainStaking::transfer_pool_shares(
+				RuntimeOrigin::signed(10),
+				0,
+				11,
+				4
+			));
+			assert_events_emitted!(Event::PoolSharesTransferred {
+				pool: 0,
+				from: 10,
+				to: 11,
+				amount: 4,
+			});
+			assert_eq!(crate::PoolShares::<Test>::get(0, 10), 6);
+			assert_eq!(crate::PoolShares::<Test>::get(0, 11), 6);
+
+			assert_noop!(
+				ParachainStaking::transfer_pool_shares_from(
+					RuntimeOrigin::signed(12),
+					0,
+					10,
+					12,
+					3
+				),
+				Error::<Test>::PoolSharesAllowanceExceeded
+			);
+			assert_ok!(ParachainStaking::approve_pool_shares(
+				RuntimeOrigin::signed(10),
+				0,
+				12,
+				3
+			));
+			assert_events_emitted!(Event::PoolSharesApproved {
+				pool: 0,
+				owner: 10,
+				spender: 12,
+				amount: 3,
+			});
+			assert_ok!(ParachainStaking::transfer_pool_shares_from(
+				RuntimeOrigin::signed(12),
+				0,
+				10,
+				12,
+				3
+			));
+			assert_eq!(crate::PoolShareApprovals::<Test>::get((0, 10, 12)), 0);
+			assert_eq!(crate::PoolShares::<Test>::get(0, 10), 3);
+			assert_eq!(crate::PoolShares::<Test>::get(0, 12), 3);
+		});
+}
+
+#[test]
+fn rebalance_pool_moves_stake_away_from_offline_candidate() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (10, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			let account = ParachainStaking::pool_account(0);
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				10
+			));
+			assert_eq!(
+				ParachainStaking::delegator_state(account)
+					.unwrap()
+					.get_bond_amount(&1),
+				Some(10)
+			);
+
+			assert_ok!(ParachainStaking::go_offline(RuntimeOrigin::signed(1)));
+			assert_ok!(ParachainStaking::rebalance_pool(
+				RuntimeOrigin::signed(10),
+				0
+			));
+			assert_events_emitted!(Event::PoolRebalanced {
+				pool: 0,
+				revoked: 1,
+				delegated: 0,
+			});
+			assert!(ParachainStaking::delegation_request_revoke_exists(
+				&1, &account
+			));
+			// the stake still belongs to the members while the revocation is pending
+			assert_eq!(ParachainStaking::pool_value(0), 10);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::rebalance_pool(
+				RuntimeOrigin::signed(10),
+				0
+			));
+			assert_events_emitted!(Event::PoolRebalanced {
+				pool: 0,
+				revoked: 0,
+				delegated: 10,
+			});
+			let state = ParachainStaking::delegator_state(account).unwrap();
+			assert_eq!(state.get_bond_amount(&1), None);
+			assert_eq!(state.get_bond_amount(&2), Some(10));
+			assert_eq!(ParachainStaking::pool_value(0), 10);
+		});
+}
+
+#[test]
+fn stake_transferred_to_pool_account_does_not_inflate_shares() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (10, 100), (11, 1_000), (12, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			let account = ParachainStaking::pool_account(0);
+			assert_ok!(ParachainStaking::join_pool(RuntimeOrigin::signed(10), 0, 3));
+			// a donation to the pool account is not stake of the members
+			assert_ok!(Balances::transfer_allow_death(
+				RuntimeOrigin::signed(11),
+				account,
+				999
+			));
+			assert_eq!(ParachainStaking::pool_value(0), 3);
+
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(12),
+				0,
+				10
+			));
+			assert_events_emitted!(Event::JoinedPool {
+				pool: 0,
+				member: 12,
+				amount: 10,
+				shares: 10,
+			});
+			assert_eq!(ParachainStaking::pool_value(0), 13);
+			assert_eq!(ParachainStaking::pool_shares_value(0, 3), 3);
+			let state = ParachainStaking::delegator_state(account).unwrap();
+			assert_eq!(state.total(), 13);
+		});
+}
+
+#[test]
+fn pool_undelegation_executed_by_anyone_is_accounted() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 20), (2, 20), (10, 100), (11, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![1, 2]
+			));
+			let account = ParachainStaking::pool_account(0);
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				10
+			));
+			assert_ok!(ParachainStaking::unbond_from_pool(
+				RuntimeOrigin::signed(10),
+				0,
+				4
+			));
+			assert_eq!(ParachainStaking::pool_value(0), 6);
+
+			roll_to_round_begin(3);
+			assert_ok!(ParachainStaking::execute_delegation_request(
+				RuntimeOrigin::signed(11),
+				account,
+				1
+			));
+			assert_eq!(ParachainStaking::pool_value(0), 6);
+			assert_ok!(ParachainStaking::withdraw_unbonded_from_pool(
+				RuntimeOrigin::signed(10),
+				0
+			));
+			assert_eq!(Balances::free_balance(&10), 94);
+			assert_eq!(ParachainStaking::pool_value(0), 6);
+			let info = crate::Pools::<Test>::get(0).unwrap();
+			assert_eq!((info.free, info.undelegating, info.unbonding), (0, 0, 0));
+		});
+}
+
+fn profile_info(
+	display_name: &[u8],
+	commission_intent: Option<Perbill>,
+) -> CandidateProfileInfo<<Test as crate::Config>::MaxCandidateProfileFieldLength> {
+	CandidateProfileInfo {
+		display_name: BoundedVec::truncate_from(display_name.to_vec()),
+		website: BoundedVec::truncate_from(b"https://collator.example".to_vec()),
+		contact: BoundedVec::truncate_from(b"ops@collator.example".to_vec()),
+		region: BoundedVec::truncate_from(b"EU".to_vec()),
+		commission_intent,
+	}
+}
+
+#[test]
+fn set_candidate_profile_holds_deposit_once() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100)])
+		.with_candidates(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::set_candidate_profile(
+				RuntimeOrigin::signed(1),
+				profile_info(b"Alice", Some(Perbill::from_percent(10)))
+			));
+			assert_events_eq!(Event::CandidateProfileSet {
+				candidate: 1,
+				deposit: 10,
+			});
+			assert_eq!(Balances::total_balance_on_hold(&1), 10);
+
+			// updating the profile does not hold the deposit again
+			assert_ok!(ParachainStaking::set_candidate_profile(
+				RuntimeOrigin::signed(1),
+				profile_info(b"Alice Staking", None)
+			));
+			assert_eq!(Balances::total_balance_on_hold(&1), 10);
+			assert_eq!(
+				CandidateProfiles::<Test>::get(1),
+				Some(CandidateProfile {
+					deposit: 10,
+					info: profile_info(b"Alice Staking", None),
+				})
+			);
+		});
+}
+
+#[test]
+fn set_candidate_profile_requires_candidate_and_commission_within_bounds() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::set_candidate_profile(
+					RuntimeOrigin::signed(2),
+					profile_info(b"Bob", None)
+				),
+				Error::<Test>::CandidateDNE
+			);
+			assert_ok!(ParachainStaking::set_candidate_commission_bounds(
+				RuntimeOrigin::root(),
+				crate::CommissionBounds {
+					min: Perbill::from_percent(5),
+					max: Perbill::from_percent(20),
+				}
+			));
+			assert_noop!(
+				ParachainStaking::set_candidate_profile(
+					RuntimeOrigin::signed(1),
+					profile_info(b"Alice", Some(Perbill::from_percent(50)))
+				),
+				Error::<Test>::CommissionOutOfBounds
+			);
+		});
+}
+
+#[test]
+fn candidate_profile_deposit_is_released_when_cleared_or_leaving() {
+	ExtBuilder::default()
+		.with_balances(vec![(1, 100), (2, 100)])
+		.with_candidates(vec![(1, 20), (2, 20)])
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				ParachainStaking::clear_candidate_profile(RuntimeOrigin::signed(1)),
+				Error::<Test>::CandidateProfileDNE
+			);
+			assert_ok!(ParachainStaking::set_candidate_profile(
+				RuntimeOrigin::signed(1),
+				profile_info(b"Alice", None)
+			));
+			assert_ok!(ParachainStaking::clear_candidate_profile(
+				RuntimeOrigin::signed(1)
+			));
+			assert_events_emitted!(Event::CandidateProfileCleared {
+				candidate: 1,
+				deposit: 10,
+			});
+			assert_eq!(Balances::total_balance_on_hold(&1), 0);
+			assert!(!CandidateProfiles::<Test>::contains_key(1));
+
+			assert_ok!(ParachainStaking::set_candidate_profile(
+				RuntimeOrigin::signed(2),
+				profile_info(b"Bob", None)
+			));
+			assert_ok!(ParachainStaking::schedule_leave_candidates(
+				RuntimeOrigin::signed(2),
+				2u32
+			));
+			roll_to(10);
+			assert_ok!(ParachainStaking::execute_leave_candidates(
+				RuntimeOrigin::signed(2),
+				2,
+				0
+			));
+			assert_events_emitted!(Event::CandidateProfileCleared {
+				candidate: 2,
+				deposit: 10,
+			});
+			assert_eq!(Balances::total_balance_on_hold(&2), 0);
+			assert!(!CandidateProfiles::<Test>::contains_key(2));
+		});
+}
@@ -46,6 +46,14 @@ impl OnNewRound for () {
 	}
 }
 
+/// Handler to notify the runtime when a delegation pool is created.
+pub trait OnPoolCreated {
+	fn on_pool_created(pool: crate::PoolId);
+}
+impl OnPoolCreated for () {
+	fn on_pool_created(_pool: crate::PoolId) {}
+}
+
 /// Defines the behavior to payout the collator's reward.
 pub trait PayoutCollatorReward<Runtime: crate::Config> {
 	fn payout_collator_reward(
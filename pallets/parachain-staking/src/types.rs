@@ -18,8 +18,8 @@
 
 use crate::{
 	auto_compound::AutoCompoundDelegations, set::OrderedSet, BalanceOf, BottomDelegations,
-	CandidateInfo, Config, DelegatorState, Error, Event, Pallet, Round, RoundIndex, TopDelegations,
-	Total,
+	CandidateCommissionBounds, CandidateInfo, CollatorCommission, Config, DelegatorState, Error,
+	Event, Pallet, Round, RoundIndex, TopDelegations, Total,
 };
 use frame_support::pallet_prelude::*;
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -129,11 +129,16 @@ pub struct CollatorSnapshot<AccountId, Balance> {
 	/// The total counted value locked for the collator, including the self bond + total staked by
 	/// top delegators.
 	pub total: Balance,
+
+	/// The commission rate charged by the collator on the rewards of the round.
+	pub commission: Perbill,
 }
 
 impl<A: PartialEq, B: PartialEq> PartialEq for CollatorSnapshot<A, B> {
 	fn eq(&self, other: &Self) -> bool {
-		let must_be_true = self.bond == other.bond && self.total == other.total;
+		let must_be_true = self.bond == other.bond
+			&& self.total == other.total
+			&& self.commission == other.commission;
 		if !must_be_true {
 			return false;
 		}
@@ -164,6 +169,7 @@ impl<A, B: Default> Default for CollatorSnapshot<A, B> {
 			bond: B::default(),
 			delegations: Vec::new(),
 			total: B::default(),
+			commission: Perbill::zero(),
 		}
 	}
 }
@@ -179,6 +185,27 @@ pub struct DelayedPayout<Balance> {
 	pub collator_commission: Perbill,
 }
 
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Reward paid to a delegator for its delegation to a collator in a given round
+pub struct DelegatorReward<AccountId, Balance> {
+	/// Collator the delegation is made to
+	pub collator: AccountId,
+	/// Amount minted to the delegator
+	pub amount: Balance,
+	/// Part of the amount compounded back into the delegation
+	pub compounded: Balance,
+}
+
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+/// Collator selected for a round, with the stake backing it when it was selected
+pub struct SelectedCollator<AccountId, Balance> {
+	pub collator: AccountId,
+	/// Self bond + sum of top delegations, without the pending revocations and decreases
+	pub total_counted: Balance,
+	/// Number of top delegators of the collator, rewarded for the round
+	pub delegator_count: u32,
+}
+
 #[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
 /// Request scheduled to change the collator candidate self-bond
 pub struct CandidateBondLessRequest<Balance> {
@@ -289,6 +316,49 @@ pub enum CapacityStatus {
 	Partial,
 }
 
+#[derive(PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+/// Request scheduled to change the commission rate of a collator candidate
+pub struct CandidateCommissionRequest {
+	pub commission: Perbill,
+	pub when_executable: RoundIndex,
+}
+
+#[derive(
+	Clone,
+	Copy,
+	Eq,
+	PartialEq,
+	Encode,
+	Decode,
+	RuntimeDebug,
+	TypeInfo,
+	MaxEncodedLen,
+	DecodeWithMemTracking,
+)]
+/// Inclusive bounds of the commission rate candidates can set
+pub struct CommissionBounds {
+	pub min: Perbill,
+	pub max: Perbill,
+}
+
+impl Default for CommissionBounds {
+	fn default() -> CommissionBounds {
+		CommissionBounds {
+			min: Perbill::zero(),
+			max: Perbill::one(),
+		}
+	}
+}
+
+impl CommissionBounds {
+	pub fn contains(&self, commission: Perbill) -> bool {
+		self.min <= commission && commission <= self.max
+	}
+	pub fn clamp(&self, commission: Perbill) -> Perbill {
+		commission.max(self.min).min(self.max)
+	}
+}
+
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
 /// All candidate info except the top and bottom delegations
 pub struct CandidateMetadata<Balance> {
@@ -312,6 +382,10 @@ pub struct CandidateMetadata<Balance> {
 	pub request: Option<CandidateBondLessRequest<Balance>>,
 	/// Current status of the collator
 	pub status: CollatorStatus,
+	/// Commission rate set by the candidate, the global `CollatorCommission` applies if `None`
+	pub commission: Option<Perbill>,
+	/// Maximum 1 pending request to change the commission rate at any given time
+	pub commission_request: Option<CandidateCommissionRequest>,
 }
 
 impl<
@@ -337,6 +411,8 @@ impl<
 			bottom_capacity: CapacityStatus::Empty,
 			request: None,
 			status: CollatorStatus::Active,
+			commission: None,
+			commission_request: None,
 		}
 	}
 	pub fn is_active(&self) -> bool {
@@ -485,6 +561,70 @@ impl<
 		Pallet::<T>::deposit_event(event);
 		Ok(())
 	}
+	/// Commission rate applied to the rewards of the candidate, within the current bounds.
+	/// `global` is the rate of candidates which did not set theirs.
+	pub fn effective_commission<T: Config>(&self, global: Perbill) -> Perbill {
+		<CandidateCommissionBounds<T>>::get().clamp(self.commission.unwrap_or(global))
+	}
+	/// Schedule a change of the commission rate of the candidate
+	pub fn schedule_commission_change<T: Config>(
+		&mut self,
+		commission: Perbill,
+	) -> Result<RoundIndex, DispatchError> {
+		// ensure no pending request
+		ensure!(
+			self.commission_request.is_none(),
+			Error::<T>::PendingCommissionRequestAlreadyExists
+		);
+		ensure!(
+			<CandidateCommissionBounds<T>>::get().contains(commission),
+			Error::<T>::CommissionOutOfBounds
+		);
+		let when_executable = <Round<T>>::get().current + T::CandidateCommissionChangeDelay::get();
+		self.commission_request = Some(CandidateCommissionRequest {
+			commission,
+			when_executable,
+		});
+		Ok(when_executable)
+	}
+	/// Execute pending request to change the commission rate of the candidate
+	pub fn execute_commission_change<T: Config>(&mut self, who: T::AccountId) -> DispatchResult {
+		let request = self
+			.commission_request
+			.ok_or(Error::<T>::PendingCommissionRequestDNE)?;
+		ensure!(
+			request.when_executable <= <Round<T>>::get().current,
+			Error::<T>::PendingCommissionRequestNotDueYet
+		);
+		// bounds may have changed since the request was scheduled
+		ensure!(
+			<CandidateCommissionBounds<T>>::get().contains(request.commission),
+			Error::<T>::CommissionOutOfBounds
+		);
+		let old = self.effective_commission::<T>(<CollatorCommission<T>>::get());
+		self.commission = Some(request.commission);
+		// reset s.t. no pending request
+		self.commission_request = None;
+		Pallet::<T>::deposit_event(Event::CandidateCommissionChanged {
+			candidate: who,
+			old,
+			new: request.commission,
+		});
+		Ok(())
+	}
+	/// Cancel pending request to change the commission rate of the candidate
+	pub fn cancel_commission_change<T: Config>(&mut self, who: T::AccountId) -> DispatchResult {
+		let request = self
+			.commission_request
+			.ok_or(Error::<T>::PendingCommissionRequestDNE)?;
+		self.commission_request = None;
+		Pallet::<T>::deposit_event(Event::CancelledCandidateCommissionChange {
+			candidate: who,
+			commission: request.commission,
+			execute_round: request.when_executable,
+		});
+		Ok(())
+	}
 	/// Reset top delegations metadata
 	pub fn reset_top_data<T: Config>(
 		&mut self,
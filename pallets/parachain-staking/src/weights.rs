@@ -83,6 +83,22 @@ pub trait WeightInfo {
 	fn mint_collator_reward() -> Weight;
 	fn notify_inactive_collator() -> Weight;
 	fn mark_collators_as_inactive(x: u32, ) -> Weight;
+	fn create_pool(x: u32, ) -> Weight;
+	fn set_pool_candidates(x: u32, ) -> Weight;
+	fn transfer_pool_shares() -> Weight;
+	fn approve_pool_shares() -> Weight;
+	fn transfer_pool_shares_from() -> Weight;
+	fn set_slash_fractions() -> Weight;
+	fn report_misbehaviour() -> Weight;
+	fn cancel_slash() -> Weight;
+	fn apply_slash(x: u32, ) -> Weight;
+	fn set_candidate_commission_bounds() -> Weight;
+	fn schedule_candidate_commission_change() -> Weight;
+	fn execute_candidate_commission_change() -> Weight;
+	fn cancel_candidate_commission_change() -> Weight;
+	fn set_candidate_profile(x: u32, ) -> Weight;
+	fn clear_candidate_profile(x: u32, ) -> Weight;
+	fn execute_all_matured_requests(x: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_parachain_staking` using the Substrate node and recommended hardware.
@@ -826,6 +842,295 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:0 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318 + x * (77 ±0)`
+		//  Estimated: `1803 + x * (2553 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(13_870_264, 1803)
+			// Standard Error: 7_823
+			.saturating_add(Weight::from_parts(3_411_025, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn set_pool_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + x * (77 ±0)`
+		//  Estimated: `3932 + x * (2553 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(15_266_118, 3932)
+			// Standard Error: 8_214
+			.saturating_add(Weight::from_parts(3_482_390, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `387`
+		//  Estimated: `6327`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 6327)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `3694`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3694)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `6461`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 6461)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slash_fractions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1515)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:0)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:1 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn report_misbehaviour() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:2 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `6203`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6203)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:301 w:301)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:301 w:301)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:0 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 300]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1248 + x * (493 ±0)`
+		//  Estimated: `6120 + x * (3633 ±0)`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(68_417_251, 6120)
+			// Standard Error: 33_419
+			.saturating_add(Weight::from_parts(61_304_887, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3633).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_candidate_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1515)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn schedule_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3936)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CollatorCommission` (r:1 w:0)
+	/// Proof: `ParachainStaking::CollatorCommission` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn execute_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `509`
+		//  Estimated: `3974`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3974)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `3941`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3941)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn set_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(44_126_310, 4066)
+			// Standard Error: 1_154
+			.saturating_add(Weight::from_parts(2_087, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn clear_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + x * (1 ±0)`
+		//  Estimated: `3867 + x * (1 ±0)`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_851_944, 3867)
+			// Standard Error: 986
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:100 w:100)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1287), added: 3762, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:100 w:0)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:100 w:100)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsPerCollator` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsPerCollator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 100]`.
+	fn execute_all_matured_requests(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1712 + x * (3291 ±0)`
+		//  Estimated: `6130 + x * (3512 ±0)`
+		// Minimum execution time: 97_318_000 picoseconds.
+		Weight::from_parts(62_407_000, 6130)
+			// Standard Error: 112_004
+			.saturating_add(Weight::from_parts(48_216_918, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3512).saturating_mul(x.into()))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -1582,4 +1887,293 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2579).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:0 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `318 + x * (77 ±0)`
+		//  Estimated: `1803 + x * (2553 ±0)`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(13_870_264, 1803)
+			// Standard Error: 7_823
+			.saturating_add(Weight::from_parts(3_411_025, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn set_pool_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `467 + x * (77 ±0)`
+		//  Estimated: `3932 + x * (2553 ±0)`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(15_266_118, 3932)
+			// Standard Error: 8_214
+			.saturating_add(Weight::from_parts(3_482_390, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `387`
+		//  Estimated: `6327`
+		// Minimum execution time: 21_000_000 picoseconds.
+		Weight::from_parts(23_000_000, 6327)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `3694`
+		// Minimum execution time: 11_000_000 picoseconds.
+		Weight::from_parts(12_000_000, 3694)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `521`
+		//  Estimated: `6461`
+		// Minimum execution time: 27_000_000 picoseconds.
+		Weight::from_parts(29_000_000, 6461)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slash_fractions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1515)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:0)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:1 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn report_misbehaviour() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_000_000 picoseconds.
+		Weight::from_parts(18_000_000, 3977)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:2 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263`
+		//  Estimated: `6203`
+		// Minimum execution time: 14_000_000 picoseconds.
+		Weight::from_parts(15_000_000, 6203)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:301 w:301)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:301 w:301)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:0 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 300]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1248 + x * (493 ±0)`
+		//  Estimated: `6120 + x * (3633 ±0)`
+		// Minimum execution time: 71_000_000 picoseconds.
+		Weight::from_parts(68_417_251, 6120)
+			// Standard Error: 33_419
+			.saturating_add(Weight::from_parts(61_304_887, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3633).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_candidate_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 7_000_000 picoseconds.
+		Weight::from_parts(8_000_000, 1515)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn schedule_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3936`
+		// Minimum execution time: 16_000_000 picoseconds.
+		Weight::from_parts(17_000_000, 3936)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CollatorCommission` (r:1 w:0)
+	/// Proof: `ParachainStaking::CollatorCommission` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn execute_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `509`
+		//  Estimated: `3974`
+		// Minimum execution time: 18_000_000 picoseconds.
+		Weight::from_parts(19_000_000, 3974)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `476`
+		//  Estimated: `3941`
+		// Minimum execution time: 13_000_000 picoseconds.
+		Weight::from_parts(14_000_000, 3941)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn set_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `601`
+		//  Estimated: `4066`
+		// Minimum execution time: 42_000_000 picoseconds.
+		Weight::from_parts(44_126_310, 4066)
+			// Standard Error: 1_154
+			.saturating_add(Weight::from_parts(2_087, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn clear_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `402 + x * (1 ±0)`
+		//  Estimated: `3867 + x * (1 ±0)`
+		// Minimum execution time: 36_000_000 picoseconds.
+		Weight::from_parts(37_851_944, 3867)
+			// Standard Error: 986
+			.saturating_add(Weight::from_parts(1_312, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:100 w:100)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1287), added: 3762, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:100 w:0)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:100 w:100)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsPerCollator` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsPerCollator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 100]`.
+	fn execute_all_matured_requests(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1712 + x * (3291 ±0)`
+		//  Estimated: `6130 + x * (3512 ±0)`
+		// Minimum execution time: 97_318_000 picoseconds.
+		Weight::from_parts(62_407_000, 6130)
+			// Standard Error: 112_004
+			.saturating_add(Weight::from_parts(48_216_918, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3512).saturating_mul(x.into()))
+	}
 }
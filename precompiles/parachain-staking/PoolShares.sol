@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @title Delegation pool shares interface
+/// @dev ERC20 interface of the shares of a delegation pool of parachain-staking.
+/// The shares of pool `id` live at the address made of the prefix 0xfffffffd,
+/// 12 zero bytes and `id` as a big endian uint32, e.g. pool 1 at
+/// 0xFFFFFFFD00000000000000000000000000000001
+interface PoolShares {
+    /// @dev Returns the name of the token.
+    /// @custom:selector 06fdde03
+    function name() external view returns (string memory);
+
+    /// @dev Returns the symbol of the token.
+    /// @custom:selector 95d89b41
+    function symbol() external view returns (string memory);
+
+    /// @dev Returns the decimals places of the token.
+    /// @custom:selector 313ce567
+    function decimals() external view returns (uint8);
+
+    /// @dev Total number of shares of the pool
+    /// @custom:selector 18160ddd
+    function totalSupply() external view returns (uint256);
+
+    /// @dev Gets the shares of the pool owned by the specified address.
+    /// @custom:selector 70a08231
+    /// @param owner The address to query the shares of.
+    /// @return An uint256 representing the amount of shares owned by the passed address.
+    function balanceOf(address owner) external view returns (uint256);
+
+    /// @dev Function to check the amount of shares that an owner allowed to a spender.
+    /// @custom:selector dd62ed3e
+    /// @param owner address The address which owns the shares.
+    /// @param spender address The address which will spend the shares.
+    /// @return A uint256 specifying the amount of shares still available for the spender.
+    function allowance(address owner, address spender)
+        external
+        view
+        returns (uint256);
+
+    /// @dev Transfer shares to a specified address
+    /// @custom:selector a9059cbb
+    /// @param to The address to transfer to.
+    /// @param value The amount of shares to be transferred.
+    /// @return true if the transfer was succesful, revert otherwise.
+    function transfer(address to, uint256 value) external returns (bool);
+
+    /// @dev Approve the passed address to spend the specified amount of shares on behalf of msg.sender.
+    /// @custom:selector 095ea7b3
+    /// @param spender The address which will spend the shares.
+    /// @param value The amount of shares to be spent.
+    /// @return true, this cannot fail
+    function approve(address spender, uint256 value) external returns (bool);
+
+    /// @dev Transfer shares from one address to another
+    /// @custom:selector 23b872dd
+    /// @param from address The address which you want to send shares from
+    /// @param to address The address which you want to transfer to
+    /// @param value uint256 the amount of shares to be transferred
+    /// @return true if the transfer was succesful, revert otherwise.
+    function transferFrom(
+        address from,
+        address to,
+        uint256 value
+    ) external returns (bool);
+
+    /// @dev Event emitted when a transfer has been performed.
+    /// @custom:selector ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef
+    /// @param from address The address sending the shares
+    /// @param to address The address receiving the shares.
+    /// @param value uint256 The amount of shares transfered.
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// @dev Event emitted when an approval has been registered.
+    /// @custom:selector 8c5be1e5ebec7d5bd14f71427d1e84f3dd0314c0f7b2291e5b200ac8c7c3b925
+    /// @param owner address Owner of the shares.
+    /// @param spender address Allowed spender.
+    /// @param value uint256 Amount of shares approved.
+    event Approval(
+        address indexed owner,
+        address indexed spender,
+        uint256 value
+    );
+}
@@ -16,6 +16,55 @@ ParachainStaking constant PARACHAIN_STAKING_CONTRACT = ParachainStaking(
 /// wraps the pallet
 /// @custom:address 0x0000000000000000000000000000000000000800
 interface ParachainStaking {
+    /// @dev Reward paid to a delegator for its delegation to a collator in a round
+    struct DelegatorReward {
+        /// The round the reward was paid for
+        uint32 round;
+        /// The collator the delegation is made to
+        address collator;
+        /// The amount minted to the delegator
+        uint256 amount;
+        /// The part of the amount compounded back into the delegation
+        uint256 compounded;
+    }
+
+    /// @dev Collator selected for a round
+    struct SelectedCollator {
+        /// The address of the collator
+        address collator;
+        /// The self bond and top delegations of the collator when it was selected,
+        /// without the pending revocations and decreases
+        uint256 totalCounted;
+        /// The number of top delegators of the collator when it was selected
+        uint32 delegatorCount;
+    }
+
+    /// @dev Self-declared information of a candidate
+    struct CandidateProfileInfo {
+        /// The display name of the candidate
+        bytes displayName;
+        /// The website of the candidate
+        bytes website;
+        /// How to contact the candidate
+        bytes contact;
+        /// The region the collator node is located in
+        bytes region;
+        /// Whether the candidate declared the commission it intends to charge
+        bool hasCommissionIntent;
+        /// The commission the candidate intends to charge, in parts per billion
+        uint32 commissionIntent;
+    }
+
+    /// @dev Profile of a candidate
+    struct CandidateProfile {
+        /// Whether the candidate has a profile
+        bool isValid;
+        /// The deposit held for the profile
+        uint256 deposit;
+        /// The information of the profile
+        CandidateProfileInfo info;
+    }
+
     /// @dev Check whether the specified address is currently a staking delegator
     /// @custom:selector fd8ab482
     /// @param delegator the address that we want to confirm is a delegator
@@ -136,6 +185,22 @@ interface ParachainStaking {
         address candidate
     ) external view returns (bool);
 
+    /// @dev Get the commission rate of a candidate
+    /// @custom:selector 5224b5cc
+    /// @param candidate the address of the candidate
+    /// @return The commission rate of the candidate, in parts per billion
+    function candidateCommission(
+        address candidate
+    ) external view returns (uint32);
+
+    /// @dev Get the profile of a candidate
+    /// @custom:selector 209eccfb
+    /// @param candidate the address of the candidate
+    /// @return The profile of the candidate, with isValid false if it has none
+    function candidateProfile(
+        address candidate
+    ) external view returns (CandidateProfile memory);
+
     /// @dev Whether there exists a pending bond less request made by a candidate
     /// @custom:selector d0deec11
     /// @param candidate the candidate which made the request
@@ -206,6 +271,29 @@ interface ParachainStaking {
     /// @custom:selector b5ad5f07
     function cancelCandidateBondLess() external;
 
+    /// @dev Request by collator candidate to change its commission rate
+    /// @custom:selector 1dafcca2
+    /// @param commission The new commission rate, in parts per billion
+    function scheduleCandidateCommissionChange(uint256 commission) external;
+
+    /// @dev Execute pending candidate commission change request (if exists && is due)
+    /// @custom:selector 2f542f22
+    /// @param candidate The address of the candidate
+    function executeCandidateCommissionChange(address candidate) external;
+
+    /// @dev Cancel pending candidate commission change request
+    /// @custom:selector 03fb301e
+    function cancelCandidateCommissionChange() external;
+
+    /// @dev Set the profile of the caller candidate, holding a deposit for it
+    /// @custom:selector cb2d31d3
+    /// @param info The information of the profile
+    function setCandidateProfile(CandidateProfileInfo memory info) external;
+
+    /// @dev Clear the profile of the caller candidate and release its deposit
+    /// @custom:selector b579422f
+    function clearCandidateProfile() external;
+
     /// @dev Make a delegation in support of a collator candidate
     /// @custom:selector 4b8bc9bf
     /// @param candidate The address of the supported collator candidate
@@ -253,11 +341,34 @@ interface ParachainStaking {
         address candidate
     ) external;
 
+    /// @dev Execute up to maxRequests matured delegation requests of a delegator
+    /// @custom:selector 95871cdc
+    /// @param delegator The address of the delegator
+    /// @param maxRequests The maximum number of requests to execute
+    function executeAllMaturedRequests(
+        address delegator,
+        uint256 maxRequests
+    ) external;
+
     /// @dev Cancel pending delegation request (already made in support of input by caller)
     /// @custom:selector c90eee83
     /// @param candidate The address of the candidate
     function cancelDelegationRequest(address candidate) external;
 
+    /// @dev Move a delegation of the caller to another candidate without unbonding it
+    /// @custom:selector ff54d0f3
+    /// @param fromCandidate The address of the currently delegated candidate
+    /// @param toCandidate The address of the candidate to move the delegation to
+    /// @param candidateDelegationCount The number of delegations in support of toCandidate
+    /// @param candidateAutoCompoundingDelegationCount The number of auto-compounding delegations
+    /// in support of toCandidate
+    function redelegate(
+        address fromCandidate,
+        address toCandidate,
+        uint256 candidateDelegationCount,
+        uint256 candidateAutoCompoundingDelegationCount
+    ) external;
+
     /// @dev Sets an auto-compound value for a delegation
     /// @custom:selector faa1786f
     /// @param candidate The address of the supported collator candidate
@@ -288,4 +399,52 @@ interface ParachainStaking {
     function getCandidateTotalCounted(
         address candidate
     ) external view returns (uint256);
+
+    /// @dev Fetch the rewards paid to a delegator in the rounds kept by the
+    /// reward history, from the oldest to the most recent round.
+    /// Empty if the reward history is disabled.
+    /// @custom:selector d7a4cc38
+    /// @param delegator Address of the delegator.
+    /// @return The rewards paid to the delegator.
+    function getDelegatorRewardHistory(
+        address delegator
+    ) external view returns (DelegatorReward[] memory);
+
+    /// @dev Fetch the collators selected for a round, ordered by address.
+    /// Empty if the round is not kept by the selection history.
+    /// @custom:selector 97bfc3e3
+    /// @param round The round index
+    /// @return The collators selected for the round
+    function getRoundSelection(
+        uint256 round
+    ) external view returns (SelectedCollator[] memory);
+
+    /// @dev Fetch the stake of the members of a delegation pool, rewards included
+    /// @custom:selector a04a4d53
+    /// @param pool The id of the pool
+    /// @return The stake the shares of the pool are worth
+    function poolValue(uint256 pool) external view returns (uint256);
+
+    /// @dev Join a delegation pool, receiving shares of it
+    /// @custom:selector 108c8492
+    /// @param pool The id of the pool
+    /// @param amount The amount to stake in the pool
+    function joinPool(uint256 pool, uint256 amount) external;
+
+    /// @dev Unbond shares of a delegation pool
+    /// @custom:selector cea79232
+    /// @param pool The id of the pool
+    /// @param shares The amount of shares to unbond
+    function unbondFromPool(uint256 pool, uint256 shares) external;
+
+    /// @dev Withdraw the stake unbonded from a delegation pool
+    /// @custom:selector 42e91b49
+    /// @param pool The id of the pool
+    function withdrawUnbondedFromPool(uint256 pool) external;
+
+    /// @dev Revoke the delegations of a pool to ineligible candidates and
+    /// delegate its idle stake
+    /// @custom:selector d64356ab
+    /// @param pool The id of the pool
+    function rebalancePool(uint256 pool) external;
 }
@@ -20,13 +20,19 @@
 
 #[cfg(test)]
 mod mock;
+mod pool_shares;
 #[cfg(test)]
 mod tests;
 
+pub use pool_shares::{
+	pool_of_shares_address, pool_shares_address, PoolSharesErc20PrecompileSet,
+	POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX,
+};
+
 use fp_evm::PrecompileHandle;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
 use frame_support::pallet_prelude::MaxEncodedLen;
-use frame_support::sp_runtime::Percent;
+use frame_support::sp_runtime::{Perbill, Percent};
 use frame_support::traits::{fungible::Inspect, Get};
 use pallet_evm::AddressMapping;
 use pallet_parachain_staking::ScheduledRequest;
@@ -138,8 +144,8 @@ where
 		candidate: Address,
 	) -> EvmResult<u32> {
 		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
-		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
-		handle.record_db_read::<Runtime>(133)?;
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
 		let result = if let Some(state) =
 			<pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
 		{
@@ -319,8 +325,8 @@ where
 	fn is_candidate(handle: &mut impl PrecompileHandle, candidate: Address) -> EvmResult<bool> {
 		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
 
-		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
-		handle.record_db_read::<Runtime>(133)?;
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
 		let is_candidate = pallet_parachain_staking::Pallet::<Runtime>::is_candidate(&candidate);
 
 		Ok(is_candidate)
@@ -392,8 +398,8 @@ where
 	) -> EvmResult<bool> {
 		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
 
-		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
-		handle.record_db_read::<Runtime>(133)?;
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
 
 		// If we are not able to get delegator state, we return false
 		// Users can call `is_candidate` to determine when this happens
@@ -422,8 +428,8 @@ where
 	) -> EvmResult<bool> {
 		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
 
-		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
-		handle.record_db_read::<Runtime>(133)?;
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
 
 		// If we are not able to get candidate metadata, we return false
 		// Users can call `is_candidate` to determine when this happens
@@ -443,6 +449,75 @@ where
 		Ok(pending)
 	}
 
+	#[precompile::public("candidateCommission(address)")]
+	#[precompile::view]
+	fn candidate_commission(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<u32> {
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
+		// CollatorCommission: Perbill(4)
+		handle.record_db_read::<Runtime>(4)?;
+		// CandidateCommissionBounds: CommissionBounds(8)
+		handle.record_db_read::<Runtime>(8)?;
+
+		// If we are not able to get candidate metadata, we return 0
+		// Users can call `is_candidate` to determine when this happens
+		let commission = if let Some(state) =
+			<pallet_parachain_staking::Pallet<Runtime>>::candidate_info(&candidate)
+		{
+			state
+				.effective_commission::<Runtime>(
+					<pallet_parachain_staking::Pallet<Runtime>>::collator_commission(),
+				)
+				.deconstruct()
+		} else {
+			log::trace!(
+				target: "staking-precompile",
+				"Candidate metadata for {:?} not found, so commission is 0",
+				candidate
+			);
+			0
+		};
+
+		Ok(commission)
+	}
+
+	#[precompile::public("candidateProfile(address)")]
+	#[precompile::view]
+	fn candidate_profile(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult<CandidateProfile<Runtime::MaxCandidateProfileFieldLength>> {
+		handle.record_db_read::<Runtime>(Self::candidate_profile_storage_read_proof_size())?;
+
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+		let Some(profile) = pallet_parachain_staking::CandidateProfiles::<Runtime>::get(&candidate)
+		else {
+			return Ok(Default::default());
+		};
+
+		let info = profile.info;
+		Ok(CandidateProfile {
+			is_valid: true,
+			deposit: profile.deposit.into(),
+			info: CandidateProfileInfo {
+				display_name: info.display_name.into_inner().into(),
+				website: info.website.into_inner().into(),
+				contact: info.contact.into_inner().into(),
+				region: info.region.into_inner().into(),
+				has_commission_intent: info.commission_intent.is_some(),
+				commission_intent: info
+					.commission_intent
+					.map(|commission| commission.deconstruct())
+					.unwrap_or_default(),
+			},
+		})
+	}
+
 	#[precompile::public("delegationAutoCompound(address,address)")]
 	#[precompile::view]
 	fn delegation_auto_compound(
@@ -697,6 +772,117 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("scheduleCandidateCommissionChange(uint256)")]
+	fn schedule_candidate_commission_change(
+		handle: &mut impl PrecompileHandle,
+		commission: Convert<U256, u32>,
+	) -> EvmResult {
+		let commission = commission.converted();
+		if commission > Perbill::one().deconstruct() {
+			return Err(RevertReason::custom("Must be at most 1_000_000_000 (100%)")
+				.in_field("commission")
+				.into());
+		}
+		let commission = Perbill::from_parts(commission);
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call =
+			pallet_parachain_staking::Call::<Runtime>::schedule_candidate_commission_change {
+				commission,
+			};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("executeCandidateCommissionChange(address)")]
+	fn execute_candidate_commission_change(
+		handle: &mut impl PrecompileHandle,
+		candidate: Address,
+	) -> EvmResult {
+		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::execute_candidate_commission_change {
+			candidate,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("cancelCandidateCommissionChange()")]
+	fn cancel_candidate_commission_change(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::cancel_candidate_commission_change {};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("setCandidateProfile((bytes,bytes,bytes,bytes,bool,uint32))")]
+	fn set_candidate_profile(
+		handle: &mut impl PrecompileHandle,
+		info: CandidateProfileInfo<Runtime::MaxCandidateProfileFieldLength>,
+	) -> EvmResult {
+		let info = Self::profile_info_to_input(info)?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::set_candidate_profile { info };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("clearCandidateProfile()")]
+	fn clear_candidate_profile(handle: &mut impl PrecompileHandle) -> EvmResult {
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::clear_candidate_profile {};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("delegateWithAutoCompound(address,uint256,uint8,uint256,uint256,uint256)")]
 	fn delegate_with_auto_compound(
 		handle: &mut impl PrecompileHandle,
@@ -853,6 +1039,33 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("executeAllMaturedRequests(address,uint256)")]
+	fn execute_all_matured_requests(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+		max_requests: Convert<U256, u32>,
+	) -> EvmResult {
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+		let max_requests = max_requests.converted();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::execute_all_matured_requests {
+			delegator,
+			max_requests,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("cancelDelegationRequest(address)")]
 	#[precompile::public("cancel_delegation_request(address)")]
 	fn cancel_delegation_request(
@@ -877,6 +1090,40 @@ where
 		Ok(())
 	}
 
+	#[precompile::public("redelegate(address,address,uint256,uint256)")]
+	fn redelegate(
+		handle: &mut impl PrecompileHandle,
+		from_candidate: Address,
+		to_candidate: Address,
+		candidate_delegation_count: Convert<U256, u32>,
+		candidate_auto_compounding_delegation_count: Convert<U256, u32>,
+	) -> EvmResult {
+		let from_candidate = Runtime::AddressMapping::into_account_id(from_candidate.0);
+		let to_candidate = Runtime::AddressMapping::into_account_id(to_candidate.0);
+		let candidate_delegation_count = candidate_delegation_count.converted();
+		let candidate_auto_compounding_delegation_count =
+			candidate_auto_compounding_delegation_count.converted();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::redelegate {
+			from_candidate,
+			to_candidate,
+			candidate_delegation_count,
+			candidate_auto_compounding_delegation_count,
+		};
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
 	#[precompile::public("setAutoCompound(address,uint8,uint256,uint256)")]
 	fn set_auto_compound(
 		handle: &mut impl PrecompileHandle,
@@ -943,8 +1190,8 @@ where
 		handle: &mut impl PrecompileHandle,
 		candidate: Address,
 	) -> EvmResult<U256> {
-		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(105)
-		handle.record_db_read::<Runtime>(133)?;
+		// CandidateInfo: Twox64Concat(8) + AccountId(20) + CandidateMetadata(119)
+		handle.record_db_read::<Runtime>(147)?;
 
 		let candidate = Runtime::AddressMapping::into_account_id(candidate.0);
 
@@ -955,12 +1202,263 @@ where
 		Ok(amount.into())
 	}
 
+	#[precompile::public("getDelegatorRewardHistory(address)")]
+	#[precompile::view]
+	fn get_delegator_reward_history(
+		handle: &mut impl PrecompileHandle,
+		delegator: Address,
+	) -> EvmResult<Vec<DelegatorReward>> {
+		// Round: RoundInfo(RoundIndex(4) + BlockNumber(4) + 4)
+		handle.record_db_read::<Runtime>(12)?;
+		// RewardHistory: one read per retained round
+		let retained =
+			<Runtime as pallet_parachain_staking::Config>::RewardHistoryRounds::get() as usize;
+		for _ in 0..retained {
+			handle.record_db_read::<Runtime>(Self::reward_history_storage_read_proof_size())?;
+		}
+
+		let delegator = Runtime::AddressMapping::into_account_id(delegator.0);
+
+		let history =
+			<pallet_parachain_staking::Pallet<Runtime>>::delegator_reward_history(&delegator)
+				.into_iter()
+				.map(|(round, reward)| DelegatorReward {
+					round,
+					collator: Address(reward.collator.into()),
+					amount: reward.amount.into(),
+					compounded: reward.compounded.into(),
+				})
+				.collect();
+
+		Ok(history)
+	}
+
+	#[precompile::public("getRoundSelection(uint256)")]
+	#[precompile::view]
+	fn get_round_selection(
+		handle: &mut impl PrecompileHandle,
+		round: Convert<U256, u32>,
+	) -> EvmResult<Vec<SelectedCollator>> {
+		handle.record_db_read::<Runtime>(Self::selection_history_storage_read_proof_size())?;
+
+		let selection =
+			<pallet_parachain_staking::Pallet<Runtime>>::round_selection(round.converted())
+				.into_iter()
+				.map(|selected| SelectedCollator {
+					collator: Address(selected.collator.into()),
+					total_counted: selected.total_counted.into(),
+					delegator_count: selected.delegator_count,
+				})
+				.collect();
+
+		Ok(selection)
+	}
+
+	#[precompile::public("poolValue(uint256)")]
+	#[precompile::view]
+	fn pool_value(handle: &mut impl PrecompileHandle, pool: Convert<U256, u32>) -> EvmResult<U256> {
+		handle.record_db_read::<Runtime>(Self::pool_storage_read_proof_size())?;
+		handle.record_db_read::<Runtime>(Self::delegator_state_storage_read_proof_size())?;
+		// DelegationScheduledRequests of each delegation of the pool, see
+		// `delegation_request_is_pending`
+		let max_d =
+			<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+		let max_requests =
+			<Runtime as pallet_parachain_staking::Config>::MaxScheduledRequestsPerDelegator::get();
+		handle.record_db_read::<Runtime>(
+			(72 + ScheduledRequest::<BalanceOf<Runtime>>::max_encoded_len()
+				* (max_requests as usize))
+				.saturating_mul(max_d as usize),
+		)?;
+		// System.Account(148)
+		handle.record_db_read::<Runtime>(148)?;
+		// Balances.Freezes: Blake2_128Concat(16) + AccountId(20) + Freezes(1 + 2 * 18)
+		handle.record_db_read::<Runtime>(73)?;
+
+		let value = <pallet_parachain_staking::Pallet<Runtime>>::pool_value(pool.converted());
+
+		Ok(value.into())
+	}
+
+	#[precompile::public("joinPool(uint256,uint256)")]
+	fn join_pool(
+		handle: &mut impl PrecompileHandle,
+		pool: Convert<U256, u32>,
+		amount: U256,
+	) -> EvmResult {
+		let pool = pool.converted();
+		let amount = Self::u256_to_amount(amount).in_field("amount")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::join_pool { pool, amount };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("unbondFromPool(uint256,uint256)")]
+	fn unbond_from_pool(
+		handle: &mut impl PrecompileHandle,
+		pool: Convert<U256, u32>,
+		shares: U256,
+	) -> EvmResult {
+		let pool = pool.converted();
+		let shares = Self::u256_to_amount(shares).in_field("shares")?;
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::unbond_from_pool { pool, shares };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("withdrawUnbondedFromPool(uint256)")]
+	fn withdraw_unbonded_from_pool(
+		handle: &mut impl PrecompileHandle,
+		pool: Convert<U256, u32>,
+	) -> EvmResult {
+		let pool = pool.converted();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::withdraw_unbonded_from_pool { pool };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
+	#[precompile::public("rebalancePool(uint256)")]
+	fn rebalance_pool(handle: &mut impl PrecompileHandle, pool: Convert<U256, u32>) -> EvmResult {
+		let pool = pool.converted();
+
+		// Build call with origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let call = pallet_parachain_staking::Call::<Runtime>::rebalance_pool { pool };
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch(
+			handle,
+			frame_system::RawOrigin::Signed(origin).into(),
+			call,
+			0,
+		)?;
+
+		Ok(())
+	}
+
 	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
 		value
 			.try_into()
 			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
 	}
 
+	/// Proof-size upper bound for one read of [`pallet_parachain_staking::RewardHistory`]
+	/// (`Twox64Concat` round + `Blake2_128Concat` delegator key, full `BoundedVec` value).
+	pub(crate) fn reward_history_storage_read_proof_size() -> usize {
+		let max_d =
+			<Runtime as pallet_parachain_staking::Config>::MaxDelegationsPerDelegator::get();
+		let rewards_bytes = (max_d as usize)
+			.saturating_mul(pallet_parachain_staking::DelegatorReward::<
+			Runtime::AccountId,
+			BalanceOf<Runtime>,
+		>::max_encoded_len());
+		// Twox64Concat(8) + RoundIndex(4) + Blake2_128Concat(16) + AccountId + compact len + rewards
+		(8 + 4 + 16)
+			.saturating_add(Runtime::AccountId::max_encoded_len())
+			.saturating_add(Compact(max_d).encode().len())
+			.saturating_add(rewards_bytes)
+	}
+
+	/// Proof-size upper bound for one read of [`pallet_parachain_staking::SelectionHistory`]
+	/// (`Twox64Concat` round key, full `BoundedVec` value).
+	pub(crate) fn selection_history_storage_read_proof_size() -> usize {
+		let max_c = <Runtime as pallet_parachain_staking::Config>::MaxCandidates::get();
+		let selection_bytes = (max_c as usize)
+			.saturating_mul(pallet_parachain_staking::SelectedCollator::<
+			Runtime::AccountId,
+			BalanceOf<Runtime>,
+		>::max_encoded_len());
+		// Twox64Concat(8) + RoundIndex(4) + compact len + selection
+		(8 + 4)
+			.saturating_add(Compact(max_c).encode().len())
+			.saturating_add(selection_bytes)
+	}
+
+	/// Proof-size upper bound for one read of [`pallet_parachain_staking::CandidateProfiles`]
+	/// (`Twox64Concat` + `AccountId` key, max-sized profile value).
+	pub(crate) fn candidate_profile_storage_read_proof_size() -> usize {
+		// Twox64Concat(8) + AccountId + CandidateProfile
+		8usize
+			.saturating_add(Runtime::AccountId::max_encoded_len())
+			.saturating_add(pallet_parachain_staking::CandidateProfile::<
+				BalanceOf<Runtime>,
+				Runtime::MaxCandidateProfileFieldLength,
+			>::max_encoded_len())
+	}
+
+	fn profile_info_to_input(
+		info: CandidateProfileInfo<Runtime::MaxCandidateProfileFieldLength>,
+	) -> MayRevert<
+		pallet_parachain_staking::CandidateProfileInfo<Runtime::MaxCandidateProfileFieldLength>,
+	> {
+		let commission_intent = if info.has_commission_intent {
+			if info.commission_intent > Perbill::one().deconstruct() {
+				return Err(RevertReason::custom("Must be at most 1_000_000_000 (100%)")
+					.in_field("commissionIntent"));
+			}
+			Some(Perbill::from_parts(info.commission_intent))
+		} else {
+			None
+		};
+
+		// Field lengths are already bounded when decoding the input
+		let field = |bytes: BoundedBytes<Runtime::MaxCandidateProfileFieldLength>| {
+			sp_runtime::BoundedVec::truncate_from(Vec::from(bytes))
+		};
+		Ok(pallet_parachain_staking::CandidateProfileInfo {
+			display_name: field(info.display_name),
+			website: field(info.website),
+			contact: field(info.contact),
+			region: field(info.region),
+			commission_intent,
+		})
+	}
+
+	/// Proof-size upper bound for one read of [`pallet_parachain_staking::Pools`]
+	/// (`Twox64Concat` pool key, pool delegating to `MaxPoolCandidates` candidates).
+	pub(crate) fn pool_storage_read_proof_size() -> usize {
+		let max_c = <Runtime as pallet_parachain_staking::Config>::MaxPoolCandidates::get();
+		// Twox64Concat(8) + PoolId(4) + compact len + candidates + total_shares + unbonding
+		// + free + undelegating
+		(8 + 4)
+			.saturating_add(Compact(max_c).encode().len())
+			.saturating_add((max_c as usize).saturating_mul(Runtime::AccountId::max_encoded_len()))
+			.saturating_add(BalanceOf::<Runtime>::max_encoded_len().saturating_mul(4))
+	}
+
 	/// Proof-size upper bound for one read of [`pallet_parachain_staking::Pallet::delegator_state`]
 	/// storage (`Twox64Concat` + `AccountId` key, max-sized SCALE `Delegator` value).
 	pub(crate) fn delegator_state_storage_read_proof_size() -> usize {
@@ -986,3 +1484,62 @@ where
 			.saturating_add(value_max)
 	}
 }
+
+/// Information of a candidate profile, as taken by `setCandidateProfile`
+#[derive(Debug, Eq, PartialEq, solidity::Codec)]
+pub struct CandidateProfileInfo<FieldLimit> {
+	display_name: BoundedBytes<FieldLimit>,
+	website: BoundedBytes<FieldLimit>,
+	contact: BoundedBytes<FieldLimit>,
+	region: BoundedBytes<FieldLimit>,
+	has_commission_intent: bool,
+	commission_intent: u32,
+}
+
+impl<T> Default for CandidateProfileInfo<T> {
+	fn default() -> Self {
+		Self {
+			display_name: Default::default(),
+			website: Default::default(),
+			contact: Default::default(),
+			region: Default::default(),
+			has_commission_intent: false,
+			commission_intent: 0,
+		}
+	}
+}
+
+/// Profile of a candidate, as returned by `candidateProfile`
+#[derive(Debug, Eq, PartialEq, solidity::Codec)]
+pub struct CandidateProfile<FieldLimit> {
+	is_valid: bool,
+	deposit: U256,
+	info: CandidateProfileInfo<FieldLimit>,
+}
+
+impl<T> Default for CandidateProfile<T> {
+	fn default() -> Self {
+		Self {
+			is_valid: false,
+			deposit: Default::default(),
+			info: Default::default(),
+		}
+	}
+}
+
+/// Collator selected for a round, as returned by `getRoundSelection`
+#[derive(Default, solidity::Codec)]
+pub struct SelectedCollator {
+	collator: Address,
+	total_counted: U256,
+	delegator_count: u32,
+}
+
+/// Reward paid to a delegator for a round, as returned by `getDelegatorRewardHistory`
+#[derive(Default, solidity::Codec)]
+pub struct DelegatorReward {
+	round: u32,
+	collator: Address,
+	amount: U256,
+	compounded: U256,
+}
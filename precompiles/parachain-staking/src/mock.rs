@@ -20,6 +20,7 @@ use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{Everything, Get, OnFinalize, OnInitialize, VariantCountOf},
 	weights::Weight,
+	PalletId,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
@@ -48,7 +49,7 @@ construct_runtime!(
 		Balances: pallet_balances,
 		Evm: pallet_evm,
 		Timestamp: pallet_timestamp,
-		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, FreezeReason},
+		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, FreezeReason, HoldReason},
 	}
 );
 
@@ -106,7 +107,7 @@ impl pallet_balances::Config for Runtime {
 	type ExistentialDeposit = ExistentialDeposit;
 	type AccountStore = System;
 	type WeightInfo = ();
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = VariantCountOf<Self::RuntimeFreezeReason>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
@@ -130,12 +131,23 @@ parameter_types! {
 		block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
 	};
 	pub SuicideQuickClearLimit: u32 = 0;
+	pub PoolSharesPrefix: &'static [u8] = &POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX;
 }
 
-pub type Precompiles<R> =
-	PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, ParachainStakingPrecompile<R>>,)>;
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<AddressU64<1>, ParachainStakingPrecompile<R>>,
+		PrecompileSetStartingWith<
+			PoolSharesPrefix,
+			PoolSharesErc20PrecompileSet<R>,
+			CallableByContract,
+		>,
+	),
+>;
 
 pub type PCall = ParachainStakingPrecompileCall<Runtime>;
+pub type PoolSharesCall = PoolSharesErc20PrecompileSetCall<Runtime>;
 
 impl pallet_evm::Config for Runtime {
 	type FeeCalculator = ();
@@ -182,6 +194,7 @@ parameter_types! {
 	pub const MaxOfflineRounds: u32 = 2;
 	pub const LeaveCandidatesDelay: u32 = 2;
 	pub const CandidateBondLessDelay: u32 = 2;
+	pub const CandidateCommissionChangeDelay: u32 = 2;
 	pub const LeaveDelegatorsDelay: u32 = 2;
 	pub const RevokeDelegationDelay: u32 = 2;
 	pub const DelegationBondLessDelay: u32 = 2;
@@ -191,9 +204,17 @@ parameter_types! {
 	pub const MaxBottomDelegationsPerCandidate: u32 = 4;
 	pub const MaxDelegationsPerDelegator: u32 = 4;
 	pub const MaxScheduledRequestsPerDelegator: u32 = 50;
+	pub const RewardHistoryRounds: u32 = 2;
+	pub const SelectionHistoryRounds: u32 = 2;
+	pub const RedelegationCooldown: u32 = 2;
+	pub const SlashDeferDuration: u32 = 1;
 	pub const MinCandidateStk: u128 = 10;
 	pub const MinDelegation: u128 = 3;
 	pub const MaxCandidates: u32 = 10;
+	pub const PoolsPalletId: PalletId = PalletId(*b"pc/stkpl");
+	pub const MaxPoolCandidates: u32 = 3;
+	pub const CandidateProfileDeposit: u128 = 10;
+	pub const MaxCandidateProfileFieldLength: u32 = 32;
 	pub BlockAuthor: AccountId = Alice.into();
 }
 
@@ -208,14 +229,20 @@ impl Get<Slot> for StakingRoundSlotProvider {
 impl pallet_parachain_staking::Config for Runtime {
 	type Currency = Balances;
 	type MonetaryGovernanceOrigin = frame_system::EnsureRoot<AccountId>;
+	type SlashingOrigin = frame_system::EnsureRoot<AccountId>;
 	type MinBlocksPerRound = MinBlocksPerRound;
 	type MaxOfflineRounds = MaxOfflineRounds;
 	type LeaveCandidatesDelay = LeaveCandidatesDelay;
 	type CandidateBondLessDelay = CandidateBondLessDelay;
+	type CandidateCommissionChangeDelay = CandidateCommissionChangeDelay;
 	type LeaveDelegatorsDelay = LeaveDelegatorsDelay;
 	type RevokeDelegationDelay = RevokeDelegationDelay;
+	type RedelegationCooldown = RedelegationCooldown;
+	type SlashDeferDuration = SlashDeferDuration;
 	type DelegationBondLessDelay = DelegationBondLessDelay;
 	type RewardPaymentDelay = RewardPaymentDelay;
+	type RewardHistoryRounds = RewardHistoryRounds;
+	type SelectionHistoryRounds = SelectionHistoryRounds;
 	type MinSelectedCandidates = MinSelectedCandidates;
 	type MaxTopDelegationsPerCandidate = MaxTopDelegationsPerCandidate;
 	type MaxBottomDelegationsPerCandidate = MaxBottomDelegationsPerCandidate;
@@ -228,12 +255,18 @@ impl pallet_parachain_staking::Config for Runtime {
 	type OnCollatorPayout = ();
 	type OnInactiveCollator = ();
 	type OnNewRound = ();
+	type OnPoolCreated = ();
 	type SlotProvider = StakingRoundSlotProvider;
 	type WeightInfo = ();
 	type MaxCandidates = MaxCandidates;
+	type PoolsPalletId = PoolsPalletId;
+	type MaxPoolCandidates = MaxPoolCandidates;
+	type CandidateProfileDeposit = CandidateProfileDeposit;
+	type MaxCandidateProfileFieldLength = MaxCandidateProfileFieldLength;
 	type SlotDuration = frame_support::traits::ConstU64<6_000>;
 	type BlockTime = frame_support::traits::ConstU64<6_000>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type LinearInflationThreshold = ();
 }
 
@@ -0,0 +1,308 @@
+// Copyright 2019-2025 PureStake Inc.
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile set exposing the shares of each delegation pool as an ERC20 token.
+//!
+//! The shares of pool `id` live at the address made of
+//! [POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX], 12 zero bytes and `id` as a big endian `u32`.
+
+extern crate alloc;
+
+use crate::{BalanceOf, ParachainStakingPrecompile};
+use alloc::format;
+use fp_evm::PrecompileHandle;
+use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
+use frame_support::pallet_prelude::MaxEncodedLen;
+use pallet_evm::AddressMapping;
+use pallet_parachain_staking::PoolId;
+use precompile_utils::{precompile_set::DiscriminantResult, prelude::*};
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::marker::PhantomData;
+
+/// Prefix of the addresses of the pool shares precompiles
+pub const POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX: [u8; 4] = [255u8, 255u8, 255u8, 253u8];
+
+/// Solidity selector of the Transfer log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// Solidity selector of the Approval log, which is the Keccak of the Log signature.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Address of the ERC20 precompile of the shares of `pool`
+pub fn pool_shares_address(pool: PoolId) -> H160 {
+	let mut address = [0u8; 20];
+	address[..4].copy_from_slice(&POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX);
+	address[16..].copy_from_slice(&pool.to_be_bytes());
+	H160(address)
+}
+
+/// Pool whose shares are exposed at `address`, if it is a pool shares address
+pub fn pool_of_shares_address(address: H160) -> Option<PoolId> {
+	let (prefix, rest) = address.as_bytes().split_at(4);
+	let (zeros, pool) = rest.split_at(12);
+	if prefix != POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX || zeros.iter().any(|byte| *byte != 0) {
+		return None;
+	}
+	let mut bytes = [0u8; 4];
+	bytes.copy_from_slice(pool);
+	Some(PoolId::from_be_bytes(bytes))
+}
+
+/// ERC20 precompiles of the shares of the delegation pools
+pub struct PoolSharesErc20PrecompileSet<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> Default for PoolSharesErc20PrecompileSet<Runtime> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+
+#[precompile_utils::precompile]
+#[precompile::precompile_set]
+impl<Runtime> PoolSharesErc20PrecompileSet<Runtime>
+where
+	Runtime: pallet_parachain_staking::Config + pallet_evm::Config,
+	Runtime::AccountId: Into<H160> + MaxEncodedLen,
+	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+	Runtime::RuntimeCall: From<pallet_parachain_staking::Call<Runtime>>,
+	BalanceOf<Runtime>: TryFrom<U256> + Into<U256> + solidity::Codec + MaxEncodedLen,
+	<Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+	#[precompile::discriminant]
+	fn discriminant(address: H160, gas: u64) -> DiscriminantResult<PoolId> {
+		let extra_cost = RuntimeHelper::<Runtime>::db_read_gas_cost();
+		if gas < extra_cost {
+			return DiscriminantResult::OutOfGas;
+		}
+
+		match pool_of_shares_address(address) {
+			Some(pool) if pallet_parachain_staking::Pools::<Runtime>::contains_key(pool) => {
+				DiscriminantResult::Some(pool, extra_cost)
+			}
+			_ => DiscriminantResult::None(extra_cost),
+		}
+	}
+
+	#[precompile::public("name()")]
+	#[precompile::view]
+	fn name(pool: PoolId, _handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+		Ok(format!("Delegation Pool {} Shares", pool).as_bytes().into())
+	}
+
+	#[precompile::public("symbol()")]
+	#[precompile::view]
+	fn symbol(pool: PoolId, _handle: &mut impl PrecompileHandle) -> EvmResult<UnboundedBytes> {
+		Ok(format!("POOL{}", pool).as_bytes().into())
+	}
+
+	#[precompile::public("decimals()")]
+	#[precompile::view]
+	fn decimals(_pool: PoolId, _handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		// the first shares of a pool are minted one for one against the native token
+		Ok(18)
+	}
+
+	#[precompile::public("totalSupply()")]
+	#[precompile::view]
+	fn total_supply(pool: PoolId, handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		handle.record_db_read::<Runtime>(
+			ParachainStakingPrecompile::<Runtime>::pool_storage_read_proof_size(),
+		)?;
+
+		let total_shares = pallet_parachain_staking::Pools::<Runtime>::get(pool)
+			.map(|info| info.total_shares)
+			.unwrap_or_default();
+
+		Ok(total_shares.into())
+	}
+
+	#[precompile::public("balanceOf(address)")]
+	#[precompile::view]
+	fn balance_of(
+		pool: PoolId,
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+	) -> EvmResult<U256> {
+		// PoolShares: Twox64Concat(8) + PoolId(4) + Blake2_128Concat(16) + AccountId(20)
+		// + Balance(16)
+		handle.record_db_read::<Runtime>(64)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.0);
+		let shares = pallet_parachain_staking::PoolShares::<Runtime>::get(pool, owner);
+
+		Ok(shares.into())
+	}
+
+	#[precompile::public("allowance(address,address)")]
+	#[precompile::view]
+	fn allowance(
+		pool: PoolId,
+		handle: &mut impl PrecompileHandle,
+		owner: Address,
+		spender: Address,
+	) -> EvmResult<U256> {
+		// PoolShareApprovals: Twox64Concat(8) + PoolId(4) + 2 * (Blake2_128Concat(16)
+		// + AccountId(20)) + Balance(16)
+		handle.record_db_read::<Runtime>(100)?;
+
+		let owner = Runtime::AddressMapping::into_account_id(owner.0);
+		let spender = Runtime::AddressMapping::into_account_id(spender.0);
+		let allowance =
+			pallet_parachain_staking::PoolShareApprovals::<Runtime>::get((pool, owner, spender));
+
+		Ok(allowance.into())
+	}
+
+	#[precompile::public("approve(address,uint256)")]
+	fn approve(
+		pool: PoolId,
+		handle: &mut impl PrecompileHandle,
+		spender: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let spender: H160 = spender.into();
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let spender = Runtime::AddressMapping::into_account_id(spender);
+			let amount = Self::u256_to_amount(value).in_field("value")?;
+			let call = pallet_parachain_staking::Call::<Runtime>::approve_pool_shares {
+				pool,
+				spender,
+				amount,
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				frame_system::RawOrigin::Signed(origin).into(),
+				call,
+				0,
+			)?;
+		}
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_APPROVAL,
+			handle.context().caller,
+			spender,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transfer(address,uint256)")]
+	fn transfer(
+		pool: PoolId,
+		handle: &mut impl PrecompileHandle,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let to: H160 = to.into();
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let dest = Runtime::AddressMapping::into_account_id(to);
+			let amount = Self::u256_to_amount(value).in_field("value")?;
+			let call = pallet_parachain_staking::Call::<Runtime>::transfer_pool_shares {
+				pool,
+				dest,
+				amount,
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				frame_system::RawOrigin::Signed(origin).into(),
+				call,
+				0,
+			)?;
+		}
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_TRANSFER,
+			handle.context().caller,
+			to,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	#[precompile::public("transferFrom(address,address,uint256)")]
+	fn transfer_from(
+		pool: PoolId,
+		handle: &mut impl PrecompileHandle,
+		from: Address,
+		to: Address,
+		value: U256,
+	) -> EvmResult<bool> {
+		handle.record_log_costs_manual(3, 32)?;
+
+		let from: H160 = from.into();
+		let to: H160 = to.into();
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let owner = Runtime::AddressMapping::into_account_id(from);
+			let dest = Runtime::AddressMapping::into_account_id(to);
+			let amount = Self::u256_to_amount(value).in_field("value")?;
+			let call = pallet_parachain_staking::Call::<Runtime>::transfer_pool_shares_from {
+				pool,
+				owner,
+				dest,
+				amount,
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch(
+				handle,
+				frame_system::RawOrigin::Signed(origin).into(),
+				call,
+				0,
+			)?;
+		}
+
+		log3(
+			handle.context().address,
+			SELECTOR_LOG_TRANSFER,
+			from,
+			to,
+			solidity::encode_event_data(value),
+		)
+		.record(handle)?;
+
+		Ok(true)
+	}
+
+	fn u256_to_amount(value: U256) -> MayRevert<BalanceOf<Runtime>> {
+		value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").into())
+	}
+}
@@ -16,14 +16,16 @@
 
 use crate::mock::{
 	events, roll_to, roll_to_round_begin, set_points, AccountId, ExtBuilder, PCall,
-	ParachainStaking, Precompiles, PrecompilesValue, Runtime, RuntimeCall, RuntimeOrigin,
+	ParachainStaking, PoolSharesCall, Precompiles, PrecompilesValue, Runtime, RuntimeCall,
+	RuntimeOrigin,
 };
-use crate::ParachainStakingPrecompile;
+use crate::pool_shares::{SELECTOR_LOG_APPROVAL, SELECTOR_LOG_TRANSFER};
+use crate::{pool_of_shares_address, pool_shares_address, ParachainStakingPrecompile};
 use core::str::from_utf8;
-use fp_evm::MAX_TRANSACTION_GAS_LIMIT;
+use fp_evm::{IsPrecompileResult, PrecompileSet, MAX_TRANSACTION_GAS_LIMIT};
 use frame_support::assert_ok;
 use frame_support::pallet_prelude::MaxEncodedLen;
-use frame_support::sp_runtime::Percent;
+use frame_support::sp_runtime::{Perbill, Percent};
 use frame_support::traits::fungible::Inspect;
 use pallet_evm::Call as EvmCall;
 use pallet_parachain_staking::Event as StakingEvent;
@@ -80,14 +82,30 @@ fn selectors() {
 	assert!(PCall::schedule_candidate_bond_less_selectors().contains(&0x60744ae0));
 	assert!(PCall::execute_candidate_bond_less_selectors().contains(&0x2e290290));
 	assert!(PCall::cancel_candidate_bond_less_selectors().contains(&0xb5ad5f07));
+	assert!(PCall::candidate_commission_selectors().contains(&0x5224b5cc));
+	assert!(PCall::schedule_candidate_commission_change_selectors().contains(&0x1dafcca2));
+	assert!(PCall::execute_candidate_commission_change_selectors().contains(&0x2f542f22));
+	assert!(PCall::cancel_candidate_commission_change_selectors().contains(&0x03fb301e));
 	assert!(PCall::delegate_with_auto_compound_selectors().contains(&0x4b8bc9bf));
 	assert!(PCall::schedule_revoke_delegation_selectors().contains(&0x1a1c740c));
 	assert!(PCall::delegator_bond_more_selectors().contains(&0x0465135b));
 	assert!(PCall::schedule_delegator_bond_less_selectors().contains(&0xc172fd2b));
 	assert!(PCall::execute_delegation_request_selectors().contains(&0xe98c8abe));
 	assert!(PCall::cancel_delegation_request_selectors().contains(&0xc90eee83));
+	assert!(PCall::redelegate_selectors().contains(&0xff54d0f3));
+	assert!(PCall::execute_all_matured_requests_selectors().contains(&0x95871cdc));
 	assert!(PCall::get_delegator_total_staked_selectors().contains(&0xe6861713));
 	assert!(PCall::get_candidate_total_counted_selectors().contains(&0xbc5a1043));
+	assert!(PCall::get_delegator_reward_history_selectors().contains(&0xd7a4cc38));
+	assert!(PCall::get_round_selection_selectors().contains(&0x97bfc3e3));
+	assert!(PCall::candidate_profile_selectors().contains(&0x209eccfb));
+	assert!(PCall::set_candidate_profile_selectors().contains(&0xcb2d31d3));
+	assert!(PCall::clear_candidate_profile_selectors().contains(&0xb579422f));
+	assert!(PCall::pool_value_selectors().contains(&0xa04a4d53));
+	assert!(PCall::join_pool_selectors().contains(&0x108c8492));
+	assert!(PCall::unbond_from_pool_selectors().contains(&0xcea79232));
+	assert!(PCall::withdraw_unbonded_from_pool_selectors().contains(&0x42e91b49));
+	assert!(PCall::rebalance_pool_selectors().contains(&0xd64356ab));
 }
 
 #[test]
@@ -120,6 +138,10 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::schedule_candidate_bond_less_selectors());
 		tester.test_default_modifier(PCall::execute_candidate_bond_less_selectors());
 		tester.test_default_modifier(PCall::cancel_candidate_bond_less_selectors());
+		tester.test_view_modifier(PCall::candidate_commission_selectors());
+		tester.test_default_modifier(PCall::schedule_candidate_commission_change_selectors());
+		tester.test_default_modifier(PCall::execute_candidate_commission_change_selectors());
+		tester.test_default_modifier(PCall::cancel_candidate_commission_change_selectors());
 		tester.test_default_modifier(PCall::delegate_with_auto_compound_selectors());
 		tester.test_default_modifier(PCall::schedule_revoke_delegation_selectors());
 		tester.test_default_modifier(PCall::delegator_bond_more_selectors());
@@ -128,6 +150,15 @@ fn modifiers() {
 		tester.test_default_modifier(PCall::cancel_delegation_request_selectors());
 		tester.test_view_modifier(PCall::get_delegator_total_staked_selectors());
 		tester.test_view_modifier(PCall::get_candidate_total_counted_selectors());
+		tester.test_view_modifier(PCall::get_round_selection_selectors());
+		tester.test_view_modifier(PCall::candidate_profile_selectors());
+		tester.test_default_modifier(PCall::set_candidate_profile_selectors());
+		tester.test_default_modifier(PCall::clear_candidate_profile_selectors());
+		tester.test_view_modifier(PCall::pool_value_selectors());
+		tester.test_default_modifier(PCall::join_pool_selectors());
+		tester.test_default_modifier(PCall::unbond_from_pool_selectors());
+		tester.test_default_modifier(PCall::withdraw_unbonded_from_pool_selectors());
+		tester.test_default_modifier(PCall::rebalance_pool_selectors());
 	});
 }
 
@@ -1168,6 +1199,86 @@ fn execute_candidate_bond_less_works() {
 		});
 }
 
+#[test]
+fn candidate_commission_change_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			// The global commission applies until the candidate sets its own
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::candidate_commission {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(Perbill::from_percent(20).deconstruct());
+
+			let input_data = PCall::schedule_candidate_commission_change {
+				commission: Perbill::from_percent(5).deconstruct().into(),
+			}
+			.into();
+			assert_ok!(
+				RuntimeCall::Evm(evm_call(Alice, input_data)).dispatch(RuntimeOrigin::root())
+			);
+			let expected: crate::mock::RuntimeEvent =
+				StakingEvent::CandidateCommissionChangeRequested {
+					candidate: Alice.into(),
+					commission: Perbill::from_percent(5),
+					execute_round: 3,
+				}
+				.into();
+			assert!(events().contains(&expected));
+
+			roll_to_round_begin(3);
+			let input_data = PCall::execute_candidate_commission_change {
+				candidate: Address(Alice.into()),
+			}
+			.into();
+			assert_ok!(
+				RuntimeCall::Evm(evm_call(Alice, input_data)).dispatch(RuntimeOrigin::root())
+			);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::candidate_commission {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(Perbill::from_percent(5).deconstruct());
+		});
+}
+
+#[test]
+fn schedule_candidate_commission_change_above_one_hundred_percent_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::schedule_candidate_commission_change {
+						commission: 1_000_000_001.into(),
+					},
+				)
+				.execute_reverts(|output| {
+					from_utf8(&output)
+						.unwrap()
+						.contains("commission: Must be at most 1_000_000_000 (100%)")
+				});
+		});
+}
+
 #[test]
 fn cancel_candidate_bond_less_works() {
 	ExtBuilder::default()
@@ -1627,6 +1738,459 @@ fn set_auto_compound_fails_if_not_delegation() {
 		});
 }
 
+#[test]
+fn execute_all_matured_requests_works() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_delegations(vec![
+			(Charlie.into(), Alice.into(), 500),
+			(Charlie.into(), Bob.into(), 500),
+		])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::schedule_revoke_delegation(
+				RuntimeOrigin::signed(Charlie.into()),
+				Alice.into()
+			));
+			assert_ok!(ParachainStaking::schedule_delegator_bond_less(
+				RuntimeOrigin::signed(Charlie.into()),
+				Bob.into(),
+				100
+			));
+			roll_to(10);
+
+			let input_data = PCall::execute_all_matured_requests {
+				delegator: Address(Charlie.into()),
+				max_requests: 10.into(),
+			}
+			.into();
+
+			// Make sure the call goes through successfully
+			assert_ok!(
+				RuntimeCall::Evm(evm_call(Alice, input_data)).dispatch(RuntimeOrigin::root())
+			);
+
+			let expected_revoke: crate::mock::RuntimeEvent = StakingEvent::DelegationRevoked {
+				delegator: Charlie.into(),
+				candidate: Alice.into(),
+				unstaked_amount: 500,
+			}
+			.into();
+			let expected_decrease: crate::mock::RuntimeEvent = StakingEvent::DelegationDecreased {
+				delegator: Charlie.into(),
+				candidate: Bob.into(),
+				amount: 100,
+				in_top: true,
+			}
+			.into();
+			// Assert that the events vector contains the ones expected
+			assert!(events().contains(&expected_revoke));
+			assert!(events().contains(&expected_decrease));
+		});
+}
+
+#[test]
+fn redelegate_works() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 1_000)])
+		.with_delegations(vec![(Charlie.into(), Alice.into(), 500)])
+		.build()
+		.execute_with(|| {
+			let input_data = PCall::redelegate {
+				from_candidate: Address(Alice.into()),
+				to_candidate: Address(Bob.into()),
+				candidate_delegation_count: 0.into(),
+				candidate_auto_compounding_delegation_count: 0.into(),
+			}
+			.into();
+
+			// Make sure the call goes through successfully
+			assert_ok!(
+				RuntimeCall::Evm(evm_call(Charlie, input_data)).dispatch(RuntimeOrigin::root())
+			);
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::Redelegated {
+				delegator: Charlie.into(),
+				from_candidate: Alice.into(),
+				to_candidate: Bob.into(),
+				amount: 500,
+				delegator_position: pallet_parachain_staking::DelegatorAdded::AddedToTop {
+					new_total: 1_500,
+				},
+				auto_compound: Percent::zero(),
+			}
+			.into();
+			// Assert that the events vector contains the one expected
+			assert!(events().contains(&expected));
+		});
+}
+
+#[test]
+fn get_round_selection_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(Alice.into(), 1_000),
+			(Bob.into(), 1_000),
+			(Charlie.into(), 1_000),
+		])
+		.with_candidates(vec![(Alice.into(), 1_000), (Bob.into(), 500)])
+		.with_delegations(vec![(Charlie.into(), Alice.into(), 200)])
+		.build()
+		.execute_with(|| {
+			// the selection at genesis is kept while the delegation grows
+			assert_ok!(ParachainStaking::delegator_bond_more(
+				RuntimeOrigin::signed(Charlie.into()),
+				Alice.into(),
+				100
+			));
+			roll_to_round_begin(2);
+
+			let selection = |alice_total: u32| {
+				let mut selection = vec![
+					crate::SelectedCollator {
+						collator: Address(Alice.into()),
+						total_counted: U256::from(alice_total),
+						delegator_count: 1,
+					},
+					crate::SelectedCollator {
+						collator: Address(Bob.into()),
+						total_counted: U256::from(500),
+						delegator_count: 0,
+					},
+				];
+				selection.sort_by_key(|selected| selected.collator.0);
+				selection
+			};
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_round_selection { round: 1.into() },
+				)
+				.execute_returns(selection(1_200));
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_round_selection { round: 2.into() },
+				)
+				.execute_returns(selection(1_300));
+		});
+}
+
+fn profile_info(
+	commission_intent: Option<u32>,
+) -> crate::CandidateProfileInfo<<Runtime as StakingConfig>::MaxCandidateProfileFieldLength> {
+	crate::CandidateProfileInfo {
+		display_name: b"Alice".to_vec().into(),
+		website: b"https://alice.example".to_vec().into(),
+		contact: b"ops@alice.example".to_vec().into(),
+		region: b"EU".to_vec().into(),
+		has_commission_intent: commission_intent.is_some(),
+		commission_intent: commission_intent.unwrap_or_default(),
+	}
+}
+
+#[test]
+fn set_and_clear_candidate_profile_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::candidate_profile {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.execute_returns(crate::CandidateProfile::default());
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::set_candidate_profile {
+						info: profile_info(Some(100_000_000)),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::candidate_profile {
+						candidate: Address(Alice.into()),
+					},
+				)
+				.execute_returns(crate::CandidateProfile {
+					is_valid: true,
+					deposit: U256::from(10),
+					info: profile_info(Some(100_000_000)),
+				});
+
+			PrecompilesValue::get()
+				.prepare_test(Alice, Precompile1, PCall::clear_candidate_profile {})
+				.expect_no_logs()
+				.execute_returns(());
+			let expected: crate::mock::RuntimeEvent = StakingEvent::CandidateProfileCleared {
+				candidate: Alice.into(),
+				deposit: 10,
+			}
+			.into();
+			assert!(events().contains(&expected));
+		});
+}
+
+#[test]
+fn set_candidate_profile_reverts_if_commission_intent_above_one() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::set_candidate_profile {
+						info: profile_info(Some(1_000_000_001)),
+					},
+				)
+				.execute_reverts(|output| {
+					from_utf8(&output)
+						.unwrap()
+						.contains("commissionIntent: Must be at most 1_000_000_000 (100%)")
+				});
+		});
+}
+
+#[test]
+fn join_pool_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![Alice.into()]
+			));
+
+			let input_data = PCall::join_pool {
+				pool: 0.into(),
+				amount: 500.into(),
+			}
+			.into();
+
+			// Make sure the call goes through successfully
+			assert_ok!(
+				RuntimeCall::Evm(evm_call(Charlie, input_data)).dispatch(RuntimeOrigin::root())
+			);
+
+			let expected: crate::mock::RuntimeEvent = StakingEvent::JoinedPool {
+				pool: 0,
+				member: Charlie.into(),
+				amount: 500,
+				shares: 500,
+			}
+			.into();
+			// Assert that the events vector contains the one expected
+			assert!(events().contains(&expected));
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					pool_shares_address(0),
+					PoolSharesCall::balance_of {
+						owner: Address(Charlie.into()),
+					},
+				)
+				.execute_returns(U256::from(500));
+			PrecompilesValue::get()
+				.prepare_test(Alice, Precompile1, PCall::pool_value { pool: 0.into() })
+				.execute_returns(U256::from(500));
+		});
+}
+
+#[test]
+fn pool_shares_address_round_trips() {
+	let address = pool_shares_address(1);
+	let mut expected = [0u8; 20];
+	expected[..4].copy_from_slice(&[0xff, 0xff, 0xff, 0xfd]);
+	expected[19] = 1;
+	assert_eq!(address, H160(expected));
+	assert_eq!(pool_of_shares_address(address), Some(1));
+	assert_eq!(pool_of_shares_address(Precompile1.into()), None);
+	let mut with_garbage = address;
+	with_garbage.0[8] = 1;
+	assert_eq!(pool_of_shares_address(with_garbage), None);
+}
+
+#[test]
+fn pool_shares_erc20_precompile_exists_for_existing_pools_only() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			let is_precompile = |pool| {
+				matches!(
+					precompiles().is_precompile(pool_shares_address(pool), 100_000),
+					IsPrecompileResult::Answer {
+						is_precompile: true,
+						..
+					}
+				)
+			};
+			assert!(!is_precompile(0));
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![Alice.into()]
+			));
+			assert!(is_precompile(0));
+			assert!(!is_precompile(1));
+		});
+}
+
+#[test]
+fn pool_shares_erc20_precompile_works() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(ParachainStaking::create_pool(
+				RuntimeOrigin::root(),
+				vec![Alice.into()]
+			));
+			assert_ok!(ParachainStaking::join_pool(
+				RuntimeOrigin::signed(Charlie.into()),
+				0,
+				500
+			));
+			let token = pool_shares_address(0);
+
+			precompiles()
+				.prepare_test(Alice, token, PoolSharesCall::name {})
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from("Delegation Pool 0 Shares"));
+			precompiles()
+				.prepare_test(Alice, token, PoolSharesCall::symbol {})
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from("POOL0"));
+			precompiles()
+				.prepare_test(Alice, token, PoolSharesCall::total_supply {})
+				.expect_no_logs()
+				.execute_returns(U256::from(500));
+
+			precompiles()
+				.prepare_test(
+					Charlie,
+					token,
+					PoolSharesCall::transfer {
+						to: Address(Bob.into()),
+						value: 200.into(),
+					},
+				)
+				.expect_log(log3(
+					token,
+					SELECTOR_LOG_TRANSFER,
+					Charlie,
+					Bob,
+					solidity::encode_event_data(U256::from(200)),
+				))
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					token,
+					PoolSharesCall::approve {
+						spender: Address(Alice.into()),
+						value: 150.into(),
+					},
+				)
+				.expect_log(log3(
+					token,
+					SELECTOR_LOG_APPROVAL,
+					Bob,
+					Alice,
+					solidity::encode_event_data(U256::from(150)),
+				))
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					token,
+					PoolSharesCall::transfer_from {
+						from: Address(Bob.into()),
+						to: Address(Alice.into()),
+						value: 100.into(),
+					},
+				)
+				.expect_log(log3(
+					token,
+					SELECTOR_LOG_TRANSFER,
+					Bob,
+					Alice,
+					solidity::encode_event_data(U256::from(100)),
+				))
+				.execute_returns(true);
+
+			precompiles()
+				.prepare_test(
+					Alice,
+					token,
+					PoolSharesCall::allowance {
+						owner: Address(Bob.into()),
+						spender: Address(Alice.into()),
+					},
+				)
+				.execute_returns(U256::from(50));
+			for (owner, shares) in [(Charlie, 300), (Bob, 100), (Alice, 100)] {
+				precompiles()
+					.prepare_test(
+						Alice,
+						token,
+						PoolSharesCall::balance_of {
+							owner: Address(owner.into()),
+						},
+					)
+					.execute_returns(U256::from(shares));
+			}
+
+			precompiles()
+				.prepare_test(
+					Bob,
+					token,
+					PoolSharesCall::transfer {
+						to: Address(Charlie.into()),
+						value: 101.into(),
+					},
+				)
+				.execute_reverts(|output| {
+					from_utf8(output)
+						.unwrap()
+						.contains("InsufficientPoolShares")
+				});
+		});
+}
+
 #[test]
 fn get_delegator_total_staked_getter() {
 	ExtBuilder::default()
@@ -1677,6 +2241,53 @@ fn get_delegator_total_staked_getter_unknown() {
 		});
 }
 
+#[test]
+fn get_delegator_reward_history_getter() {
+	ExtBuilder::default()
+		.with_balances(vec![(Alice.into(), 1_000), (Charlie.into(), 1_000)])
+		.with_candidates(vec![(Alice.into(), 1_000)])
+		.with_delegations(vec![(Charlie.into(), Alice.into(), 500)])
+		.build()
+		.execute_with(|| {
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_delegator_reward_history {
+						delegator: Address(Charlie.into()),
+					},
+				)
+				.execute_returns(Vec::<crate::DelegatorReward>::new());
+
+			// Round 1 is paid in the block after the start of round 3
+			roll_to_round_begin(3);
+			roll_to(frame_system::Pallet::<Runtime>::block_number() + 1);
+
+			let expected: Vec<_> = ParachainStaking::delegator_reward_history(&Charlie.into())
+				.into_iter()
+				.map(|(round, reward)| crate::DelegatorReward {
+					round,
+					collator: Address(reward.collator.into()),
+					amount: reward.amount.into(),
+					compounded: reward.compounded.into(),
+				})
+				.collect();
+			assert_eq!(expected.len(), 1);
+			assert_eq!(expected[0].round, 1);
+
+			PrecompilesValue::get()
+				.prepare_test(
+					Alice,
+					Precompile1,
+					PCall::get_delegator_reward_history {
+						delegator: Address(Charlie.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(expected);
+		});
+}
+
 #[test]
 fn get_candidate_total_counted_getter() {
 	ExtBuilder::default()
@@ -1709,6 +2320,10 @@ fn test_solidity_interface_has_all_function_selectors_documented_and_implemented
 	check_precompile_implements_solidity_interfaces(
 		&["StakingInterface.sol"],
 		PCall::supports_selector,
+	);
+	check_precompile_implements_solidity_interfaces(
+		&["PoolShares.sol"],
+		PoolSharesCall::supports_selector,
 	)
 }
 
@@ -0,0 +1,47 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+pub use pallet_parachain_staking::{
+	CandidateBondLessRequest, CollatorStatus, CollatorSummary, DelegationAction,
+	DelegationPlacement, DelegationPosition, DelegatorPosition, ProjectedRewards, ScheduledRequest,
+	SelectedCollator,
+};
+use parity_scale_codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API to query the delegators and collator candidates of `pallet_parachain_staking`.
+	pub trait ParachainStakingApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Delegations of a delegator, `None` if the account is not delegating.
+		fn delegator_position(delegator: AccountId) -> Option<DelegatorPosition<AccountId, Balance>>;
+		/// Summary of a collator candidate, `None` if the account is not a candidate.
+		fn collator_summary(candidate: AccountId) -> Option<CollatorSummary<Balance>>;
+		/// Rewards projected for a new delegation of `stake` to a collator, `None` if the account
+		/// is not a candidate.
+		fn projected_rewards(collator: AccountId, stake: Balance) -> Option<ProjectedRewards<Balance>>;
+		/// Collators selected for `round`, empty if the round is not kept in the selection
+		/// history.
+		fn round_selection(round: u32) -> Vec<SelectedCollator<AccountId, Balance>>;
+	}
+}
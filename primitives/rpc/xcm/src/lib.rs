@@ -0,0 +1,320 @@
+// Copyright 2025 Moonbeam foundation
+// This file is part of Moonbeam.
+
+// Moonbeam is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Moonbeam is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+// These clippy lints are disabled because the macro-generated code triggers them.
+#![allow(clippy::unnecessary_mut_passed)]
+#![allow(clippy::too_many_arguments)]
+
+pub use frame_support::traits::ProcessMessageError;
+use frame_support::weights::Weight;
+use parity_scale_codec::{Decode, Encode};
+use sp_core::{H160, H256, U256};
+use sp_runtime::scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// keccak256("Transfer(address,address,uint256)")
+pub const ERC20_TRANSFER_SELECTOR: [u8; 32] =
+	hex_literal::hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef");
+
+/// Origin of a message processed by the message queue.
+#[derive(Eq, PartialEq, Clone, Copy, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum MessageOrigin {
+	/// Message sent by the parachain itself.
+	Here,
+	/// Downward message from the relay chain.
+	Parent,
+	/// Horizontal message from a sibling parachain.
+	Sibling(u32),
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ProcessedMessage {
+	pub id: H256,
+	pub origin: MessageOrigin,
+	pub weight_used: Weight,
+	pub success: bool,
+	/// Deposits made while processing the message.
+	pub deposits: Vec<Deposit>,
+}
+
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct FailedMessage {
+	pub id: H256,
+	pub origin: MessageOrigin,
+	pub error: ProcessMessageError,
+}
+
+/// Funds deposited to an account, in the native currency or as an ERC-20 mint.
+#[derive(Eq, PartialEq, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct Deposit {
+	/// Contract of the minted ERC-20, `None` for the native currency.
+	pub asset: Option<H160>,
+	pub who: H160,
+	pub amount: U256,
+}
+
+impl Deposit {
+	/// Build a deposit from an ERC-20 `Transfer` log, if it is a mint.
+	pub fn from_erc20_mint(address: H160, topics: &[H256], data: &[u8]) -> Option<Self> {
+		match topics {
+			[selector, from, to]
+				if selector.0 == ERC20_TRANSFER_SELECTOR && from.is_zero() && data.len() == 32 =>
+			{
+				Some(Self {
+					asset: Some(address),
+					who: H160::from(*to),
+					amount: U256::from_big_endian(data),
+				})
+			}
+			_ => None,
+		}
+	}
+}
+
+/// XCM messages processed in a block.
+#[derive(Eq, PartialEq, Clone, Default, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct XcmProcessingReport {
+	pub processed: Vec<ProcessedMessage>,
+	pub failed: Vec<FailedMessage>,
+}
+
+/// Block event relevant to an [`XcmProcessingReport`].
+#[derive(Eq, PartialEq, Clone, RuntimeDebug)]
+pub enum ReportEvent {
+	/// A message was processed by the message queue.
+	Processed {
+		id: H256,
+		origin: MessageOrigin,
+		weight_used: Weight,
+		success: bool,
+	},
+	/// A message could not be processed by the message queue.
+	ProcessingFailed {
+		id: H256,
+		origin: MessageOrigin,
+		error: ProcessMessageError,
+	},
+	Deposit(Deposit),
+	/// Event that is not emitted while executing a message, e.g. another message queue event or
+	/// an event of a pallet that isn't involved in XCM execution.
+	Unrelated,
+}
+
+impl XcmProcessingReport {
+	/// Build the report from the relevant events of a block, in the order they were emitted.
+	///
+	/// The message queue emits the `Processed` event of a message once it is executed, so the
+	/// deposits of a processed message are the ones emitted since the previous message queue or
+	/// unrelated event of the same phase.
+	pub fn from_events<Phase: PartialEq>(
+		events: impl IntoIterator<Item = (Phase, ReportEvent)>,
+	) -> Self {
+		let mut report = Self::default();
+		let mut deposits = Vec::new();
+		let mut current_phase = None;
+		for (phase, event) in events {
+			if current_phase.as_ref() != Some(&phase) {
+				deposits.clear();
+				current_phase = Some(phase);
+			}
+			match event {
+				ReportEvent::Processed {
+					id,
+					origin,
+					weight_used,
+					success,
+				} => report.processed.push(ProcessedMessage {
+					id,
+					origin,
+					weight_used,
+					success,
+					deposits: core::mem::take(&mut deposits),
+				}),
+				ReportEvent::ProcessingFailed { id, origin, error } => {
+					deposits.clear();
+					report.failed.push(FailedMessage { id, origin, error });
+				}
+				ReportEvent::Deposit(deposit) => deposits.push(deposit),
+				ReportEvent::Unrelated => deposits.clear(),
+			}
+		}
+		report
+	}
+}
+
+sp_api::decl_runtime_apis! {
+	/// Runtime API used by dev nodes to report the outcome of injected XCM messages. Only
+	/// implemented by runtimes used for development.
+	pub trait XcmProcessingApi {
+		/// Report built from the events of the block, see [`XcmProcessingReport::from_events`].
+		fn xcm_processing_report() -> XcmProcessingReport;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const PHASE: u32 = 1;
+
+	fn deposit(who: u8, amount: u64) -> ReportEvent {
+		ReportEvent::Deposit(Deposit {
+			asset: None,
+			who: H160::repeat_byte(who),
+			amount: amount.into(),
+		})
+	}
+
+	fn processed(id: u8) -> ReportEvent {
+		ReportEvent::Processed {
+			id: H256::repeat_byte(id),
+			origin: MessageOrigin::Parent,
+			weight_used: Weight::from_parts(1_000, 10),
+			success: true,
+		}
+	}
+
+	fn deposits_of(report: &XcmProcessingReport) -> Vec<Vec<(H160, U256)>> {
+		report
+			.processed
+			.iter()
+			.map(|message| {
+				message
+					.deposits
+					.iter()
+					.map(|deposit| (deposit.who, deposit.amount))
+					.collect()
+			})
+			.collect()
+	}
+
+	#[test]
+	fn deposits_are_attributed_to_the_next_processed_message() {
+		let report = XcmProcessingReport::from_events([
+			(PHASE, deposit(1, 10)),
+			(PHASE, deposit(2, 1)),
+			(PHASE, processed(1)),
+			(PHASE, deposit(3, 20)),
+			(PHASE, processed(2)),
+		]);
+
+		assert_eq!(
+			report
+				.processed
+				.iter()
+				.map(|message| message.id)
+				.collect::<Vec<_>>(),
+			vec![H256::repeat_byte(1), H256::repeat_byte(2)]
+		);
+		assert_eq!(
+			deposits_of(&report),
+			vec![
+				vec![
+					(H160::repeat_byte(1), 10.into()),
+					(H160::repeat_byte(2), 1.into())
+				],
+				vec![(H160::repeat_byte(3), 20.into())],
+			]
+		);
+		assert!(report.failed.is_empty());
+	}
+
+	#[test]
+	fn deposits_before_an_unrelated_event_are_not_attributed() {
+		let report = XcmProcessingReport::from_events([
+			// e.g. a staking reward paid by an earlier hook of the same phase
+			(PHASE, deposit(1, 10)),
+			(PHASE, ReportEvent::Unrelated),
+			(PHASE, deposit(2, 20)),
+			(PHASE, processed(1)),
+		]);
+
+		assert_eq!(
+			deposits_of(&report),
+			vec![vec![(H160::repeat_byte(2), 20.into())]]
+		);
+	}
+
+	#[test]
+	fn deposits_of_other_phases_are_not_attributed() {
+		let report = XcmProcessingReport::from_events([
+			(0, deposit(1, 10)),
+			(PHASE, deposit(2, 20)),
+			(PHASE, processed(1)),
+			(PHASE + 1, deposit(3, 30)),
+		]);
+
+		assert_eq!(
+			deposits_of(&report),
+			vec![vec![(H160::repeat_byte(2), 20.into())]]
+		);
+	}
+
+	#[test]
+	fn deposits_before_a_failed_message_are_not_attributed() {
+		let report = XcmProcessingReport::from_events([
+			(PHASE, deposit(1, 10)),
+			(
+				PHASE,
+				ReportEvent::ProcessingFailed {
+					id: H256::repeat_byte(1),
+					origin: MessageOrigin::Sibling(2000),
+					error: ProcessMessageError::Unsupported,
+				},
+			),
+			(PHASE, processed(2)),
+		]);
+
+		assert_eq!(
+			report.failed,
+			vec![FailedMessage {
+				id: H256::repeat_byte(1),
+				origin: MessageOrigin::Sibling(2000),
+				error: ProcessMessageError::Unsupported,
+			}]
+		);
+		assert_eq!(deposits_of(&report), vec![vec![]]);
+	}
+
+	#[test]
+	fn erc20_mints_are_deposits() {
+		let contract = H160::repeat_byte(0xff);
+		let to = H256::from(H160::repeat_byte(1));
+		let amount = U256::from(1_000).to_big_endian();
+		let selector = H256(ERC20_TRANSFER_SELECTOR);
+
+		assert_eq!(
+			Deposit::from_erc20_mint(contract, &[selector, H256::zero(), to], &amount),
+			Some(Deposit {
+				asset: Some(contract),
+				who: H160::repeat_byte(1),
+				amount: 1_000.into(),
+			})
+		);
+		// Transfers between accounts aren't mints
+		let from = H256::from(H160::repeat_byte(2));
+		assert_eq!(
+			Deposit::from_erc20_mint(contract, &[selector, from, to], &amount),
+			None
+		);
+		assert_eq!(
+			Deposit::from_erc20_mint(contract, &[H256::zero(), H256::zero(), to], &amount),
+			None
+		);
+	}
+}
@@ -838,6 +838,35 @@ macro_rules! impl_runtime_apis_plus_common {
 				}
 			}
 
+			impl moonbeam_rpc_primitives_staking::ParachainStakingApi<Block, AccountId, Balance>
+				for Runtime
+			{
+				fn delegator_position(
+					delegator: AccountId,
+				) -> Option<moonbeam_rpc_primitives_staking::DelegatorPosition<AccountId, Balance>> {
+					ParachainStaking::delegator_position(&delegator)
+				}
+
+				fn collator_summary(
+					candidate: AccountId,
+				) -> Option<moonbeam_rpc_primitives_staking::CollatorSummary<Balance>> {
+					ParachainStaking::collator_summary(&candidate)
+				}
+
+				fn projected_rewards(
+					collator: AccountId,
+					stake: Balance,
+				) -> Option<moonbeam_rpc_primitives_staking::ProjectedRewards<Balance>> {
+					ParachainStaking::projected_rewards(&collator, stake)
+				}
+
+				fn round_selection(
+					round: u32,
+				) -> Vec<moonbeam_rpc_primitives_staking::SelectedCollator<AccountId, Balance>> {
+					ParachainStaking::round_selection(round)
+				}
+			}
+
 			#[cfg(feature = "runtime-benchmarks")]
 			impl frame_benchmarking::Benchmark<Block> for Runtime {
 
@@ -780,6 +780,18 @@ impl pallet_parachain_staking::OnNewRound for OnNewRound {
 		MoonbeamOrbiters::on_new_round(round_index)
 	}
 }
+pub struct OnPoolCreated;
+impl pallet_parachain_staking::OnPoolCreated for OnPoolCreated {
+	fn on_pool_created(pool: pallet_parachain_staking::PoolId) {
+		// The shares of the pool are exposed through an ERC20 precompile. Insert some dummy
+		// bytecode at its address so that calls from contracts are not rejected.
+		let revert_bytecode = vec![0x60, 0x00, 0x60, 0x00, 0xFD];
+		pallet_evm::AccountCodes::<Runtime>::insert(
+			pallet_evm_precompile_parachain_staking::pool_shares_address(pool),
+			revert_bytecode,
+		);
+	}
+}
 pub struct PayoutCollatorOrOrbiterReward;
 impl pallet_parachain_staking::PayoutCollatorReward<Runtime> for PayoutCollatorOrOrbiterReward {
 	fn payout_collator_reward(
@@ -835,9 +847,14 @@ impl Get<Slot> for RelayChainSlotProvider {
 	}
 }
 
+parameter_types! {
+	pub const StakingPoolsPalletId: PalletId = PalletId(*b"pc/stkpl");
+}
+
 impl pallet_parachain_staking::Config for Runtime {
 	type Currency = Balances;
 	type MonetaryGovernanceOrigin = MonetaryGovernanceOrigin;
+	type SlashingOrigin = MonetaryGovernanceOrigin;
 	/// Minimum round length is 2 minutes (10 * 12 second block times)
 	type MinBlocksPerRound = ConstU32<10>;
 	/// If a collator doesn't produce any block on this number of rounds, it is notified as inactive
@@ -846,14 +863,24 @@ impl pallet_parachain_staking::Config for Runtime {
 	type LeaveCandidatesDelay = ConstU32<2>;
 	/// Rounds before the candidate bond increase/decrease can be executed
 	type CandidateBondLessDelay = ConstU32<2>;
+	/// Rounds before a candidate commission change can be executed
+	type CandidateCommissionChangeDelay = ConstU32<2>;
 	/// Rounds before the delegator exit can be executed
 	type LeaveDelegatorsDelay = ConstU32<2>;
 	/// Rounds before the delegator revocation can be executed
 	type RevokeDelegationDelay = ConstU32<2>;
+	/// Rounds before a delegator can redelegate again
+	type RedelegationCooldown = ConstU32<2>;
+	/// Rounds before a reported slash is applied, during which it can be cancelled
+	type SlashDeferDuration = ConstU32<1>;
 	/// Rounds before the delegator bond increase/decrease can be executed
 	type DelegationBondLessDelay = ConstU32<2>;
 	/// Rounds before the reward is paid
 	type RewardPaymentDelay = ConstU32<2>;
+	/// Paid rounds for which the rewards of each delegator are kept
+	type RewardHistoryRounds = ConstU32<24>;
+	/// Rounds for which the collators selected for each round are kept
+	type SelectionHistoryRounds = ConstU32<24>;
 	/// Minimum collators selected per round, default at genesis and minimum forever after
 	type MinSelectedCandidates = ConstU32<8>;
 	/// Maximum top delegations per candidate
@@ -873,12 +900,22 @@ impl pallet_parachain_staking::Config for Runtime {
 	type PayoutCollatorReward = PayoutCollatorOrOrbiterReward;
 	type OnInactiveCollator = OnInactiveCollator;
 	type OnNewRound = OnNewRound;
+	type OnPoolCreated = OnPoolCreated;
 	type SlotProvider = RelayChainSlotProvider;
 	type WeightInfo = moonbase_weights::pallet_parachain_staking::WeightInfo<Runtime>;
 	type MaxCandidates = ConstU32<200>;
+	/// Identifier from which the accounts of delegation pools are derived
+	type PoolsPalletId = StakingPoolsPalletId;
+	/// Maximum candidates a delegation pool delegates to
+	type MaxPoolCandidates = ConstU32<8>;
+	/// Deposit held for the profile of a candidate, covering its maximum size
+	type CandidateProfileDeposit = ConstU128<{ currency::deposit(1, 600) }>;
+	/// Maximum length in bytes of each text field of a candidate profile
+	type MaxCandidateProfileFieldLength = ConstU32<128>;
 	type SlotDuration = ConstU64<MILLISECS_PER_BLOCK>;
 	type BlockTime = ConstU64<MILLISECS_PER_BLOCK>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type LinearInflationThreshold = ();
 }
 
@@ -1432,7 +1469,7 @@ construct_runtime! {
 		EthereumChainId: pallet_evm_chain_id::{Pallet, Storage, Config<T>} = 9,
 		EVM: pallet_evm::{Pallet, Config<T>, Call, Storage, Event<T>} = 10,
 		Ethereum: pallet_ethereum::{Pallet, Call, Storage, Event, Origin, Config<T>} = 11,
-		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason} = 12,
+		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason, HoldReason} = 12,
 		Scheduler: pallet_scheduler::{Pallet, Storage, Event<T>, Call} = 13,
 		// Previously 14: pallet_democracy::{Pallet, Storage, Config<T>, Event<T>, Call} = 14,
 		// Previously 15: CouncilCollective: pallet_collective::<Instance1>
@@ -1674,6 +1711,66 @@ moonbeam_runtime_common::impl_runtime_apis_plus_common!(
 			}
 		}
 
+		impl moonbeam_rpc_primitives_xcm::XcmProcessingApi<Block> for Runtime {
+			fn xcm_processing_report() -> moonbeam_rpc_primitives_xcm::XcmProcessingReport {
+				use cumulus_primitives_core::AggregateMessageOrigin;
+				use moonbeam_rpc_primitives_xcm::{
+					Deposit, MessageOrigin, ReportEvent, XcmProcessingReport,
+				};
+
+				let message_origin = |origin: AggregateMessageOrigin| match origin {
+					AggregateMessageOrigin::Here => MessageOrigin::Here,
+					AggregateMessageOrigin::Parent => MessageOrigin::Parent,
+					AggregateMessageOrigin::Sibling(para_id) => MessageOrigin::Sibling(para_id.into()),
+				};
+
+				XcmProcessingReport::from_events(System::read_events_no_consensus().filter_map(
+					|record| {
+						let frame_system::EventRecord { phase, event, .. } = *record;
+						let event = match event {
+							RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+								id, origin, weight_used, success,
+							}) => ReportEvent::Processed {
+								id,
+								origin: message_origin(origin),
+								weight_used,
+								success,
+							},
+							RuntimeEvent::MessageQueue(
+								pallet_message_queue::Event::ProcessingFailed { id, origin, error },
+							) => ReportEvent::ProcessingFailed {
+								id,
+								origin: message_origin(origin),
+								error,
+							},
+							RuntimeEvent::Balances(pallet_balances::Event::Deposit { who, amount }) => {
+								ReportEvent::Deposit(Deposit {
+									asset: None,
+									who: who.into(),
+									amount: amount.into(),
+								})
+							}
+							RuntimeEvent::EVM(pallet_evm::Event::Log { log }) => ReportEvent::Deposit(
+								Deposit::from_erc20_mint(log.address, &log.topics, &log.data)?,
+							),
+							// Pallets involved in the execution of XCM messages
+							RuntimeEvent::System(_)
+							| RuntimeEvent::Balances(_)
+							| RuntimeEvent::EVM(_)
+							| RuntimeEvent::Ethereum(_)
+							| RuntimeEvent::Treasury(_)
+							| RuntimeEvent::PolkadotXcm(_)
+							| RuntimeEvent::EthereumXcm(_)
+							| RuntimeEvent::EvmForeignAssets(_)
+							| RuntimeEvent::XcmWeightTrader(_) => return None,
+							_ => ReportEvent::Unrelated,
+						};
+						Some((phase, event))
+					},
+				))
+			}
+		}
+
 	}
 
 	// Benchmark customizations
@@ -1812,6 +1909,41 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn candidate_commission_change_delay_not_lower_than_reward_payment_delay() {
+		assert!(
+			get!(pallet_parachain_staking, CandidateCommissionChangeDelay, u32)
+				>= get!(pallet_parachain_staking, RewardPaymentDelay, u32)
+		);
+	}
+
+	#[test]
+	fn max_pool_candidates_not_greater_than_max_delegations_per_delegator() {
+		assert!(
+			get!(pallet_parachain_staking, MaxPoolCandidates, u32)
+				<= get!(pallet_parachain_staking, MaxDelegationsPerDelegator, u32)
+		);
+	}
+
+	#[test]
+	fn candidate_profile_deposit_covers_max_profile_size() {
+		use parity_scale_codec::MaxEncodedLen;
+		let max_size = pallet_parachain_staking::CandidateProfile::<
+			Balance,
+			<Runtime as pallet_parachain_staking::Config>::MaxCandidateProfileFieldLength,
+		>::max_encoded_len() as u32;
+		assert!(
+			get!(pallet_parachain_staking, CandidateProfileDeposit, u128) >= deposit(1, max_size)
+		);
+	}
+
+	#[test]
+	fn slash_defer_duration_lower_than_leave_and_revoke_delays() {
+		let defer = get!(pallet_parachain_staking, SlashDeferDuration, u32);
+		assert!(defer < get!(pallet_parachain_staking, LeaveCandidatesDelay, u32));
+		assert!(defer < get!(pallet_parachain_staking, RevokeDelegationDelay, u32));
+	}
+
 	#[test]
 	// Required migration is
 	// pallet_parachain_staking::migrations::IncreaseMaxTopDelegationsPerCandidate
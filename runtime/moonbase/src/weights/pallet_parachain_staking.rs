@@ -819,4 +819,295 @@ impl<T: frame_system::Config> pallet_parachain_staking::WeightInfo for WeightInf
 			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(x.into())))
 			.saturating_add(Weight::from_parts(0, 2580).saturating_mul(x.into()))
 	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::NextPoolId` (r:1 w:1)
+	/// Proof: `ParachainStaking::NextPoolId` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `EVM::AccountCodes` (r:0 w:1)
+	/// Proof: `EVM::AccountCodes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:0 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn create_pool(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `396 + x * (77 ±0)`
+		//  Estimated: `1881 + x * (2553 ±0)`
+		// Minimum execution time: 19_442_000 picoseconds.
+		Weight::from_parts(18_611_907, 1881)
+			// Standard Error: 6_102
+			.saturating_add(Weight::from_parts(3_594_316, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:8 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:1)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 8]`.
+	fn set_pool_candidates(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `545 + x * (77 ±0)`
+		//  Estimated: `4010 + x * (2553 ±0)`
+		// Minimum execution time: 20_875_000 picoseconds.
+		Weight::from_parts(19_951_470, 4010)
+			// Standard Error: 5_873
+			.saturating_add(Weight::from_parts(3_652_745, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2553).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `465`
+		//  Estimated: `6405`
+		// Minimum execution time: 27_316_000 picoseconds.
+		Weight::from_parts(28_244_000, 6405)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:0 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn approve_pool_shares() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `307`
+		//  Estimated: `3772`
+		// Minimum execution time: 14_903_000 picoseconds.
+		Weight::from_parts(15_421_000, 3772)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::PoolShareApprovals` (r:1 w:1)
+	/// Proof: `ParachainStaking::PoolShareApprovals` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Pools` (r:1 w:0)
+	/// Proof: `ParachainStaking::Pools` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PoolShares` (r:2 w:2)
+	/// Proof: `ParachainStaking::PoolShares` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn transfer_pool_shares_from() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `599`
+		//  Estimated: `6539`
+		// Minimum execution time: 34_581_000 picoseconds.
+		Weight::from_parts(35_730_000, 6539)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:1)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_slash_fractions() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 8_611_000 picoseconds.
+		Weight::from_parts(9_064_000, 1515)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::SlashFractions` (r:1 w:0)
+	/// Proof: `ParachainStaking::SlashFractions` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:1 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn report_misbehaviour() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `590`
+		//  Estimated: `4055`
+		// Minimum execution time: 21_734_000 picoseconds.
+		Weight::from_parts(22_496_000, 4055)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::PendingSlashes` (r:2 w:1)
+	/// Proof: `ParachainStaking::PendingSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `341`
+		//  Estimated: `6281`
+		// Minimum execution time: 18_252_000 picoseconds.
+		Weight::from_parts(19_017_000, 6281)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:1 w:1)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:301 w:301)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:301 w:301)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::DelegatorState` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:300 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:0 w:300)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 300]`.
+	fn apply_slash(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1326 + x * (493 ±0)`
+		//  Estimated: `6198 + x * (3633 ±0)`
+		// Minimum execution time: 88_306_000 picoseconds.
+		Weight::from_parts(85_712_093, 6198)
+			// Standard Error: 28_007
+			.saturating_add(Weight::from_parts(74_918_516, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((4_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3633).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_candidate_commission_bounds() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `30`
+		//  Estimated: `1515`
+		// Minimum execution time: 8_742_000 picoseconds.
+		Weight::from_parts(9_183_000, 1515)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn schedule_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `549`
+		//  Estimated: `4014`
+		// Minimum execution time: 20_187_000 picoseconds.
+		Weight::from_parts(21_036_000, 4014)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CollatorCommission` (r:1 w:0)
+	/// Proof: `ParachainStaking::CollatorCommission` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn execute_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `587`
+		//  Estimated: `4052`
+		// Minimum execution time: 22_645_000 picoseconds.
+		Weight::from_parts(23_590_000, 4052)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn cancel_candidate_commission_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `554`
+		//  Estimated: `4019`
+		// Minimum execution time: 16_408_000 picoseconds.
+		Weight::from_parts(17_122_000, 4019)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateInfo` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateCommissionBounds` (r:1 w:0)
+	/// Proof: `ParachainStaking::CandidateCommissionBounds` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn set_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `679`
+		//  Estimated: `4144`
+		// Minimum execution time: 53_870_000 picoseconds.
+		Weight::from_parts(55_302_116, 4144)
+			// Standard Error: 709
+			.saturating_add(Weight::from_parts(2_413, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ParachainStaking::CandidateProfiles` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidateProfiles` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(91), added: 2566, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 512]`.
+	fn clear_candidate_profile(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480 + x * (1 ±0)`
+		//  Estimated: `3945 + x * (1 ±0)`
+		// Minimum execution time: 46_215_000 picoseconds.
+		Weight::from_parts(47_698_342, 3945)
+			// Standard Error: 612
+			.saturating_add(Weight::from_parts(1_540, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 1).saturating_mul(x.into()))
+	}
+	/// Storage: `ParachainStaking::DelegatorState` (r:1 w:1)
+	/// Proof: `ParachainStaking::DelegatorState` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Round` (r:1 w:0)
+	/// Proof: `ParachainStaking::Round` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequests` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequests` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidateInfo` (r:100 w:100)
+	/// Proof: `ParachainStaking::CandidateInfo` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1287), added: 3762, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainStaking::AutoCompoundingDelegations` (r:100 w:0)
+	/// Proof: `ParachainStaking::AutoCompoundingDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::TopDelegations` (r:100 w:100)
+	/// Proof: `ParachainStaking::TopDelegations` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::CandidatePool` (r:1 w:1)
+	/// Proof: `ParachainStaking::CandidatePool` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::Total` (r:1 w:1)
+	/// Proof: `ParachainStaking::Total` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsPerCollator` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsPerCollator` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (r:100 w:100)
+	/// Proof: `ParachainStaking::DelegationScheduledRequestsSummaryMap` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[1, 100]`.
+	fn execute_all_matured_requests(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1712 + x * (3291 ±0)`
+		//  Estimated: `6130 + x * (3512 ±0)`
+		// Minimum execution time: 97_318_000 picoseconds.
+		Weight::from_parts(62_407_000, 6130)
+			// Standard Error: 112_004
+			.saturating_add(Weight::from_parts(48_216_918, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().reads((6_u64).saturating_mul(x.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(x.into())))
+			.saturating_add(Weight::from_parts(0, 3512).saturating_mul(x.into()))
+	}
 }
@@ -654,6 +654,28 @@ fn transfer_through_evm_to_stake() {
 		});
 }
 
+#[test]
+fn create_pool_inserts_revert_code_at_pool_shares_address() {
+	ExtBuilder::default()
+		.with_balances(vec![(AccountId::from(ALICE), 2_000 * UNIT)])
+		.with_collators(vec![(AccountId::from(ALICE), 1_000 * UNIT)])
+		.build()
+		.execute_with(|| {
+			let token = pallet_evm_precompile_parachain_staking::pool_shares_address(0);
+			assert!(pallet_evm::AccountCodes::<Runtime>::get(token).is_empty());
+
+			assert_ok!(ParachainStaking::create_pool(
+				root_origin(),
+				vec![AccountId::from(ALICE)]
+			));
+
+			assert_eq!(
+				pallet_evm::AccountCodes::<Runtime>::get(token),
+				vec![0x60, 0x00, 0x60, 0x00, 0xFD]
+			);
+		});
+}
+
 #[test]
 fn reward_block_authors() {
 	ExtBuilder::default()
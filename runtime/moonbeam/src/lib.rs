@@ -777,6 +777,18 @@ impl pallet_parachain_staking::OnNewRound for OnNewRound {
 		MoonbeamOrbiters::on_new_round(round_index)
 	}
 }
+pub struct OnPoolCreated;
+impl pallet_parachain_staking::OnPoolCreated for OnPoolCreated {
+	fn on_pool_created(pool: pallet_parachain_staking::PoolId) {
+		// The shares of the pool are exposed through an ERC20 precompile. Insert some dummy
+		// bytecode at its address so that calls from contracts are not rejected.
+		let revert_bytecode = vec![0x60, 0x00, 0x60, 0x00, 0xFD];
+		pallet_evm::AccountCodes::<Runtime>::insert(
+			pallet_evm_precompile_parachain_staking::pool_shares_address(pool),
+			revert_bytecode,
+		);
+	}
+}
 pub struct PayoutCollatorOrOrbiterReward;
 impl pallet_parachain_staking::PayoutCollatorReward<Runtime> for PayoutCollatorOrOrbiterReward {
 	fn payout_collator_reward(
@@ -836,9 +848,14 @@ parameter_types! {
 	pub const LinearInflationThreshold: Option<Balance> = Some(1_200_000_000 * currency::GLMR);
 }
 
+parameter_types! {
+	pub const StakingPoolsPalletId: PalletId = PalletId(*b"py/stkpl");
+}
+
 impl pallet_parachain_staking::Config for Runtime {
 	type Currency = Balances;
 	type MonetaryGovernanceOrigin = MonetaryGovernanceOrigin;
+	type SlashingOrigin = MonetaryGovernanceOrigin;
 	/// Minimum round length is 2 minutes (10 * 12 second block times)
 	type MinBlocksPerRound = ConstU32<10>;
 	/// If a collator doesn't produce any block on this number of rounds, it is notified as inactive
@@ -847,14 +864,24 @@ impl pallet_parachain_staking::Config for Runtime {
 	type LeaveCandidatesDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the candidate bond increase/decrease can be executed
 	type CandidateBondLessDelay = ConstU32<{ 4 * 7 }>;
+	/// Rounds before a candidate commission change can be executed
+	type CandidateCommissionChangeDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the delegator exit can be executed
 	type LeaveDelegatorsDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the delegator revocation can be executed
 	type RevokeDelegationDelay = ConstU32<{ 4 * 7 }>;
+	/// Rounds before a delegator can redelegate again
+	type RedelegationCooldown = ConstU32<{ 4 * 7 }>;
+	/// Rounds before a reported slash is applied, during which it can be cancelled
+	type SlashDeferDuration = ConstU32<14>;
 	/// Rounds before the delegator bond increase/decrease can be executed
 	type DelegationBondLessDelay = ConstU32<{ 4 * 7 }>;
 	/// Rounds before the reward is paid
 	type RewardPaymentDelay = ConstU32<2>;
+	/// Reward history of delegators is disabled
+	type RewardHistoryRounds = ConstU32<0>;
+	/// Rounds for which the collators selected for each round are kept
+	type SelectionHistoryRounds = ConstU32<24>;
 	/// Minimum collators selected per round, default at genesis and minimum forever after
 	type MinSelectedCandidates = ConstU32<8>;
 	/// Maximum top delegations per candidate
@@ -874,12 +901,22 @@ impl pallet_parachain_staking::Config for Runtime {
 	type PayoutCollatorReward = PayoutCollatorOrOrbiterReward;
 	type OnInactiveCollator = OnInactiveCollator;
 	type OnNewRound = OnNewRound;
+	type OnPoolCreated = OnPoolCreated;
 	type SlotProvider = RelayChainSlotProvider;
 	type WeightInfo = moonbeam_weights::pallet_parachain_staking::WeightInfo<Runtime>;
 	type MaxCandidates = ConstU32<200>;
+	/// Identifier from which the accounts of delegation pools are derived
+	type PoolsPalletId = StakingPoolsPalletId;
+	/// Maximum candidates a delegation pool delegates to
+	type MaxPoolCandidates = ConstU32<8>;
+	/// Deposit held for the profile of a candidate, covering its maximum size
+	type CandidateProfileDeposit = ConstU128<{ currency::deposit(1, 600) }>;
+	/// Maximum length in bytes of each text field of a candidate profile
+	type MaxCandidateProfileFieldLength = ConstU32<128>;
 	type SlotDuration = ConstU64<MILLISECS_PER_BLOCK>;
 	type BlockTime = ConstU64<MILLISECS_PER_BLOCK>;
 	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type LinearInflationThreshold = LinearInflationThreshold;
 }
 
@@ -1433,7 +1470,7 @@ construct_runtime! {
 		TransactionPayment: pallet_transaction_payment::{Pallet, Storage, Config<T>, Event<T>} = 11,
 
 		// Consensus support.
-		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason} = 20,
+		ParachainStaking: pallet_parachain_staking::{Pallet, Call, Storage, Event<T>, Config<T>, FreezeReason, HoldReason} = 20,
 		AuthorInherent: pallet_author_inherent::{Pallet, Call, Storage, Inherent} = 21,
 		AuthorFilter: pallet_author_slot_filter::{Pallet, Call, Storage, Event, Config<T>} = 22,
 		AuthorMapping: pallet_author_mapping::{Pallet, Call, Config<T>, Storage, Event<T>} = 23,
@@ -2014,6 +2051,41 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn candidate_commission_change_delay_not_lower_than_reward_payment_delay() {
+		assert!(
+			get!(pallet_parachain_staking, CandidateCommissionChangeDelay, u32)
+				>= get!(pallet_parachain_staking, RewardPaymentDelay, u32)
+		);
+	}
+
+	#[test]
+	fn max_pool_candidates_not_greater_than_max_delegations_per_delegator() {
+		assert!(
+			get!(pallet_parachain_staking, MaxPoolCandidates, u32)
+				<= get!(pallet_parachain_staking, MaxDelegationsPerDelegator, u32)
+		);
+	}
+
+	#[test]
+	fn candidate_profile_deposit_covers_max_profile_size() {
+		use parity_scale_codec::MaxEncodedLen;
+		let max_size = pallet_parachain_staking::CandidateProfile::<
+			Balance,
+			<Runtime as pallet_parachain_staking::Config>::MaxCandidateProfileFieldLength,
+		>::max_encoded_len() as u32;
+		assert!(
+			get!(pallet_parachain_staking, CandidateProfileDeposit, u128) >= deposit(1, max_size)
+		);
+	}
+
+	#[test]
+	fn slash_defer_duration_lower_than_leave_and_revoke_delays() {
+		let defer = get!(pallet_parachain_staking, SlashDeferDuration, u32);
+		assert!(defer < get!(pallet_parachain_staking, LeaveCandidatesDelay, u32));
+		assert!(defer < get!(pallet_parachain_staking, RevokeDelegationDelay, u32));
+	}
+
 	#[test]
 	// Required migration is
 	// pallet_parachain_staking::migrations::IncreaseMaxTopDelegationsPerCandidate
@@ -24,7 +24,9 @@ parameter_types! {
 	pub RelayAssetId: AssetId = AssetType::Xcm(xcm::v3::Location::parent()).into();
 }
 
-type MoonbeamMigrations = ();
+type MoonbeamMigrations = (
+	pallet_parachain_staking::migrations::MigrateCandidateCommission<crate::Runtime>,
+);
 
 /// List of single block migrations to be executed by frame executive.
 pub type SingleBlockMigrations<Runtime> = (
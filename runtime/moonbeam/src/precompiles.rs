@@ -41,7 +41,9 @@ use pallet_evm_precompile_gmp::GmpPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
 use pallet_evm_precompile_p256verify::P256Verify;
-use pallet_evm_precompile_parachain_staking::ParachainStakingPrecompile;
+use pallet_evm_precompile_parachain_staking::{
+	ParachainStakingPrecompile, PoolSharesErc20PrecompileSet, POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX,
+};
 use pallet_evm_precompile_preimage::PreimagePrecompile;
 use pallet_evm_precompile_proxy::{OnlyIsProxyAndProxy, ProxyPrecompile};
 use pallet_evm_precompile_randomness::RandomnessPrecompile;
@@ -107,6 +109,7 @@ pub const ERC20_BALANCES_PRECOMPILE: u64 = 2050;
 parameter_types! {
 	pub ForeignAssetPrefix: &'static [u8] = FOREIGN_ASSET_PRECOMPILE_ADDRESS_PREFIX;
 	pub LocalAssetPrefix: &'static [u8] = LOCAL_ASSET_PRECOMPILE_ADDRESS_PREFIX;
+	pub PoolSharesPrefix: &'static [u8] = &POOL_SHARES_PRECOMPILE_ADDRESS_PREFIX;
 }
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
@@ -328,5 +331,11 @@ pub type MoonbeamPrecompiles<R> = PrecompileSetBuilder<
 	(
 		// Skip precompiles if out of range.
 		PrecompilesInRangeInclusive<(AddressU64<1>, AddressU64<4095>), MoonbeamPrecompilesAt<R>>,
+		// Shares of the parachain-staking delegation pools
+		PrecompileSetStartingWith<
+			PoolSharesPrefix,
+			PoolSharesErc20PrecompileSet<R>,
+			(CallableByContract, CallableByPrecompile),
+		>,
 	),
 >;
@@ -0,0 +1,62 @@
+import "@moonbeam-network/api-augment";
+import { customDevRpcRequest, describeSuite, expect } from "moonwall";
+import { u8aConcat, u8aToHex } from "@polkadot/util";
+import { xxhashAsU8a } from "@polkadot/util-crypto";
+
+describeSuite({
+  id: "D020404",
+  title: "Chain - Mocked relay storage",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const twox64Concat = (data: Uint8Array) => u8aConcat(xxhashAsU8a(data, 64), data);
+    const hrmpKey = (storage: string, key: Uint8Array) =>
+      u8aToHex(u8aConcat(xxhashAsU8a("Hrmp", 128), xxhashAsU8a(storage, 128), twox64Concat(key)));
+
+    it({
+      id: "T01",
+      title: "should include the relay storage entries set through the RPC",
+      test: async function () {
+        const api = context.polkadotJs();
+        const paraId = (await api.query.parachainInfo.parachainId()).toNumber();
+        const recipient = 2000;
+
+        const egressIndexKey = hrmpKey(
+          "HrmpEgressChannelsIndex",
+          api.createType("u32", paraId).toU8a()
+        );
+        const channelKey = hrmpKey(
+          "HrmpChannels",
+          api.createType("(u32, u32)", [paraId, recipient]).toU8a()
+        );
+        const channel = api
+          .createType("(u32, u32, u32, u32, u32, Option<H256>)", [1000, 102400, 1024, 0, 0, null])
+          .toHex();
+
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, api.createType("Vec<u32>", [recipient]).toHex()],
+            [channelKey, channel],
+          ],
+        ]);
+        await context.createBlock();
+
+        const messagingState = (await api.query.parachainSystem.relevantMessagingState()).unwrap();
+        expect(messagingState.egressChannels.length).toBe(1);
+        expect(messagingState.egressChannels[0][0].toNumber()).toBe(recipient);
+        expect(messagingState.egressChannels[0][1].maxMessageSize.toNumber()).toBe(1024);
+
+        // Dropping the entries restores the default relay state
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, null],
+            [channelKey, null],
+          ],
+        ]);
+        await context.createBlock();
+
+        const restoredState = (await api.query.parachainSystem.relevantMessagingState()).unwrap();
+        expect(restoredState.egressChannels.length).toBe(0);
+      },
+    });
+  },
+});
@@ -0,0 +1,85 @@
+import "@moonbeam-network/api-augment";
+import {
+  BALTATHAR_ADDRESS,
+  GLMR,
+  createRawTransfer,
+  customDevRpcRequest,
+  describeSuite,
+  expect,
+} from "moonwall";
+
+describeSuite({
+  id: "D020402",
+  title: "Chain - Snapshot and revert",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    it({
+      id: "T01",
+      title: "should rewind head and state to the snapshot",
+      test: async function () {
+        await context.createBlock();
+        const snapshotNumber = await context.viem().getBlockNumber();
+        const snapshotBalance = await context.viem().getBalance({ address: BALTATHAR_ADDRESS });
+        const snapshotId = await customDevRpcRequest("evm_snapshot", []);
+
+        await context.createBlock(createRawTransfer(context, BALTATHAR_ADDRESS, 10n * GLMR));
+        await context.createBlock();
+        expect(await context.viem().getBlockNumber()).toBe(snapshotNumber + 2n);
+        expect(await context.viem().getBalance({ address: BALTATHAR_ADDRESS })).toBe(
+          snapshotBalance + 10n * GLMR
+        );
+
+        expect(await customDevRpcRequest("evm_revert", [snapshotId])).toBe(true);
+        expect(await context.viem().getBlockNumber()).toBe(snapshotNumber);
+        expect(await context.viem().getBalance({ address: BALTATHAR_ADDRESS })).toBe(
+          snapshotBalance
+        );
+
+        // The chain keeps producing blocks on top of the restored head
+        await context.createBlock();
+        expect(await context.viem().getBlockNumber()).toBe(snapshotNumber + 1n);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should consume the snapshot and later ones when reverting",
+      test: async function () {
+        const first = await customDevRpcRequest("evm_snapshot", []);
+        await context.createBlock();
+        const second = await customDevRpcRequest("evm_snapshot", []);
+        await context.createBlock();
+
+        expect(await customDevRpcRequest("evm_revert", [first])).toBe(true);
+        expect(await customDevRpcRequest("evm_revert", [first])).toBe(false);
+        expect(await customDevRpcRequest("evm_revert", [second])).toBe(false);
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should return false for an unknown snapshot",
+      test: async function () {
+        expect(await customDevRpcRequest("evm_revert", ["0xffff"])).toBe(false);
+      },
+    });
+
+    it({
+      id: "T04",
+      title: "should not finalize blocks while a snapshot is held",
+      test: async function () {
+        await context.createBlock();
+        const snapshotNumber = await context.viem().getBlockNumber();
+        const snapshotId = await customDevRpcRequest("evm_snapshot", []);
+
+        await context.createBlock(undefined, { finalize: true });
+        const finalizedHash = await context.polkadotJs().rpc.chain.getFinalizedHead();
+        const finalized = await context.polkadotJs().rpc.chain.getHeader(finalizedHash);
+        expect(finalized.number.toBigInt()).toBeLessThanOrEqual(snapshotNumber);
+
+        expect(await customDevRpcRequest("evm_revert", [snapshotId])).toBe(true);
+        expect(await context.viem().getBlockNumber()).toBe(snapshotNumber);
+      },
+    });
+  },
+});
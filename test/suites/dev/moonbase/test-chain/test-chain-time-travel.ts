@@ -0,0 +1,81 @@
+import "@moonbeam-network/api-augment";
+import { customDevRpcRequest, describeSuite, expect } from "moonwall";
+
+describeSuite({
+  id: "D020403",
+  title: "Chain - Time travel",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    it({
+      id: "T01",
+      title: "should use the requested timestamp for the next block",
+      test: async function () {
+        await context.createBlock();
+        const current = (await context.polkadotJs().query.timestamp.now()).toBigInt();
+        const target = current + 3_600_000n;
+
+        await customDevRpcRequest("test_setNextBlockTimestamp", [Number(target)]);
+        await context.createBlock();
+        expect((await context.polkadotJs().query.timestamp.now()).toBigInt()).toBe(target);
+
+        // Following blocks keep advancing from the new timestamp
+        await context.createBlock();
+        expect((await context.polkadotJs().query.timestamp.now()).toBigInt()).toBe(
+          target + 6_000n
+        );
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should reject a timestamp less than one slot ahead",
+      test: async function () {
+        const current = (await context.polkadotJs().query.timestamp.now()).toBigInt();
+
+        await expect(
+          async () =>
+            await customDevRpcRequest("test_setNextBlockTimestamp", [Number(current - 60_000n)])
+        ).rejects.toThrowError("too early");
+        await expect(
+          async () =>
+            await customDevRpcRequest("test_setNextBlockTimestamp", [Number(current + 5_999n)])
+        ).rejects.toThrowError("too early");
+
+        await context.createBlock();
+        expect((await context.polkadotJs().query.timestamp.now()).toBigInt()).toBe(
+          current + 6_000n
+        );
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should mine the requested number of blocks",
+      test: async function () {
+        const before = (await context.polkadotJs().rpc.chain.getHeader()).number.toNumber();
+
+        const hashes = await customDevRpcRequest("test_mineBlocks", [10]);
+        expect(hashes.length).toBe(10);
+
+        const header = await context.polkadotJs().rpc.chain.getHeader();
+        expect(header.number.toNumber()).toBe(before + 10);
+        expect(header.hash.toHex()).toBe(hashes[9]);
+      },
+    });
+
+    it({
+      id: "T04",
+      title: "should jump the relay slot",
+      test: async function () {
+        await context.createBlock();
+        const [slotBefore] = (await context.polkadotJs().query.asyncBacking.slotInfo()).unwrap();
+
+        await customDevRpcRequest("test_skipRelaySlots", [100]);
+        await context.createBlock();
+        const [slotAfter] = (await context.polkadotJs().query.asyncBacking.slotInfo()).unwrap();
+
+        expect(slotAfter.toBigInt()).toBe(slotBefore.toBigInt() + 101n);
+      },
+    });
+  },
+});
@@ -0,0 +1,107 @@
+import "@moonbeam-network/api-augment/moonbase";
+import {
+  BALTATHAR_ADDRESS,
+  createViemTransaction,
+  customDevRpcRequest,
+  describeSuite,
+  expect,
+} from "moonwall";
+import { WsProvider } from "@polkadot/api";
+import { DEFAULT_TXN_MAX_BASE_FEE } from "../../../../helpers";
+
+describeSuite({
+  id: "D022002",
+  title: "Moon RPC Methods - batch finality queries and subscription",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const transfer = async () =>
+      createViemTransaction(context, {
+        to: BALTATHAR_ADDRESS,
+        gas: 12_000_000n,
+        gasPrice: BigInt(DEFAULT_TXN_MAX_BASE_FEE),
+        value: 1_000_000n,
+      });
+
+    it({
+      id: "T01",
+      title: "should report the finality of each block",
+      test: async function () {
+        const finalized = (await context.createBlock([], { finalize: true })).block.hash;
+        const unfinalized = (await context.createBlock([], { finalize: false })).block.hash;
+        const unknown = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+        const resp = await customDevRpcRequest("moon_areBlocksFinalized", [
+          [finalized, unfinalized, unknown],
+        ]);
+        expect(resp).toEqual([true, false, false]);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should report the finality of each transaction",
+      test: async function () {
+        const { result: finalized } = await context.createBlock(await transfer(), {
+          finalize: true,
+        });
+        const { result: unfinalized } = await context.createBlock(await transfer(), {
+          finalize: false,
+        });
+        const unknown = "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+        const resp = await customDevRpcRequest("moon_areTxsFinalized", [
+          [finalized!.hash, unfinalized!.hash, unknown],
+        ]);
+        expect(resp).toEqual([true, false, false]);
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should return the latest finalized Ethereum block",
+      test: async function () {
+        await context.createBlock([], { finalize: true });
+        const expected = await context.viem().getBlock({ blockTag: "latest" });
+        await context.createBlock([], { finalize: false });
+
+        const resp = await customDevRpcRequest("moon_getFinalizedEthBlock", []);
+        expect(BigInt(resp.number)).toBe(expected.number);
+        expect(resp.hash).toBe(expected.hash);
+      },
+    });
+
+    it({
+      id: "T04",
+      title: "should emit the transactions of blocks as they are finalized",
+      test: async function () {
+        const provider = new WsProvider(`ws://127.0.0.1:${process.env.MOONWALL_RPC_PORT}`);
+        await provider.isReady;
+
+        const notifications: any[] = [];
+        const subscription = await provider.subscribe(
+          "moon_finalizedTransactions",
+          "moon_subscribeFinalizedTransactions",
+          [],
+          (_error: any, notification: any) => notifications.push(notification)
+        );
+
+        const { result } = await context.createBlock(await transfer(), { finalize: false });
+        expect(notifications).toHaveLength(0);
+
+        // Finalizing a descendant finalizes the block holding the transaction too
+        await context.createBlock([], { finalize: true });
+        await new Promise((resolve) => setTimeout(resolve, 1000));
+
+        const transactionHashes = notifications.flatMap((n) => n.transactionHashes);
+        expect(transactionHashes).toContain(result!.hash);
+
+        await provider.unsubscribe(
+          "moon_finalizedTransactions",
+          "moon_unsubscribeFinalizedTransactions",
+          subscription
+        );
+        await provider.disconnect();
+      },
+    });
+  },
+});
@@ -0,0 +1,58 @@
+import "@moonbeam-network/api-augment/moonbase";
+import {
+  BALTATHAR_ADDRESS,
+  createViemTransaction,
+  customDevRpcRequest,
+  describeSuite,
+  expect,
+} from "moonwall";
+import { DEFAULT_TXN_MAX_BASE_FEE } from "../../../../helpers";
+
+describeSuite({
+  id: "D022003",
+  title: "Moon RPC Methods - Substrate effects of Ethereum transactions",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    it({
+      id: "T01",
+      title: "should return the extrinsic and events of an Ethereum transaction",
+      test: async function () {
+        const rawTx = await createViemTransaction(context, {
+          to: BALTATHAR_ADDRESS,
+          gas: 12_000_000n,
+          gasPrice: BigInt(DEFAULT_TXN_MAX_BASE_FEE),
+          value: 1_000_000n,
+        });
+        const { block, result } = await context.createBlock(rawTx);
+
+        const resp = await customDevRpcRequest("moon_getTransactionSubstrateEffects", [
+          result!.hash,
+        ]);
+        expect(resp.blockHash).toBe(block.hash);
+
+        const signedBlock = await context.polkadotJs().rpc.chain.getBlock(block.hash);
+        const extrinsic = signedBlock.block.extrinsics[resp.extrinsicIndex];
+        expect(extrinsic.method.section).toBe("ethereum");
+        expect(extrinsic.method.method).toBe("transact");
+
+        const executed = resp.events.find(
+          (e: any) => e.pallet === "Ethereum" && e.event === "Executed"
+        );
+        expect(executed.fields.transaction_hash).toBe(result!.hash);
+        expect(resp.events.map((e: any) => e.event)).toContain("ExtrinsicSuccess");
+        expect(resp.xcmMessageHashes).toEqual([]);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should return null for an unknown transaction",
+      test: async function () {
+        const resp = await customDevRpcRequest("moon_getTransactionSubstrateEffects", [
+          "0xffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+        ]);
+        expect(resp).toBeNull();
+      },
+    });
+  },
+});
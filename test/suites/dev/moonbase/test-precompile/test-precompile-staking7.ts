@@ -0,0 +1,80 @@
+import "@moonbeam-network/api-augment";
+import {
+  MIN_GLMR_DELEGATOR,
+  alith,
+  beforeAll,
+  describeSuite,
+  ethan,
+  expect,
+} from "moonwall";
+import { jumpRounds } from "../../../../helpers";
+
+describeSuite({
+  id: "D022780",
+  title: "Precompiles - Staking - Delegator reward history",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    beforeAll(async function () {
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.parachainStaking.delegateWithAutoCompound(
+            alith.address,
+            MIN_GLMR_DELEGATOR,
+            50,
+            0,
+            0,
+            0
+          )
+          .signAsync(ethan),
+        { allowFailures: false }
+      );
+    });
+
+    it({
+      id: "T01",
+      title: "should have no reward history before the first payout",
+      test: async function () {
+        expect(
+          await context.readPrecompile!({
+            precompileName: "ParachainStaking",
+            functionName: "getDelegatorRewardHistory",
+            args: [ethan.address],
+          })
+        ).toHaveLength(0);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should return the rewards paid to the delegator",
+      test: async function () {
+        const rewardPaymentDelay = context
+          .polkadotJs()
+          .consts.parachainStaking.rewardPaymentDelay.toNumber();
+        await jumpRounds(context, rewardPaymentDelay + 1);
+        // Payouts start in the block after the round change
+        await context.createBlock();
+
+        const paidRound =
+          (await context.polkadotJs().query.parachainStaking.round()).current.toNumber() -
+          rewardPaymentDelay;
+        const [stored] = await context
+          .polkadotJs()
+          .query.parachainStaking.rewardHistory(paidRound, ethan.address);
+
+        const history = (await context.readPrecompile!({
+          precompileName: "ParachainStaking",
+          functionName: "getDelegatorRewardHistory",
+          args: [ethan.address],
+        })) as any[];
+        expect(history).toHaveLength(1);
+        expect(history[0].round).toBe(paidRound);
+        expect(history[0].collator).toBe(alith.address);
+        expect(history[0].amount).toBe(stored.amount.toBigInt());
+        expect(history[0].amount > 0n).toBe(true);
+        expect(history[0].compounded).toBe(stored.compounded.toBigInt());
+      },
+    });
+  },
+});
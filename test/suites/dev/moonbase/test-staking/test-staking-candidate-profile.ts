@@ -0,0 +1,77 @@
+import "@moonbeam-network/api-augment";
+import { alith, baltathar, describeSuite, expect } from "moonwall";
+
+describeSuite({
+  id: "D023495",
+  title: "Staking - Candidate profile",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const profile = {
+      displayName: "Alith",
+      website: "https://alith.example",
+      contact: "ops@alith.example",
+      region: "EU",
+      commissionIntent: 100_000_000,
+    };
+
+    it({
+      id: "T01",
+      title: "should store the profile and hold its deposit",
+      test: async () => {
+        const heldBefore = (
+          await context.polkadotJs().query.system.account(alith.address)
+        ).data.reserved.toBigInt();
+
+        const { result } = await context.createBlock(
+          context.polkadotJs().tx.parachainStaking.setCandidateProfile(profile).signAsync(alith)
+        );
+        expect(result!.successful).to.be.true;
+
+        const stored = (
+          await context.polkadotJs().query.parachainStaking.candidateProfiles(alith.address)
+        ).unwrap();
+        expect(stored.info.displayName.toUtf8()).toBe("Alith");
+        expect(stored.info.region.toUtf8()).toBe("EU");
+        expect(stored.info.commissionIntent.unwrap().toNumber()).toBe(100_000_000);
+
+        const heldAfter = (
+          await context.polkadotJs().query.system.account(alith.address)
+        ).data.reserved.toBigInt();
+        expect(heldAfter - heldBefore).toBe(stored.deposit.toBigInt());
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should not store a profile for accounts that are not candidates",
+      test: async () => {
+        const { result } = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.setCandidateProfile(profile)
+            .signAsync(baltathar)
+        );
+        expect(result!.successful).to.be.false;
+        expect(result!.error!.name).to.equal("CandidateDNE");
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should release the deposit when the profile is cleared",
+      test: async () => {
+        const { result } = await context.createBlock(
+          context.polkadotJs().tx.parachainStaking.clearCandidateProfile().signAsync(alith)
+        );
+        expect(result!.successful).to.be.true;
+        expect(
+          result!.events.some(({ event }) => event.method === "CandidateProfileCleared")
+        ).to.be.true;
+        expect(
+          (await context.polkadotJs().query.parachainStaking.candidateProfiles(alith.address))
+            .isNone
+        ).to.be.true;
+      },
+    });
+  },
+});
@@ -0,0 +1,88 @@
+import "@moonbeam-network/api-augment";
+import { MIN_GLMR_DELEGATOR, alith, beforeAll, describeSuite, ethan, expect } from "moonwall";
+import { jumpToRound } from "../../../../helpers";
+
+describeSuite({
+  id: "D023493",
+  title: "Staking - Matured Requests - execution on idle",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    let whenExecutable: number;
+
+    beforeAll(async () => {
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.parachainStaking.delegateWithAutoCompound(
+            alith.address,
+            MIN_GLMR_DELEGATOR + 10n,
+            0,
+            0,
+            0,
+            0
+          )
+          .signAsync(ethan),
+        { allowFailures: false }
+      );
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.parachainStaking.scheduleDelegatorBondLess(alith.address, 10n)
+          .signAsync(ethan),
+        { allowFailures: false }
+      );
+
+      const delegationRequests = await context
+        .polkadotJs()
+        .query.parachainStaking.delegationScheduledRequests(alith.address, ethan.address);
+      whenExecutable = delegationRequests[0].whenExecutable.toNumber();
+    });
+
+    it({
+      id: "T01",
+      title: "should not execute the request before it is matured",
+      test: async () => {
+        await jumpToRound(context, whenExecutable - 1);
+        await context.createBlock();
+
+        const delegationRequests = await context
+          .polkadotJs()
+          .query.parachainStaking.delegationScheduledRequests(alith.address, ethan.address);
+        expect(delegationRequests.length).to.equal(1);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should execute the request in an idle block once matured",
+      test: async () => {
+        await jumpToRound(context, whenExecutable);
+        await context.createBlock();
+
+        const delegationRequests = await context
+          .polkadotJs()
+          .query.parachainStaking.delegationScheduledRequests(alith.address, ethan.address);
+        expect(delegationRequests.length).to.equal(0);
+
+        const state = (
+          await context.polkadotJs().query.parachainStaking.delegatorState(ethan.address)
+        ).unwrap();
+        expect(state.total.toBigInt()).toBe(MIN_GLMR_DELEGATOR);
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should fail to execute when no request is matured",
+      test: async () => {
+        const block = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.executeAllMaturedRequests(ethan.address, 10)
+            .signAsync(alith)
+        );
+        expect(block.result!.error!.name).to.equal("NoMaturedDelegationRequests");
+      },
+    });
+  },
+});
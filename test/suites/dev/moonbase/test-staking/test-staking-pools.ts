@@ -0,0 +1,100 @@
+import "@moonbeam-network/api-augment";
+import {
+  MIN_GLMR_DELEGATOR,
+  alith,
+  baltathar,
+  beforeAll,
+  describeSuite,
+  ethan,
+  expect,
+} from "moonwall";
+
+describeSuite({
+  id: "D023494",
+  title: "Staking - Delegation pools",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    let poolAccount: string;
+
+    beforeAll(async () => {
+      const { result } = await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.sudo.sudo(context.polkadotJs().tx.parachainStaking.createPool([alith.address]))
+          .signAsync(alith),
+        { allowFailures: false }
+      );
+      const created = result!.events.find(({ event }) => event.method === "PoolCreated");
+      expect(created).to.not.be.undefined;
+      poolAccount = created!.event.data[1].toString();
+    });
+
+    it({
+      id: "T01",
+      title: "should delegate the stake of members joining the pool",
+      test: async () => {
+        const { result } = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.joinPool(0, MIN_GLMR_DELEGATOR)
+            .signAsync(ethan)
+        );
+        expect(result!.successful).to.be.true;
+        expect(result!.events.some(({ event }) => event.method === "JoinedPool")).to.be.true;
+
+        const shares = await context
+          .polkadotJs()
+          .query.parachainStaking.poolShares(0, ethan.address);
+        expect(shares.toBigInt()).toBe(MIN_GLMR_DELEGATOR);
+
+        const state = (
+          await context.polkadotJs().query.parachainStaking.delegatorState(poolAccount)
+        ).unwrap();
+        expect(state.delegations.map((d) => d.owner.toString())).to.deep.equal([alith.address]);
+        expect(state.delegations[0].amount.toBigInt()).toBe(MIN_GLMR_DELEGATOR);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should transfer shares of the pool",
+      test: async () => {
+        const { result } = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.transferPoolShares(0, baltathar.address, MIN_GLMR_DELEGATOR / 2n)
+            .signAsync(ethan)
+        );
+        expect(result!.successful).to.be.true;
+
+        const shares = await context
+          .polkadotJs()
+          .query.parachainStaking.poolShares(0, baltathar.address);
+        expect(shares.toBigInt()).toBe(MIN_GLMR_DELEGATOR / 2n);
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should expose the shares of the pool as an ERC20 token",
+      test: async () => {
+        const poolShares = "0xFFFFFFFD00000000000000000000000000000000";
+        expect(
+          await context.readContract!({
+            contractName: "ERC20Instance",
+            contractAddress: poolShares,
+            functionName: "totalSupply",
+          })
+        ).toBe(MIN_GLMR_DELEGATOR);
+        expect(
+          await context.readContract!({
+            contractName: "ERC20Instance",
+            contractAddress: poolShares,
+            functionName: "balanceOf",
+            args: [baltathar.address],
+          })
+        ).toBe(MIN_GLMR_DELEGATOR / 2n);
+      },
+    });
+  },
+});
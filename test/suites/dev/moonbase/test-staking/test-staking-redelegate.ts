@@ -0,0 +1,94 @@
+import "@moonbeam-network/api-augment";
+import {
+  MIN_GLMR_DELEGATOR,
+  MIN_GLMR_STAKING,
+  alith,
+  baltathar,
+  beforeAll,
+  describeSuite,
+  ethan,
+  expect,
+} from "moonwall";
+
+describeSuite({
+  id: "D023492",
+  title: "Staking - Redelegate",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    beforeAll(async () => {
+      await context.createBlock(
+        [
+          context
+            .polkadotJs()
+            .tx.parachainStaking.joinCandidates(MIN_GLMR_STAKING, 1)
+            .signAsync(baltathar),
+          context
+            .polkadotJs()
+            .tx.parachainStaking.delegateWithAutoCompound(
+              alith.address,
+              MIN_GLMR_DELEGATOR,
+              20,
+              0,
+              0,
+              0
+            )
+            .signAsync(ethan),
+        ],
+        { allowFailures: false }
+      );
+    });
+
+    it({
+      id: "T01",
+      title: "should move the delegation to another candidate",
+      test: async () => {
+        const frozenBefore = (
+          await context.polkadotJs().query.system.account(ethan.address)
+        ).data.frozen.toBigInt();
+
+        const { result } = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.redelegate(alith.address, baltathar.address, 0, 0)
+            .signAsync(ethan)
+        );
+        expect(result!.successful).to.be.true;
+        expect(result!.events.some(({ event }) => event.method === "Redelegated")).to.be.true;
+
+        const state = (
+          await context.polkadotJs().query.parachainStaking.delegatorState(ethan.address)
+        ).unwrap();
+        expect(state.delegations.map((d) => d.owner.toString())).to.deep.equal([
+          baltathar.address,
+        ]);
+        expect(state.delegations[0].amount.toBigInt()).toBe(MIN_GLMR_DELEGATOR);
+
+        const autoCompounding = await context
+          .polkadotJs()
+          .query.parachainStaking.autoCompoundingDelegations(baltathar.address);
+        expect(autoCompounding[0].delegator.toString()).toBe(ethan.address);
+        expect(autoCompounding[0].value.toNumber()).toBe(20);
+
+        const frozenAfter = (
+          await context.polkadotJs().query.system.account(ethan.address)
+        ).data.frozen.toBigInt();
+        expect(frozenAfter).toBe(frozenBefore);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should not redelegate again before the cooldown",
+      test: async () => {
+        const { result } = await context.createBlock(
+          context
+            .polkadotJs()
+            .tx.parachainStaking.redelegate(baltathar.address, alith.address, 1, 0)
+            .signAsync(ethan)
+        );
+        expect(result!.successful).to.be.false;
+        expect(result!.error!.name).to.equal("RedelegationCooldownNotElapsed");
+      },
+    });
+  },
+});
@@ -0,0 +1,134 @@
+import "@moonbeam-network/api-augment";
+import {
+  DEFAULT_GENESIS_STAKING,
+  MIN_GLMR_DELEGATOR,
+  alith,
+  baltathar,
+  beforeAll,
+  customDevRpcRequest,
+  describeSuite,
+  ethan,
+  expect,
+} from "moonwall";
+
+describeSuite({
+  id: "D023491",
+  title: "Staking - RPC - delegator position and collator summary",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    beforeAll(async () => {
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.parachainStaking.delegateWithAutoCompound(
+            alith.address,
+            MIN_GLMR_DELEGATOR + 10n,
+            50,
+            0,
+            0,
+            0
+          )
+          .signAsync(ethan),
+        { allowFailures: false }
+      );
+      await context.createBlock(
+        context
+          .polkadotJs()
+          .tx.parachainStaking.scheduleDelegatorBondLess(alith.address, 10n)
+          .signAsync(ethan),
+        { allowFailures: false }
+      );
+    });
+
+    it({
+      id: "T01",
+      title: "should return the delegations of a delegator",
+      test: async () => {
+        const currentRound = (
+          await context.polkadotJs().query.parachainStaking.round()
+        ).current.toNumber();
+        const delay = context
+          .polkadotJs()
+          .consts.parachainStaking.delegationBondLessDelay.toNumber();
+
+        const position = await customDevRpcRequest("staking_getDelegatorPosition", [
+          ethan.address,
+        ]);
+        expect(BigInt(position.total)).toBe(MIN_GLMR_DELEGATOR + 10n);
+        expect(BigInt(position.lessTotal)).toBe(10n);
+        expect(position.delegations).toHaveLength(1);
+
+        const [delegation] = position.delegations;
+        expect(delegation.candidate).toBe(alith.address.toLowerCase());
+        expect(BigInt(delegation.amount)).toBe(MIN_GLMR_DELEGATOR + 10n);
+        expect(delegation.autoCompound).toBe(50);
+        expect(delegation.placement).toBe("top");
+        expect(delegation.scheduledRequests).toHaveLength(1);
+        expect(delegation.scheduledRequests[0].action).toBe("decrease");
+        expect(BigInt(delegation.scheduledRequests[0].amount)).toBe(10n);
+        expect(delegation.scheduledRequests[0].whenExecutable).toBe(currentRound + delay);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should return the summary of a collator",
+      test: async () => {
+        const summary = await customDevRpcRequest("staking_getCollatorSummary", [alith.address]);
+        expect(BigInt(summary.bond)).toBe(DEFAULT_GENESIS_STAKING);
+        expect(summary.status).toBe("active");
+        expect(summary.isSelected).toBe(true);
+        expect(summary.delegationCount).toBe(1);
+        expect(summary.topDelegationCount).toBe(1);
+        expect(summary.autoCompoundingDelegationCount).toBe(1);
+        expect(summary.scheduledRequestDelegatorCount).toBe(1);
+        expect(summary.bondLessRequest).toBeNull();
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should project the rewards of a new delegation",
+      test: async () => {
+        const rewards = await customDevRpcRequest("staking_getProjectedRewards", [
+          alith.address,
+          `0x${MIN_GLMR_DELEGATOR.toString(16)}`,
+        ]);
+        expect(rewards.counted).toBe(true);
+        expect(BigInt(rewards.delegationRoundReward) > 0n).toBe(true);
+        expect(BigInt(rewards.delegationAnnualReward) > BigInt(rewards.delegationRoundReward)).toBe(
+          true
+        );
+        expect(rewards.delegationApr).toBeGreaterThan(0);
+      },
+    });
+
+    it({
+      id: "T04",
+      title: "should return null for accounts that are not staking",
+      test: async () => {
+        expect(
+          await customDevRpcRequest("staking_getDelegatorPosition", [baltathar.address])
+        ).toBeNull();
+        expect(
+          await customDevRpcRequest("staking_getCollatorSummary", [baltathar.address])
+        ).toBeNull();
+        expect(
+          await customDevRpcRequest("staking_getProjectedRewards", [baltathar.address, "0x1"])
+        ).toBeNull();
+      },
+    });
+
+    it({
+      id: "T05",
+      title: "should return the collators selected for a round",
+      test: async () => {
+        const selection = await customDevRpcRequest("staking_getRoundSelection", [1]);
+        expect(selection.length).toBe(1);
+        expect(selection[0].collator.toLowerCase()).toBe(alith.address.toLowerCase());
+        expect(BigInt(selection[0].totalCounted)).toBe(DEFAULT_GENESIS_STAKING);
+        expect(selection[0].delegatorCount).toBe(0);
+      },
+    });
+  },
+});
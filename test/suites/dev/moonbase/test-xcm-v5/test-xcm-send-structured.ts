@@ -0,0 +1,94 @@
+import "@moonbeam-network/api-augment";
+import { beforeAll, customDevRpcRequest, describeSuite, expect, generateKeyringPair } from "moonwall";
+
+import {
+  RELAY_SOURCE_LOCATION,
+  addAssetToWeightTrader,
+  registerForeignAsset,
+  relayAssetMetadata,
+} from "../../../../helpers/assets.js";
+
+describeSuite({
+  id: "D024213",
+  title: "Mock XCM - Structured message injection with processing report",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const assetId = 1n;
+
+    beforeAll(async () => {
+      await registerForeignAsset(
+        context,
+        assetId,
+        RELAY_SOURCE_LOCATION,
+        relayAssetMetadata as any
+      );
+
+      const WEIGHT_REF_TIME_PER_SECOND = 1_000_000_000_000n;
+      const nativeAmountPerSecond = await context
+        .polkadotJs()
+        .call.transactionPaymentApi.queryWeightToFee({
+          refTime: WEIGHT_REF_TIME_PER_SECOND,
+          proofSize: 0n,
+        });
+      const relativePrice = BigInt(nativeAmountPerSecond.toString()) * 10n ** 18n;
+      await addAssetToWeightTrader(RELAY_SOURCE_LOCATION, relativePrice, context);
+    });
+
+    it({
+      id: "T01",
+      title: "should report the processed downward message and the resulting deposit",
+      test: async function () {
+        const beneficiary = generateKeyringPair().address as `0x${string}`;
+        const topic = `0x${"11".repeat(32)}`;
+
+        const result = await customDevRpcRequest("xcm_sendDownwardXcm", [
+          {
+            assets: [{ location: { parents: 1, interior: [] }, amount: "10000000000000" }],
+            beneficiary,
+            topic,
+          },
+        ]);
+
+        const blockHash = (await context.polkadotJs().rpc.chain.getBlockHash()).toHex();
+        expect(result.blockHash).toBe(blockHash);
+        expect(result.failed).toHaveLength(0);
+        expect(result.processed).toHaveLength(1);
+        expect(result.processed[0].id).toBe(topic);
+        expect(result.processed[0].origin).toBe("parent");
+        expect(result.processed[0].success).toBe(true);
+        expect(BigInt(result.processed[0].weightUsed.refTime)).toBeGreaterThan(0n);
+
+        const deposit = result.processed[0].deposits.find(
+          (d: any) => d.who.toLowerCase() === beneficiary.toLowerCase()
+        );
+        expect(deposit).toBeDefined();
+        expect(deposit.asset).not.toBeNull();
+        expect(BigInt(deposit.amount)).toBeGreaterThan(0n);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should report an unsuccessful HRMP message for an unknown asset",
+      test: async function () {
+        const result = await customDevRpcRequest("xcm_sendHrmpXcm", [
+          2000,
+          {
+            assets: [
+              {
+                location: { parents: 1, interior: [{ parachain: 2000 }] },
+                amount: "10000000000000",
+              },
+            ],
+            beneficiary: generateKeyringPair().address,
+          },
+        ]);
+
+        expect(result.processed).toHaveLength(1);
+        expect(result.processed[0].origin).toEqual({ sibling: 2000 });
+        expect(result.processed[0].success).toBe(false);
+        expect(result.processed[0].deposits).toHaveLength(0);
+      },
+    });
+  },
+});
@@ -0,0 +1,99 @@
+import "@moonbeam-network/api-augment";
+import { alith, customDevRpcRequest, describeSuite, expect } from "moonwall";
+import { u8aConcat, u8aToHex } from "@polkadot/util";
+import { xxhashAsU8a } from "@polkadot/util-crypto";
+
+import { sealUntilXcmProcessed, wrapWithXcmVersion } from "../../../../helpers";
+
+describeSuite({
+  id: "D023918",
+  title: "XCM - HRMP forwarding between dev nodes",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const twox64Concat = (data: Uint8Array) => u8aConcat(xxhashAsU8a(data, 64), data);
+    const hrmpKey = (storage: string, key: Uint8Array) =>
+      u8aToHex(u8aConcat(xxhashAsU8a("Hrmp", 128), xxhashAsU8a(storage, 128), twox64Concat(key)));
+
+    it({
+      id: "T01",
+      title: "should process the HRMP message forwarded from a sibling dev node",
+      test: async function () {
+        const polkadotJs = context.polkadotJs();
+        const paraId = (await polkadotJs.query.parachainInfo.parachainId()).toNumber();
+        const recipient = 2000;
+
+        // Open the egress channel declared by `--dev-hrmp-peer` in the mocked relay state
+        const egressIndexKey = hrmpKey(
+          "HrmpEgressChannelsIndex",
+          polkadotJs.createType("u32", paraId).toU8a()
+        );
+        const channelKey = hrmpKey(
+          "HrmpChannels",
+          polkadotJs.createType("(u32, u32)", [paraId, recipient]).toU8a()
+        );
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, polkadotJs.createType("Vec<u32>", [recipient]).toHex()],
+            [
+              channelKey,
+              polkadotJs
+                .createType("(u32, u32, u32, u32, u32, Option<H256>)", [
+                  1000,
+                  1048576,
+                  102400,
+                  0,
+                  0,
+                  null,
+                ])
+                .toHex(),
+            ],
+          ],
+        ]);
+
+        const { block } = await context.createBlock(
+          polkadotJs.tx.polkadotXcm
+            .send(
+              wrapWithXcmVersion({ parents: 1, interior: { X1: [{ Parachain: recipient }] } }, 5),
+              wrapWithXcmVersion([{ ClearOrigin: null }], 5)
+            )
+            .signAsync(alith)
+        );
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, null],
+            [channelKey, null],
+          ],
+        ]);
+
+        const outbound = await customDevRpcRequest("xcm_getOutboundMessages", [block.hash]);
+        expect(outbound.horizontal).toHaveLength(1);
+        const message = outbound.horizontal[0];
+        expect(message.recipient).toBe(recipient);
+
+        // This node stands in for the peer: inject the outbound message the way the HRMP
+        // forwarder does, from the sender parachain and with the raw XCMP data
+        await customDevRpcRequest("xcm_injectHrmpMessage", [
+          paraId,
+          Array.from(polkadotJs.createType("Bytes", message.data).toU8a(true)),
+        ]);
+        const injectedAt = (await polkadotJs.rpc.chain.getHeader()).number.toNumber();
+        await sealUntilXcmProcessed(context, 10);
+        const sealedTo = (await polkadotJs.rpc.chain.getHeader()).number.toNumber();
+
+        const processed = [];
+        for (let number = injectedAt + 1; number <= sealedTo; number++) {
+          const apiAt = await polkadotJs.at(await polkadotJs.rpc.chain.getBlockHash(number));
+          processed.push(
+            ...(await apiAt.query.system.events()).filter(({ event }) =>
+              polkadotJs.events.messageQueue.Processed.is(event)
+            )
+          );
+        }
+        expect(processed).toHaveLength(1);
+        const { origin } = processed[0].event.data as any;
+        expect(origin.isSibling).toBe(true);
+        expect(origin.asSibling.toNumber()).toBe(paraId);
+      },
+    });
+  },
+});
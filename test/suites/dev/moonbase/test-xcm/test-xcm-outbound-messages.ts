@@ -0,0 +1,145 @@
+import "@moonbeam-network/api-augment";
+import { alith, customDevRpcRequest, describeSuite, expect } from "moonwall";
+import { u8aConcat, u8aToHex } from "@polkadot/util";
+import { xxhashAsU8a } from "@polkadot/util-crypto";
+
+import { wrapWithXcmVersion } from "../../../../helpers";
+
+describeSuite({
+  id: "D023917",
+  title: "XCM - Outbound messages",
+  foundationMethods: "dev",
+  testCases: ({ context, it }) => {
+    const twox64Concat = (data: Uint8Array) => u8aConcat(xxhashAsU8a(data, 64), data);
+    const hrmpKey = (storage: string, key: Uint8Array) =>
+      u8aToHex(u8aConcat(xxhashAsU8a("Hrmp", 128), xxhashAsU8a(storage, 128), twox64Concat(key)));
+
+    it({
+      id: "T01",
+      title: "should expose the upward message sent by a block",
+      test: async function () {
+        const polkadotJs = context.polkadotJs();
+        const balancesPalletIndex = (await polkadotJs.rpc.state.getMetadata()).asLatest.pallets
+          .find(({ name }) => name.toString() === "Balances")!
+          .index.toNumber();
+
+        const { block } = await context.createBlock(
+          polkadotJs.tx.polkadotXcm
+            .transferAssets(
+              wrapWithXcmVersion({ parents: 1, interior: { Here: null } }, 5),
+              wrapWithXcmVersion(
+                {
+                  parents: 0,
+                  interior: {
+                    X1: [{ AccountId32: { network: null, id: `0x${"11".repeat(32)}` } }],
+                  },
+                },
+                5
+              ),
+              wrapWithXcmVersion(
+                [
+                  {
+                    id: { parents: 0, interior: { X1: [{ PalletInstance: balancesPalletIndex }] } },
+                    fun: { Fungible: 1_000_000_000_000_000n },
+                  },
+                ],
+                5
+              ),
+              0,
+              "Unlimited"
+            )
+            .signAsync(alith)
+        );
+
+        const outbound = await customDevRpcRequest("xcm_getOutboundMessages", [block.hash]);
+        expect(outbound.blockHash).toBe(block.hash);
+        expect(outbound.horizontal).toHaveLength(0);
+        expect(outbound.upward).toHaveLength(1);
+        expect(outbound.upward[0].version).toBe(5);
+        expect(outbound.upward[0].instructions.length).toBeGreaterThan(0);
+        expect(
+          outbound.upward[0].instructions.some((i: string) => i.startsWith("DepositAsset"))
+        ).toBe(true);
+      },
+    });
+
+    it({
+      id: "T02",
+      title: "should return no messages for a block that sent none",
+      test: async function () {
+        await context.createBlock();
+
+        const outbound = await customDevRpcRequest("xcm_getOutboundMessages", []);
+        expect(outbound.upward).toHaveLength(0);
+        expect(outbound.horizontal).toHaveLength(0);
+      },
+    });
+
+    it({
+      id: "T03",
+      title: "should expose the HRMP message sent through an open egress channel",
+      test: async function () {
+        const polkadotJs = context.polkadotJs();
+        const paraId = (await polkadotJs.query.parachainInfo.parachainId()).toNumber();
+        const recipient = 2000;
+
+        // HRMP messages are only sent through the egress channels of the mocked relay state
+        const egressIndexKey = hrmpKey(
+          "HrmpEgressChannelsIndex",
+          polkadotJs.createType("u32", paraId).toU8a()
+        );
+        const channelKey = hrmpKey(
+          "HrmpChannels",
+          polkadotJs.createType("(u32, u32)", [paraId, recipient]).toU8a()
+        );
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, polkadotJs.createType("Vec<u32>", [recipient]).toHex()],
+            [
+              channelKey,
+              polkadotJs
+                .createType("(u32, u32, u32, u32, u32, Option<H256>)", [
+                  1000,
+                  1048576,
+                  102400,
+                  0,
+                  0,
+                  null,
+                ])
+                .toHex(),
+            ],
+          ],
+        ]);
+
+        const { block } = await context.createBlock(
+          polkadotJs.tx.polkadotXcm
+            .send(
+              wrapWithXcmVersion({ parents: 1, interior: { X1: [{ Parachain: recipient }] } }, 5),
+              wrapWithXcmVersion([{ ClearOrigin: null }], 5)
+            )
+            .signAsync(alith)
+        );
+
+        const outbound = await customDevRpcRequest("xcm_getOutboundMessages", [block.hash]);
+        expect(outbound.upward).toHaveLength(0);
+        expect(outbound.horizontal).toHaveLength(1);
+        expect(outbound.horizontal[0].recipient).toBe(recipient);
+        expect(outbound.horizontal[0].messages).toHaveLength(1);
+        expect(outbound.horizontal[0].messages[0].version).toBe(5);
+        expect(
+          outbound.horizontal[0].messages[0].instructions.some((i: string) =>
+            i.startsWith("ClearOrigin")
+          )
+        ).toBe(true);
+
+        await customDevRpcRequest("test_setRelayStorage", [
+          [
+            [egressIndexKey, null],
+            [channelKey, null],
+          ],
+        ]);
+        await context.createBlock();
+      },
+    });
+  },
+});
@@ -0,0 +1,39 @@
+import "@moonbeam-network/api-augment";
+import { beforeAll, describeSuite, expect } from "moonwall";
+import type { ApiPromise } from "@polkadot/api";
+
+describeSuite({
+  id: "LL-COMMON-FORK-ALIGNMENT",
+  title: "Lazy Loading - Upstream alignment",
+  foundationMethods: "dev",
+  testCases: ({ it, context }) => {
+    let api: ApiPromise;
+
+    beforeAll(async () => {
+      api = context.polkadotJs();
+    });
+
+    it({
+      id: "T01",
+      title: "should continue upstream timestamp and relay state",
+      test: async function () {
+        const forkTimestamp = (await api.query.timestamp.now()).toBigInt();
+        const forkRelayNumber = (
+          await api.query.parachainSystem.lastRelayChainBlockNumber()
+        ).toNumber();
+        const [forkSlot] = (await api.query.asyncBacking.slotInfo()).unwrap();
+        const forkEpoch = (await api.query.randomness.relayEpoch()).toBigInt();
+
+        await context.createBlock();
+
+        expect((await api.query.timestamp.now()).toBigInt()).toBe(forkTimestamp + 6_000n);
+        expect(
+          (await api.query.parachainSystem.lastRelayChainBlockNumber()).toNumber()
+        ).toBeGreaterThan(forkRelayNumber);
+        const [slot] = (await api.query.asyncBacking.slotInfo()).unwrap();
+        expect(slot.toBigInt()).toBeGreaterThan(forkSlot.toBigInt());
+        expect((await api.query.randomness.relayEpoch()).toBigInt()).toBe(forkEpoch);
+      },
+    });
+  },
+});
@@ -0,0 +1,47 @@
+import "@moonbeam-network/api-augment";
+import { spawnSync } from "node:child_process";
+import { customDevRpcRequest, describeSuite, expect } from "moonwall";
+
+// Block 12962274 contains a EIP7702 transaction
+const REPLAYED_BLOCK = 12962274;
+
+describeSuite({
+  id: "LL-MOONBEAM-REPLAY",
+  title: "Lazy Loading - Replay of upstream blocks",
+  foundationMethods: "dev",
+  testCases: ({ it }) => {
+    it({
+      id: "T01",
+      title: "should replay a known block without divergence",
+      timeout: 170000,
+      test: async function () {
+        const forkBlockHash = await customDevRpcRequest("chain_getBlockHash", [
+          REPLAYED_BLOCK - 1,
+        ]);
+
+        const replay = spawnSync(
+          "../target/debug/moonbeam",
+          [
+            "--lazy-loading-remote-rpc=https://trace.api.moonbeam.network",
+            `--lazy-loading-block=${forkBlockHash}`,
+            `--lazy-loading-replay-to=${REPLAYED_BLOCK}`,
+            "--lazy-loading-delay-between-requests=10",
+            "--lazy-loading-max-retries-per-request=5",
+            "--no-hardware-benchmarks",
+            "--no-telemetry",
+            "--no-prometheus",
+            "--unsafe-force-node-key-generation",
+            "--tmp",
+          ],
+          { encoding: "utf8", timeout: 160000 }
+        );
+        const logs = `${replay.stdout}${replay.stderr}`;
+
+        expect(replay.status, logs).toBe(0);
+        expect(logs).toMatch(new RegExp(`#${REPLAYED_BLOCK} \\(0x[0-9a-f]+\\) matches upstream`));
+        expect(logs).not.toContain("diverged:");
+        expect(logs).toContain("Replay completed: 0 of 1 blocks diverged from upstream");
+      },
+    });
+  },
+});
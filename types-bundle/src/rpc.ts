@@ -57,6 +57,26 @@ export const rpcDefinitions: Record<string, Record<string, DefinitionRpc | Defin
       ],
       type: "Result<()>",
     },
+    sendDownwardXcm: {
+      description:
+        "Inject a downward message, encoded or described in JSON, seal a block and return its processing outcome.",
+      params: [{ name: "message", type: "Json" }],
+      type: "Json",
+    },
+    sendHrmpXcm: {
+      description:
+        "Inject an HRMP message, encoded or described in JSON, seal a block and return its processing outcome.",
+      params: [
+        { name: "sender", type: "ParaId" },
+        { name: "message", type: "Json" },
+      ],
+      type: "Json",
+    },
+    getOutboundMessages: {
+      description: "Returns the upward and HRMP messages sent by a block.",
+      params: [{ name: "blockHash", type: "Hash", isOptional: true }],
+      type: "Json",
+    },
   },
   moon: {
     isBlockFinalized: {
@@ -69,10 +89,79 @@ export const rpcDefinitions: Record<string, Record<string, DefinitionRpc | Defin
       params: [{ name: "txHash", type: "Hash" }],
       type: "bool",
     },
+    areBlocksFinalized: {
+      description: "Returns whether each of the given Ethereum blocks is finalized",
+      params: [{ name: "blockHashes", type: "Vec<Hash>" }],
+      type: "Vec<bool>",
+    },
+    areTxsFinalized: {
+      description: "Returns whether each of the given Ethereum transactions is finalized",
+      params: [{ name: "txHashes", type: "Vec<Hash>" }],
+      type: "Vec<bool>",
+    },
     getEthSyncBlockRange: {
       description: "Returns the range of blocks that are fully indexed in frontier's backend.",
       params: [],
       type: "(H256, H256)",
     },
+    getFinalizedEthBlock: {
+      description: "Returns the number and hash of the latest finalized Ethereum block",
+      params: [],
+      type: "Json",
+    },
+    getTransactionSubstrateEffects: {
+      description:
+        "Returns the extrinsic of an Ethereum transaction, with its decoded runtime events and the hashes of the XCM messages it sent",
+      params: [{ name: "txHash", type: "Hash" }],
+      type: "Json",
+    },
+    subscribeFinalizedTransactions: {
+      description: "Emits the Ethereum transaction hashes of each block as it is finalized",
+      params: [],
+      pubsub: [
+        "finalizedTransactions",
+        "subscribeFinalizedTransactions",
+        "unsubscribeFinalizedTransactions",
+      ],
+      type: "Json",
+    },
+  },
+  staking: {
+    getDelegatorPosition: {
+      description:
+        "Returns the delegations of a delegator, with their auto-compound percentage, pending requests and top/bottom placement",
+      params: [
+        { name: "delegator", type: "H160" },
+        { name: "at", type: "Hash", isOptional: true },
+      ],
+      type: "Json",
+    },
+    getCollatorSummary: {
+      description: "Returns the bond, status, delegation counts and totals of a collator candidate",
+      params: [
+        { name: "candidate", type: "H160" },
+        { name: "at", type: "Hash", isOptional: true },
+      ],
+      type: "Json",
+    },
+    getProjectedRewards: {
+      description:
+        "Returns the per round and annual rewards projected for a new delegation to a collator",
+      params: [
+        { name: "collator", type: "H160" },
+        { name: "stake", type: "U256" },
+        { name: "at", type: "Hash", isOptional: true },
+      ],
+      type: "Json",
+    },
+    getRoundSelection: {
+      description:
+        "Returns the collators selected for a past round, with their counted stake and delegator count",
+      params: [
+        { name: "round", type: "u32" },
+        { name: "at", type: "Hash", isOptional: true },
+      ],
+      type: "Json",
+    },
   },
 };